@@ -0,0 +1,406 @@
+//! Following the chain with finality tracking and reorg rollback.
+//!
+//! The indexer keeps two kinds of blocks apart:
+//! - Finalized blocks can never be reverted. They are fetched by height, so catching up on a long
+//!   history needs no more memory than following the head
+//! - Blocks of the best chain above the finalized head are applied right away, so consumers see
+//!   new events without waiting for finality, but they are kept as `pending` until finalized. When
+//!   the node switches to another fork, the abandoned blocks are reverted newest first before the
+//!   blocks of the new fork are applied
+//!
+//! The [`Checkpoint`] records both, so a restarted indexer reverts whatever was applied from a
+//! fork that lost while it was down.
+
+use crate::{
+    error::Error,
+    node::{BlockRef, Chain},
+};
+use mod_net_runtime::BlockNumber;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Receives the changes of the indexed chain, in order.
+pub trait Sink {
+    /// Index block `block`. Its parent was applied before.
+    async fn apply(&mut self, block: &BlockRef) -> Result<(), Error>;
+
+    /// Undo `apply` of `block`, which is no longer part of the best chain. Blocks are reverted
+    /// newest first.
+    async fn revert(&mut self, block: &BlockRef) -> Result<(), Error>;
+
+    /// `block`, which was applied before, is now final and will never be reverted.
+    async fn finalize(&mut self, block: &BlockRef) -> Result<(), Error>;
+}
+
+/// How far the indexer got.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    /// The last finalized block that was applied and finalized.
+    pub finalized: BlockRef,
+    /// Applied blocks above `finalized` that are not finalized yet, in chain order.
+    pub pending: Vec<BlockRef>,
+}
+
+impl Checkpoint {
+    /// Start indexing after the finalized block at height `number`.
+    pub async fn start(chain: &impl Chain, number: BlockNumber) -> Result<Self, Error> {
+        let head = chain.block(chain.finalized_head().await?).await?;
+        if number > head.number {
+            return Err(Error::NotFinalized(number));
+        }
+        let hash = chain
+            .block_hash(number)
+            .await?
+            .ok_or(Error::MissingBlock(number))?;
+        Ok(Self {
+            finalized: chain.block(hash).await?,
+            pending: Vec::new(),
+        })
+    }
+
+    /// The checkpoint saved at `path`, or `None` if there is none yet.
+    pub fn load(path: &Path) -> Result<Option<Self>, Error> {
+        match std::fs::read(path) {
+            Ok(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Save the checkpoint to `path`. The file is replaced atomically, so a crash leaves either
+    /// the old or the new checkpoint.
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let temp = path.with_extension("tmp");
+        std::fs::write(&temp, serde_json::to_vec_pretty(self)?)?;
+        std::fs::rename(temp, path)?;
+        Ok(())
+    }
+
+    /// Check that the finalized block of the checkpoint is on the node's finalized chain, so a
+    /// checkpoint of another chain is not resumed.
+    pub async fn verify(&self, chain: &impl Chain) -> Result<(), Error> {
+        let hash = chain.block_hash(self.finalized.number).await?;
+        if hash != Some(self.finalized.hash) {
+            return Err(Error::FinalityViolation(self.finalized.number));
+        }
+        Ok(())
+    }
+
+    /// Bring `sink` up to date with the chain.
+    ///
+    /// At most `max_finalized` finalized blocks are indexed per call, so the caller can save the
+    /// checkpoint regularly while catching up. Returns whether the best head was reached.
+    pub async fn advance(
+        &mut self,
+        chain: &impl Chain,
+        sink: &mut impl Sink,
+        max_finalized: u32,
+    ) -> Result<bool, Error> {
+        let head = chain.block(chain.finalized_head().await?).await?;
+        let target = head
+            .number
+            .min(self.finalized.number.saturating_add(max_finalized));
+        while self.finalized.number < target {
+            let number = self.finalized.number + 1;
+            let hash = chain
+                .block_hash(number)
+                .await?
+                .ok_or(Error::MissingBlock(number))?;
+            // A pending block on another fork was abandoned, and so was everything built on it
+            if self.pending.first().is_some_and(|block| block.hash != hash) {
+                self.revert_to(0, sink).await?;
+            }
+            if self.pending.is_empty() {
+                let block = chain.block(hash).await?;
+                if block.parent_hash != self.finalized.hash {
+                    return Err(Error::FinalityViolation(self.finalized.number));
+                }
+                sink.apply(&block).await?;
+                self.pending.push(block);
+            }
+            let block = self.pending.remove(0);
+            sink.finalize(&block).await?;
+            self.finalized = block;
+        }
+        if target < head.number {
+            return Ok(false);
+        }
+
+        // Walk back from the best head to the newest block that was already applied; anything
+        // applied after it belongs to an abandoned fork
+        let mut route = Vec::new();
+        let mut block = chain.best_block().await?;
+        let kept = loop {
+            if block.hash == self.finalized.hash {
+                break 0;
+            }
+            if let Some(index) = self.pending.iter().position(|b| b.hash == block.hash) {
+                break index + 1;
+            }
+            if block.number <= self.finalized.number {
+                return Err(Error::FinalityViolation(block.number));
+            }
+            let parent = chain.block(block.parent_hash).await?;
+            route.push(block);
+            block = parent;
+        };
+        self.revert_to(kept, sink).await?;
+        for block in route.into_iter().rev() {
+            sink.apply(&block).await?;
+            self.pending.push(block);
+        }
+        Ok(true)
+    }
+
+    /// Revert pending blocks, newest first, until `len` are left.
+    async fn revert_to(&mut self, len: usize, sink: &mut impl Sink) -> Result<(), Error> {
+        while self.pending.len() > len {
+            let block = self.pending.pop().expect("longer than len; qed");
+            sink.revert(&block).await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mod_net_runtime::Hash;
+    use std::collections::HashMap;
+
+    /// A block tree held in memory.
+    struct TestChain {
+        blocks: HashMap<Hash, BlockRef>,
+        best: BlockRef,
+        finalized: BlockRef,
+    }
+
+    /// Hash of block `number` on fork `fork`.
+    fn hash(number: BlockNumber, fork: u8) -> Hash {
+        let mut bytes = [0u8; 32];
+        bytes[0] = fork;
+        bytes[28..].copy_from_slice(&number.to_be_bytes());
+        Hash::from(bytes)
+    }
+
+    impl TestChain {
+        fn new() -> Self {
+            let genesis = BlockRef {
+                number: 0,
+                hash: hash(0, 0),
+                parent_hash: Hash::zero(),
+            };
+            Self {
+                blocks: HashMap::from([(genesis.hash, genesis)]),
+                best: genesis,
+                finalized: genesis,
+            }
+        }
+
+        /// Build blocks on fork `fork` from `parent` up to height `to`, and make the last one the
+        /// best block.
+        fn build(&mut self, parent: Hash, fork: u8, to: BlockNumber) -> Hash {
+            let mut parent = self.blocks[&parent];
+            for number in parent.number + 1..=to {
+                let block = BlockRef {
+                    number,
+                    hash: hash(number, fork),
+                    parent_hash: parent.hash,
+                };
+                self.blocks.insert(block.hash, block);
+                parent = block;
+            }
+            self.best = parent;
+            parent.hash
+        }
+
+        fn finalize(&mut self, hash: Hash) {
+            self.finalized = self.blocks[&hash];
+        }
+    }
+
+    impl Chain for TestChain {
+        async fn finalized_head(&self) -> Result<Hash, Error> {
+            Ok(self.finalized.hash)
+        }
+
+        async fn best_block(&self) -> Result<BlockRef, Error> {
+            Ok(self.best)
+        }
+
+        async fn block(&self, hash: Hash) -> Result<BlockRef, Error> {
+            self.blocks
+                .get(&hash)
+                .copied()
+                .ok_or(Error::UnknownBlock(hash))
+        }
+
+        async fn block_hash(&self, number: BlockNumber) -> Result<Option<Hash>, Error> {
+            let mut block = self.best;
+            while block.number > number {
+                block = self.blocks[&block.parent_hash];
+            }
+            Ok((block.number == number).then_some(block.hash))
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Change {
+        Apply(Hash),
+        Revert(Hash),
+        Finalize(Hash),
+    }
+    use Change::*;
+
+    #[derive(Default)]
+    struct TestSink(Vec<Change>);
+
+    impl Sink for TestSink {
+        async fn apply(&mut self, block: &BlockRef) -> Result<(), Error> {
+            self.0.push(Apply(block.hash));
+            Ok(())
+        }
+
+        async fn revert(&mut self, block: &BlockRef) -> Result<(), Error> {
+            self.0.push(Revert(block.hash));
+            Ok(())
+        }
+
+        async fn finalize(&mut self, block: &BlockRef) -> Result<(), Error> {
+            self.0.push(Finalize(block.hash));
+            Ok(())
+        }
+    }
+
+    impl TestSink {
+        fn take(&mut self) -> Vec<Change> {
+            core::mem::take(&mut self.0)
+        }
+    }
+
+    #[tokio::test]
+    async fn follows_best_chain_and_finalizes() {
+        let mut chain = TestChain::new();
+        let mut sink = TestSink::default();
+        let head = chain.build(hash(0, 0), 0, 3);
+        chain.finalize(hash(1, 0));
+
+        let mut checkpoint = Checkpoint::start(&chain, 0).await.unwrap();
+        assert!(checkpoint.advance(&chain, &mut sink, 10).await.unwrap());
+        assert_eq!(
+            sink.take(),
+            [
+                Apply(hash(1, 0)),
+                Finalize(hash(1, 0)),
+                Apply(hash(2, 0)),
+                Apply(hash(3, 0))
+            ]
+        );
+
+        chain.finalize(head);
+        assert!(checkpoint.advance(&chain, &mut sink, 10).await.unwrap());
+        assert_eq!(sink.take(), [Finalize(hash(2, 0)), Finalize(hash(3, 0))]);
+        assert_eq!(checkpoint.finalized.hash, head);
+        assert!(checkpoint.pending.is_empty());
+
+        // Nothing happens without new blocks
+        assert!(checkpoint.advance(&chain, &mut sink, 10).await.unwrap());
+        assert!(sink.take().is_empty());
+    }
+
+    #[tokio::test]
+    async fn reverts_abandoned_fork() {
+        let mut chain = TestChain::new();
+        let mut sink = TestSink::default();
+        chain.build(hash(0, 0), 0, 3);
+        chain.finalize(hash(1, 0));
+        let mut checkpoint = Checkpoint::start(&chain, 1).await.unwrap();
+        checkpoint.advance(&chain, &mut sink, 10).await.unwrap();
+        sink.take();
+
+        // A longer fork from block 1 takes over
+        let head = chain.build(hash(1, 0), 1, 4);
+        checkpoint.advance(&chain, &mut sink, 10).await.unwrap();
+        assert_eq!(
+            sink.take(),
+            [
+                Revert(hash(3, 0)),
+                Revert(hash(2, 0)),
+                Apply(hash(2, 1)),
+                Apply(hash(3, 1)),
+                Apply(hash(4, 1))
+            ]
+        );
+
+        // A fork from a pending block only reverts what comes after it
+        chain.build(hash(2, 1), 2, 5);
+        checkpoint.advance(&chain, &mut sink, 10).await.unwrap();
+        assert_eq!(
+            sink.take(),
+            [
+                Revert(hash(4, 1)),
+                Revert(hash(3, 1)),
+                Apply(hash(3, 2)),
+                Apply(hash(4, 2)),
+                Apply(hash(5, 2))
+            ]
+        );
+
+        // The old fork returns and is finalized
+        chain.best = chain.blocks[&head];
+        chain.finalize(head);
+        checkpoint.advance(&chain, &mut sink, 10).await.unwrap();
+        assert_eq!(
+            sink.take(),
+            [
+                Finalize(hash(2, 1)),
+                Revert(hash(5, 2)),
+                Revert(hash(4, 2)),
+                Revert(hash(3, 2)),
+                Apply(hash(3, 1)),
+                Finalize(hash(3, 1)),
+                Apply(hash(4, 1)),
+                Finalize(hash(4, 1))
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn resumes_from_saved_checkpoint() {
+        let mut chain = TestChain::new();
+        let mut sink = TestSink::default();
+        let head = chain.build(hash(0, 0), 0, 5);
+        chain.finalize(head);
+        let path = std::env::temp_dir().join(format!("indexer-{}.json", std::process::id()));
+
+        let mut checkpoint = Checkpoint::start(&chain, 0).await.unwrap();
+        assert!(!checkpoint.advance(&chain, &mut sink, 2).await.unwrap());
+        checkpoint.save(&path).unwrap();
+        assert_eq!(sink.take().len(), 4);
+
+        let mut resumed = Checkpoint::load(&path).unwrap().unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(resumed, checkpoint);
+        resumed.verify(&chain).await.unwrap();
+        assert!(resumed.advance(&chain, &mut sink, 10).await.unwrap());
+        assert_eq!(
+            sink.take(),
+            [
+                Apply(hash(3, 0)),
+                Finalize(hash(3, 0)),
+                Apply(hash(4, 0)),
+                Finalize(hash(4, 0)),
+                Apply(hash(5, 0)),
+                Finalize(hash(5, 0))
+            ]
+        );
+        assert_eq!(Checkpoint::load(&path).unwrap(), None);
+
+        // A checkpoint of another chain is refused
+        let other = TestChain::new();
+        assert!(matches!(
+            resumed.verify(&other).await,
+            Err(Error::FinalityViolation(5))
+        ));
+    }
+}
@@ -0,0 +1,84 @@
+//! Errors of the indexer.
+
+use mod_net_runtime::{BlockNumber, Hash};
+
+/// Everything that stops the indexer. It exits on any of them and resumes from its last
+/// checkpoint when restarted.
+#[derive(Debug)]
+pub enum Error {
+    /// The RPC connection failed or the node rejected a request.
+    Rpc(jsonrpsee::core::ClientError),
+    /// Storage could not be decoded with this build's runtime types.
+    Decode(codec::Error),
+    /// The checkpoint file could not be read or written.
+    Io(std::io::Error),
+    /// The checkpoint file or a node response is malformed.
+    Json(serde_json::Error),
+    /// The node does not know a block the indexer asked for by hash.
+    UnknownBlock(Hash),
+    /// The node has no block at a height the indexer asked for.
+    MissingBlock(BlockNumber),
+    /// Indexing was asked to start after a block that is not finalized yet.
+    NotFinalized(BlockNumber),
+    /// The node's chain conflicts with a block the indexer already treats as finalized, e.g. a
+    /// checkpoint written against another chain.
+    FinalityViolation(BlockNumber),
+    /// A block was produced by a runtime this indexer was not built against.
+    SpecVersion {
+        /// The spec version of the runtime crate the indexer was built with.
+        expected: u32,
+        /// The spec version of the block.
+        found: u32,
+    },
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Error::Rpc(e) => write!(f, "RPC error: {e}"),
+            Error::Decode(e) => write!(f, "cannot decode storage: {e}"),
+            Error::Io(e) => write!(f, "checkpoint file error: {e}"),
+            Error::Json(e) => write!(f, "malformed JSON: {e}"),
+            Error::UnknownBlock(hash) => write!(f, "the node does not know block {hash:?}"),
+            Error::MissingBlock(number) => write!(f, "the node has no block #{number}"),
+            Error::NotFinalized(number) => write!(f, "block #{number} is not finalized yet"),
+            Error::FinalityViolation(number) => {
+                write!(
+                    f,
+                    "block #{number} conflicts with the indexed finalized chain"
+                )
+            }
+            Error::SpecVersion { expected, found } => write!(
+                f,
+                "block of runtime spec version {found}, but the indexer was built for {expected}; \
+                 rebuild it against the new runtime"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<jsonrpsee::core::ClientError> for Error {
+    fn from(e: jsonrpsee::core::ClientError) -> Self {
+        Error::Rpc(e)
+    }
+}
+
+impl From<codec::Error> for Error {
+    fn from(e: codec::Error) -> Self {
+        Error::Decode(e)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Json(e)
+    }
+}
@@ -0,0 +1,120 @@
+//! # Mod-Net indexer example
+//!
+//! A minimal event indexer for a Mod-Net node, meant as the starting point for production
+//! indexers. It follows the best chain over JSON-RPC, decodes the events of every block with the
+//! runtime's own types and writes them to stdout as JSON lines:
+//!
+//! ```text
+//! {"action":"apply","number":7,"hash":"0x…","events":[{"phase":"ApplyExtrinsic(1)","event":"Mcp(ServerRegistered { … })"}]}
+//! {"action":"revert","number":7,"hash":"0x…"}
+//! {"action":"finalize","number":7,"hash":"0x…"}
+//! ```
+//!
+//! A consumer stores applied events as tentative, deletes them again on `revert` and marks them
+//! final on `finalize`. See [`checkpoint`] for how forks and restarts are handled. A production
+//! indexer replaces [`JsonLines`] with a [`Sink`] that writes to its database, and saves the
+//! checkpoint in the same transaction so that no block is applied twice after a crash. This
+//! example saves it to a file after writing, so blocks may be repeated after a crash.
+
+mod checkpoint;
+mod error;
+mod node;
+
+use checkpoint::{Checkpoint, Sink};
+use clap::Parser;
+use error::Error;
+use mod_net_runtime::BlockNumber;
+use node::{BlockRef, Node};
+use serde_json::json;
+use std::{io::Write, path::PathBuf, time::Duration};
+
+/// Finalized blocks indexed between two checkpoint saves while catching up.
+const CHECKPOINT_EVERY: u32 = 100;
+
+/// Index the events of a Mod-Net chain.
+#[derive(Debug, Parser)]
+struct Cli {
+    /// WebSocket RPC endpoint of the node.
+    #[arg(long, default_value = "ws://127.0.0.1:9944")]
+    url: String,
+
+    /// File the indexer saves its progress to and resumes from.
+    #[arg(long, default_value = "indexer-checkpoint.json")]
+    checkpoint: PathBuf,
+
+    /// Finalized block to start after when there is no checkpoint yet.
+    #[arg(long, default_value_t = 0)]
+    from: BlockNumber,
+
+    /// Seconds to wait for new blocks once the indexer has caught up.
+    #[arg(long, default_value_t = 3)]
+    poll_interval: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Error> {
+    let cli = Cli::parse();
+    let node = Node::connect(&cli.url).await?;
+    let mut checkpoint = match Checkpoint::load(&cli.checkpoint)? {
+        Some(checkpoint) => {
+            checkpoint.verify(&node).await?;
+            checkpoint
+        }
+        None => Checkpoint::start(&node, cli.from).await?,
+    };
+    let mut sink = JsonLines { node: &node };
+    loop {
+        let caught_up = checkpoint
+            .advance(&node, &mut sink, CHECKPOINT_EVERY)
+            .await?;
+        checkpoint.save(&cli.checkpoint)?;
+        if caught_up {
+            tokio::time::sleep(Duration::from_secs(cli.poll_interval)).await;
+        }
+    }
+}
+
+/// Writes every change to stdout as a line of JSON.
+struct JsonLines<'a> {
+    node: &'a Node,
+}
+
+impl JsonLines<'_> {
+    fn write(&self, line: serde_json::Value) -> Result<(), Error> {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer(&mut stdout, &line)?;
+        writeln!(stdout)?;
+        Ok(())
+    }
+}
+
+impl Sink for JsonLines<'_> {
+    async fn apply(&mut self, block: &BlockRef) -> Result<(), Error> {
+        let events: Vec<_> = self
+            .node
+            .events(block.hash)
+            .await?
+            .into_iter()
+            .map(|record| {
+                json!({
+                    "phase": format!("{:?}", record.phase),
+                    "event": format!("{:?}", record.event),
+                })
+            })
+            .collect();
+        self.write(json!({
+            "action": "apply",
+            "number": block.number,
+            "hash": block.hash,
+            "events": events,
+        }))
+    }
+
+    async fn revert(&mut self, block: &BlockRef) -> Result<(), Error> {
+        self.write(json!({ "action": "revert", "number": block.number, "hash": block.hash }))
+    }
+
+    async fn finalize(&mut self, block: &BlockRef) -> Result<(), Error> {
+        self.write(json!({ "action": "finalize", "number": block.number, "hash": block.hash }))
+    }
+}
@@ -0,0 +1,147 @@
+//! Access to the chain through a node's JSON-RPC interface.
+
+use crate::error::Error;
+use codec::Decode;
+use jsonrpsee::{
+    core::client::ClientT,
+    rpc_params,
+    ws_client::{WsClient, WsClientBuilder},
+};
+use mod_net_runtime::{BlockNumber, Hash, Header, RuntimeEvent};
+use serde::{Deserialize, Serialize};
+use sp_core::Bytes;
+
+/// An event as stored in `System::Events`, decoded with this build's runtime types.
+pub type EventRecord = frame_system::EventRecord<RuntimeEvent, Hash>;
+
+/// Position of a block in the chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockRef {
+    /// Height of the block.
+    pub number: BlockNumber,
+    /// Hash of the block.
+    pub hash: Hash,
+    /// Hash of its parent.
+    pub parent_hash: Hash,
+}
+
+impl From<Header> for BlockRef {
+    fn from(header: Header) -> Self {
+        Self {
+            number: header.number,
+            hash: header.hash(),
+            parent_hash: header.parent_hash,
+        }
+    }
+}
+
+/// The chain queries the follower needs.
+pub trait Chain {
+    /// Hash of the latest finalized block.
+    async fn finalized_head(&self) -> Result<Hash, Error>;
+
+    /// Head of the best chain.
+    async fn best_block(&self) -> Result<BlockRef, Error>;
+
+    /// The block with hash `hash`.
+    async fn block(&self, hash: Hash) -> Result<BlockRef, Error>;
+
+    /// Hash of the block at `number` on the best chain, if there is one. Up to the finalized
+    /// head this is the finalized block.
+    async fn block_hash(&self, number: BlockNumber) -> Result<Option<Hash>, Error>;
+}
+
+/// A WebSocket connection to a node.
+pub struct Node {
+    client: WsClient,
+}
+
+impl Node {
+    /// Connect to the node at `url`, e.g. `ws://127.0.0.1:9944`.
+    pub async fn connect(url: &str) -> Result<Self, Error> {
+        Ok(Self {
+            client: WsClientBuilder::default().build(url).await?,
+        })
+    }
+
+    /// The events emitted in block `hash`.
+    ///
+    /// The raw storage is decoded with the runtime crate this indexer was built with, the same
+    /// types the node executes. Blocks of any other runtime version are rejected instead of being
+    /// decoded into garbage.
+    pub async fn events(&self, hash: Hash) -> Result<Vec<EventRecord>, Error> {
+        let version: serde_json::Value = self
+            .client
+            .request("state_getRuntimeVersion", rpc_params![hash])
+            .await?;
+        let expected = mod_net_runtime::VERSION.spec_version;
+        let found = version["specVersion"].as_u64().unwrap_or_default() as u32;
+        if found != expected {
+            return Err(Error::SpecVersion { expected, found });
+        }
+
+        let storage: Option<Bytes> = self
+            .client
+            .request("state_getStorage", rpc_params![Bytes(events_key()), hash])
+            .await?;
+        match storage {
+            Some(bytes) => Ok(Vec::<EventRecord>::decode(&mut &bytes[..])?),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    async fn header(&self, hash: Option<Hash>) -> Result<Option<Header>, Error> {
+        Ok(self
+            .client
+            .request("chain_getHeader", rpc_params![hash])
+            .await?)
+    }
+}
+
+impl Chain for Node {
+    async fn finalized_head(&self) -> Result<Hash, Error> {
+        Ok(self
+            .client
+            .request("chain_getFinalizedHead", rpc_params![])
+            .await?)
+    }
+
+    async fn best_block(&self) -> Result<BlockRef, Error> {
+        let header = self.header(None).await?;
+        Ok(header.ok_or(Error::MissingBlock(0))?.into())
+    }
+
+    async fn block(&self, hash: Hash) -> Result<BlockRef, Error> {
+        let header = self.header(Some(hash)).await?;
+        Ok(header.ok_or(Error::UnknownBlock(hash))?.into())
+    }
+
+    async fn block_hash(&self, number: BlockNumber) -> Result<Option<Hash>, Error> {
+        Ok(self
+            .client
+            .request("chain_getBlockHash", rpc_params![number])
+            .await?)
+    }
+}
+
+/// Storage key of `System::Events`.
+pub fn events_key() -> Vec<u8> {
+    [
+        sp_crypto_hashing::twox_128(b"System"),
+        sp_crypto_hashing::twox_128(b"Events"),
+    ]
+    .concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn events_key_matches_the_well_known_key() {
+        assert_eq!(
+            serde_json::to_value(Bytes(events_key())).unwrap(),
+            "0x26aa394eea5630e07c48ae0c9558cef780d41e5e16056765bc8461851072c9d7"
+        );
+    }
+}
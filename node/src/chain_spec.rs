@@ -1,5 +1,7 @@
 use mod_net_runtime::WASM_BINARY;
+use sc_network::config::MultiaddrWithPeerId;
 use sc_service::ChainType;
+use sc_telemetry::TelemetryEndpoints;
 
 fn load_runtime_wasm() -> Result<Vec<u8>, String> {
     if let Some(b) = WASM_BINARY {
@@ -24,25 +26,86 @@ fn load_runtime_wasm() -> Result<Vec<u8>, String> {
     Err("Development wasm not available".to_string())
 }
 
+/// Boot nodes and telemetry endpoints embedded in a preset, so that joining its network needs no
+/// extra flags.
+///
+/// Nodes can add boot nodes with `--bootnodes` or `--bootnodes-file`, and drop the embedded
+/// telemetry endpoints with `--no-default-telemetry`.
+struct NetworkDefaults {
+    boot_nodes: &'static [&'static str],
+    /// Telemetry URLs with their verbosity level.
+    telemetry: &'static [(&'static str, u8)],
+}
+
+impl NetworkDefaults {
+    fn boot_nodes(&self) -> Result<Vec<MultiaddrWithPeerId>, String> {
+        self.boot_nodes
+            .iter()
+            .map(|addr| {
+                addr.parse()
+                    .map_err(|e| format!("Invalid embedded boot node {addr}: {e}"))
+            })
+            .collect()
+    }
+
+    fn telemetry_endpoints(&self) -> Result<Option<TelemetryEndpoints>, String> {
+        if self.telemetry.is_empty() {
+            return Ok(None);
+        }
+        let endpoints = self
+            .telemetry
+            .iter()
+            .map(|(url, verbosity)| (url.to_string(), *verbosity))
+            .collect();
+        TelemetryEndpoints::new(endpoints)
+            .map(Some)
+            .map_err(|e| format!("Invalid embedded telemetry endpoint: {e}"))
+    }
+}
+
+/// The development chain runs a single authority and reports to no telemetry.
+const DEVELOPMENT_NETWORK: NetworkDefaults = NetworkDefaults {
+    boot_nodes: &[],
+    telemetry: &[],
+};
+
+/// Alice's node of the local testnet, started with `--alice --node-key
+/// 0000000000000000000000000000000000000000000000000000000000000001`, which gives the peer id
+/// below. Other nodes on the same machine then only need `--bob`, `--charlie`, ...
+const LOCAL_NETWORK: NetworkDefaults = NetworkDefaults {
+    boot_nodes: &[
+        "/ip4/127.0.0.1/tcp/30333/p2p/12D3KooWEyoppNCUx8Yx66oV9fJnriXwCcXwDDUA2kj6vnc6iDEp",
+    ],
+    telemetry: &[],
+};
+
 /// Specialized `ChainSpec`. This is a specialization of the general Substrate ChainSpec type.
 pub type ChainSpec = sc_service::GenericChainSpec;
 
 pub fn development_chain_spec() -> Result<ChainSpec, String> {
     let wasm = load_runtime_wasm()?;
-    Ok(ChainSpec::builder(&wasm, None)
+    let mut builder = ChainSpec::builder(&wasm, None)
         .with_name("Development")
         .with_id("dev")
         .with_chain_type(ChainType::Development)
         .with_genesis_config_preset_name(sp_genesis_builder::DEV_RUNTIME_PRESET)
-        .build())
+        .with_boot_nodes(DEVELOPMENT_NETWORK.boot_nodes()?);
+    if let Some(endpoints) = DEVELOPMENT_NETWORK.telemetry_endpoints()? {
+        builder = builder.with_telemetry_endpoints(endpoints);
+    }
+    Ok(builder.build())
 }
 
 pub fn local_chain_spec() -> Result<ChainSpec, String> {
     let wasm = load_runtime_wasm()?;
-    Ok(ChainSpec::builder(&wasm, None)
+    let mut builder = ChainSpec::builder(&wasm, None)
         .with_name("Local Testnet")
         .with_id("local_testnet")
         .with_chain_type(ChainType::Local)
         .with_genesis_config_preset_name(sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET)
-        .build())
+        .with_boot_nodes(LOCAL_NETWORK.boot_nodes()?);
+    if let Some(endpoints) = LOCAL_NETWORK.telemetry_endpoints()? {
+        builder = builder.with_telemetry_endpoints(endpoints);
+    }
+    Ok(builder.build())
 }
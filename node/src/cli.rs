@@ -7,6 +7,19 @@ pub struct Cli {
 
     #[clap(flatten)]
     pub run: RunCmd,
+
+    /// Read additional boot nodes from a file, one multiaddress per line.
+    ///
+    /// Empty lines and lines starting with `#` are ignored. The boot nodes are added to those
+    /// of the chain spec and of `--bootnodes`.
+    #[arg(long, value_name = "PATH")]
+    pub bootnodes_file: Option<std::path::PathBuf>,
+
+    /// Do not report to the telemetry endpoints embedded in the chain spec.
+    ///
+    /// Endpoints passed with `--telemetry-url` are still used.
+    #[arg(long)]
+    pub no_default_telemetry: bool,
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -44,4 +57,7 @@ pub enum Subcommand {
 
     /// Db meta columns information.
     ChainInfo(sc_cli::ChainInfoCmd),
+
+    /// Rebuild the module registry from events and compare it with on-chain state.
+    ReplayRegistry(crate::replay::ReplayRegistryCmd),
 }
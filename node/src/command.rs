@@ -205,9 +205,27 @@ pub fn run() -> sc_cli::Result<()> {
             let runner = cli.create_runner(cmd)?;
             runner.sync_run(|config| cmd.run::<Block>(&config))
         }
+        Some(Subcommand::ReplayRegistry(cmd)) => {
+            let runner = cli.create_runner(cmd)?;
+            runner.sync_run(|config| {
+                let PartialComponents { client, .. } = service::new_partial(&config)?;
+                cmd.run(&client)
+            })
+        }
         None => {
             let runner = cli.create_runner(&cli.run)?;
-            runner.run_node_until_exit(|config| async move {
+            let extra_boot_nodes = match &cli.bootnodes_file {
+                Some(path) => read_boot_nodes(path)?,
+                None => Vec::new(),
+            };
+            let drop_default_telemetry =
+                cli.no_default_telemetry && cli.run.telemetry_params.telemetry_endpoints.is_empty();
+            runner.run_node_until_exit(|mut config| async move {
+                config.network.boot_nodes.extend(extra_boot_nodes);
+                if drop_default_telemetry {
+                    config.telemetry_endpoints = None;
+                }
+
                 match config.network.network_backend {
                     sc_network::config::NetworkBackendType::Libp2p => service::new_full::<
                         sc_network::NetworkWorker<
@@ -225,3 +243,23 @@ pub fn run() -> sc_cli::Result<()> {
         }
     }
 }
+
+/// Parse a `--bootnodes-file`: one multiaddress per line, `#` starts a comment line.
+#[allow(clippy::result_large_err)]
+fn read_boot_nodes(
+    path: &std::path::Path,
+) -> sc_cli::Result<Vec<sc_network::config::MultiaddrWithPeerId>> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| format!("cannot read boot nodes file {}: {e}", path.display()))?;
+    contents
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(number, line)| {
+            line.parse().map_err(|e| {
+                format!("{}:{number}: invalid boot node {line}: {e}", path.display()).into()
+            })
+        })
+        .collect()
+}
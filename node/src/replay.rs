@@ -0,0 +1,273 @@
+//! The `replay-registry` subcommand.
+//!
+//! Rebuilds the module registry purely from `ModuleRegistry` events, block by block, and compares
+//! the result with the registry storage at the target block. Any difference means the events
+//! alone do not describe the state, which is what indexers rely on; this is worth checking after
+//! migrations and when validating an indexer against a node.
+//!
+//! Events are read from each block's state, so all replayed blocks must still have their state:
+//! run the node with `--state-pruning archive` (or `archive-canonical`) to replay old blocks.
+
+use crate::service::FullClient;
+use codec::Decode;
+use mod_net_runtime::{Hash, RuntimeEvent};
+use pallet_module_registry::Event as RegistryEvent;
+use sc_cli::{CliConfiguration, DatabaseParams, PruningParams, SharedParams};
+use sc_client_api::StorageProvider;
+use sp_blockchain::HeaderBackend;
+use sp_core::{storage::StorageKey, twox_128};
+use std::collections::BTreeMap;
+
+/// Byte length of the `Blake2_128Concat` hash that precedes each encoded `Modules` key.
+const BLAKE2_128_LEN: usize = 16;
+
+/// The `replay-registry` subcommand.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ReplayRegistryCmd {
+    /// Block number to replay up to. Defaults to the best block.
+    #[arg(long, value_name = "BLOCK")]
+    pub to: Option<u32>,
+
+    /// Print every applied event.
+    #[arg(long)]
+    pub verbose: bool,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub pruning_params: PruningParams,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub shared_params: SharedParams,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub database_params: DatabaseParams,
+}
+
+/// A difference between the replayed registry and the on-chain registry.
+#[derive(Debug, PartialEq, Eq)]
+enum Divergence {
+    /// The events register a module that is not in storage.
+    MissingInState { key: Vec<u8> },
+    /// Storage holds a module the events never registered.
+    MissingInEvents { key: Vec<u8> },
+    /// Both hold the module, with different CIDs.
+    CidMismatch {
+        key: Vec<u8>,
+        replayed: Vec<u8>,
+        on_chain: Vec<u8>,
+    },
+}
+
+/// Registry contents rebuilt from events.
+#[derive(Default)]
+struct RegistryReplay {
+    modules: BTreeMap<Vec<u8>, Vec<u8>>,
+    /// Events that do not fit the replayed state, e.g. an update of an unknown key.
+    anomalies: Vec<String>,
+    applied: usize,
+}
+
+impl RegistryReplay {
+    fn apply(&mut self, block: u32, event: &RegistryEvent<mod_net_runtime::Runtime>) {
+        match event {
+            RegistryEvent::ModuleRegistered { key, cid, .. } => {
+                if self.modules.insert(key.to_vec(), cid.to_vec()).is_some() {
+                    self.anomaly(block, "registered an existing key", key);
+                }
+            }
+            RegistryEvent::ModuleUpdated { key, cid, .. } => {
+                if self.modules.insert(key.to_vec(), cid.to_vec()).is_none() {
+                    self.anomaly(block, "updated an unknown key", key);
+                }
+            }
+            RegistryEvent::ModuleRemoved { key, .. } => {
+                if self.modules.remove(key.as_slice()).is_none() {
+                    self.anomaly(block, "removed an unknown key", key);
+                }
+            }
+            _ => return,
+        }
+        self.applied += 1;
+    }
+
+    fn anomaly(&mut self, block: u32, what: &str, key: &[u8]) {
+        self.anomalies
+            .push(format!("block #{block}: event {what} 0x{}", hex(key)));
+    }
+
+    /// Compare with the registry as read from storage.
+    fn diff(&self, on_chain: &BTreeMap<Vec<u8>, Vec<u8>>) -> Vec<Divergence> {
+        let mut divergences = Vec::new();
+        for (key, replayed) in &self.modules {
+            match on_chain.get(key) {
+                None => divergences.push(Divergence::MissingInState { key: key.clone() }),
+                Some(cid) if cid != replayed => divergences.push(Divergence::CidMismatch {
+                    key: key.clone(),
+                    replayed: replayed.clone(),
+                    on_chain: cid.clone(),
+                }),
+                Some(_) => {}
+            }
+        }
+        for key in on_chain.keys() {
+            if !self.modules.contains_key(key) {
+                divergences.push(Divergence::MissingInEvents { key: key.clone() });
+            }
+        }
+        divergences
+    }
+}
+
+impl ReplayRegistryCmd {
+    /// Run the `replay-registry` subcommand.
+    #[allow(clippy::result_large_err)]
+    pub fn run(&self, client: &FullClient) -> sc_cli::Result<()> {
+        let best = client.info().best_number;
+        let to = self.to.unwrap_or(best);
+        if to > best {
+            return Err(format!("block #{to} is beyond the best block #{best}").into());
+        }
+
+        let events_key = storage_prefix(b"System", b"Events");
+        let mut replay = RegistryReplay::default();
+
+        for number in 1..=to {
+            let hash = block_hash(client, number)?;
+            let events = client
+                .storage(hash, &events_key)
+                .map_err(|e| state_unavailable(number, e))?
+                .map(|data| {
+                    Vec::<frame_system::EventRecord<RuntimeEvent, Hash>>::decode(&mut &data.0[..])
+                })
+                .transpose()
+                .map_err(|e| format!("cannot decode events of block #{number}: {e}"))?
+                .unwrap_or_default();
+
+            for record in events {
+                if let RuntimeEvent::ModuleRegistry(event) = record.event {
+                    if self.verbose {
+                        println!("#{number}: {event:?}");
+                    }
+                    replay.apply(number, &event);
+                }
+            }
+        }
+
+        let on_chain = registry_state(client, block_hash(client, to)?)
+            .map_err(|e| state_unavailable(to, e))?;
+        let divergences = replay.diff(&on_chain);
+
+        println!(
+            "Replayed {} registry events up to block #{to}: {} modules from events, {} in state",
+            replay.applied,
+            replay.modules.len(),
+            on_chain.len(),
+        );
+        for anomaly in &replay.anomalies {
+            println!("anomaly: {anomaly}");
+        }
+        for divergence in &divergences {
+            match divergence {
+                Divergence::MissingInState { key } => {
+                    println!(
+                        "divergence: 0x{} registered by events, absent in state",
+                        hex(key)
+                    )
+                }
+                Divergence::MissingInEvents { key } => {
+                    println!(
+                        "divergence: 0x{} in state, never registered by events",
+                        hex(key)
+                    )
+                }
+                Divergence::CidMismatch {
+                    key,
+                    replayed,
+                    on_chain,
+                } => println!(
+                    "divergence: 0x{} has CID {} from events but {} in state",
+                    hex(key),
+                    String::from_utf8_lossy(replayed),
+                    String::from_utf8_lossy(on_chain),
+                ),
+            }
+        }
+
+        if divergences.is_empty() && replay.anomalies.is_empty() {
+            println!("Registry state matches the events.");
+            Ok(())
+        } else {
+            Err(format!(
+                "registry replay found {} divergences and {} anomalies",
+                divergences.len(),
+                replay.anomalies.len()
+            )
+            .into())
+        }
+    }
+}
+
+impl CliConfiguration for ReplayRegistryCmd {
+    fn shared_params(&self) -> &SharedParams {
+        &self.shared_params
+    }
+
+    fn pruning_params(&self) -> Option<&PruningParams> {
+        Some(&self.pruning_params)
+    }
+
+    fn database_params(&self) -> Option<&DatabaseParams> {
+        Some(&self.database_params)
+    }
+}
+
+/// Read every `ModuleRegistry::Modules` entry at `hash`.
+fn registry_state(
+    client: &FullClient,
+    hash: Hash,
+) -> sp_blockchain::Result<BTreeMap<Vec<u8>, Vec<u8>>> {
+    let prefix = storage_prefix(b"ModuleRegistry", b"Modules");
+    let mut modules = BTreeMap::new();
+    for (key, value) in client.storage_pairs(hash, Some(&prefix), None)? {
+        let mut encoded_key = key
+            .0
+            .get(prefix.0.len() + BLAKE2_128_LEN..)
+            .unwrap_or_default();
+        let (Ok(key), Ok(cid)) = (
+            Vec::<u8>::decode(&mut encoded_key),
+            Vec::<u8>::decode(&mut &value.0[..]),
+        ) else {
+            return Err(sp_blockchain::Error::Backend(format!(
+                "undecodable registry entry 0x{}",
+                hex(&key.0)
+            )));
+        };
+        modules.insert(key, cid);
+    }
+    Ok(modules)
+}
+
+#[allow(clippy::result_large_err)]
+fn block_hash(client: &FullClient, number: u32) -> sc_cli::Result<Hash> {
+    client
+        .hash(number)?
+        .ok_or_else(|| format!("block #{number} not found").into())
+}
+
+fn storage_prefix(pallet: &[u8], item: &[u8]) -> StorageKey {
+    StorageKey([twox_128(pallet), twox_128(item)].concat())
+}
+
+fn state_unavailable(number: u32, e: sp_blockchain::Error) -> sc_cli::Error {
+    format!(
+        "state of block #{number} is unavailable ({e}); replaying old blocks needs \
+         a node run with --state-pruning archive"
+    )
+    .into()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
@@ -0,0 +1,732 @@
+//! `mcp_*` RPC methods for discovering registered MCP servers.
+//!
+//! Responses are shaped like the objects of the MCP specification (`Tool`, `Prompt`, `Resource`
+//! and `ServerCapabilities`), so an MCP client can deserialize them directly. On-chain
+//! bookkeeping such as identifiers, status and CIDs is placed under `_meta`.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned, INVALID_PARAMS_CODE},
+};
+use mod_net_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Runtime};
+use pallet_mcp::{
+    AccountOverview, ActivitySubject, ExecutionRequest, HealthStatus, PromptId, PromptTemplate,
+    ProtocolVersion, RenderError, RequestId, ResourceId, ResourceInfo, ResourcesCapability,
+    SamplingRequest, SamplingRequestId, ServerCapabilities, ServerId, ServerInfo, ToolId, ToolInfo,
+    TransportConfig, VerificationLevel,
+};
+use pallet_mcp_runtime_api::McpApi as _;
+use serde_json::{json, Map, Value};
+use sp_api::{ApiError, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+/// Number of servers `mcp_listServers` returns when no limit is given, and the most it returns.
+const MAX_PAGE_SIZE: u32 = 100;
+
+/// The runtime API as implemented by this node's runtime.
+pub trait McpRuntimeApi:
+    pallet_mcp_runtime_api::McpApi<
+    Block,
+    AccountId,
+    Balance,
+    ServerInfo<Runtime>,
+    ToolInfo<Runtime>,
+    PromptTemplate<Runtime>,
+    ResourceInfo<Runtime>,
+    HealthStatus<BlockNumber>,
+    AccountOverview<Runtime>,
+    SamplingRequest<Runtime>,
+>
+{
+}
+
+impl<T> McpRuntimeApi for T where
+    T: pallet_mcp_runtime_api::McpApi<
+        Block,
+        AccountId,
+        Balance,
+        ServerInfo<Runtime>,
+        ToolInfo<Runtime>,
+        PromptTemplate<Runtime>,
+        ResourceInfo<Runtime>,
+        HealthStatus<BlockNumber>,
+        AccountOverview<Runtime>,
+        SamplingRequest<Runtime>,
+    >
+{
+}
+
+/// Discovery queries of the MCP server registry.
+#[rpc(server, namespace = "mcp")]
+pub trait McpApi<BlockHash> {
+    /// Servers with an identifier of at least `start`, at most `limit` (capped at 100), optionally
+    /// only those with an average rating of at least `min_reputation` hundredths of a point and
+    /// those verified at least at `min_verification` (`identity`, `reviewed` or `audited`).
+    #[method(name = "listServers")]
+    fn list_servers(
+        &self,
+        start: Option<ServerId>,
+        limit: Option<u32>,
+        min_reputation: Option<u32>,
+        min_verification: Option<String>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<Value>>;
+
+    /// Servers owned by an account.
+    #[method(name = "serversByOwner")]
+    fn servers_by_owner(&self, owner: AccountId, at: Option<BlockHash>) -> RpcResult<Vec<Value>>;
+
+    /// Tools of a server, as MCP `Tool` objects.
+    #[method(name = "toolsByServer")]
+    fn tools_by_server(&self, server_id: ServerId, at: Option<BlockHash>) -> RpcResult<Vec<Value>>;
+
+    /// The fee `caller` would pay for a run of a tool, as a decimal string, or `null` if the
+    /// tool does not exist.
+    #[method(name = "getQuote")]
+    fn get_quote(
+        &self,
+        tool_id: ToolId,
+        caller: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<String>>;
+
+    /// Prompt templates of a server, as MCP `Prompt` objects.
+    #[method(name = "promptsByServer")]
+    fn prompts_by_server(
+        &self,
+        server_id: ServerId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<Value>>;
+
+    /// A prompt rendered with the `arguments` object, as an MCP `GetPromptResult` with a single
+    /// user message.
+    #[method(name = "renderPrompt")]
+    fn render_prompt(
+        &self,
+        prompt_id: PromptId,
+        arguments: Value,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Value>;
+
+    /// Resources of a server, as MCP `Resource` objects.
+    #[method(name = "resourcesByServer")]
+    fn resources_by_server(
+        &self,
+        server_id: ServerId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<Value>>;
+
+    /// Identifiers of the active servers announcing at least the capabilities in `required`, an
+    /// MCP `ServerCapabilities` object, optionally only those implementing `protocol_version`
+    /// (e.g. `2025-06-18`) or a newer revision.
+    #[method(name = "negotiateCapabilities")]
+    fn negotiate_capabilities(
+        &self,
+        required: Value,
+        protocol_version: Option<String>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<ServerId>>;
+
+    /// Capabilities of a server, as an MCP `ServerCapabilities` object.
+    #[method(name = "getServerCapabilities")]
+    fn get_server_capabilities(
+        &self,
+        server_id: ServerId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<Value>>;
+
+    /// Endpoint health of a server, or `null` if it was never probed.
+    #[method(name = "getServerHealth")]
+    fn get_server_health(
+        &self,
+        server_id: ServerId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<Value>>;
+
+    /// What an account has in the registry: its servers and their tools (as MCP `Tool` objects),
+    /// its tool and resource subscriptions, the unsettled execution requests it made or has to
+    /// answer, and its latest activity, oldest first.
+    #[method(name = "accountOverview")]
+    fn account_overview(&self, account: AccountId, at: Option<BlockHash>) -> RpcResult<Value>;
+
+    /// Unanswered sampling requests to a server, oldest first, shaped like the parameters of an
+    /// MCP `sampling/createMessage` request. The messages to complete are stored at `promptCid`
+    /// under `_meta`.
+    #[method(name = "pendingSamplingRequests")]
+    fn pending_sampling_requests(
+        &self,
+        server_id: ServerId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<Value>>;
+
+    /// The identifiers the next server, tool, prompt, resource, execution request and sampling
+    /// request will be assigned, so a client can refer to an item before submitting it.
+    #[method(name = "nextIds")]
+    fn next_ids(&self, at: Option<BlockHash>) -> RpcResult<Value>;
+}
+
+/// Implementation of the `mcp_*` RPC methods.
+pub struct Mcp<C> {
+    client: Arc<C>,
+}
+
+impl<C> Mcp<C> {
+    /// Create the RPC handler over `client`.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+}
+
+impl<C> McpApiServer<<Block as BlockT>::Hash> for Mcp<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: McpRuntimeApi,
+{
+    fn list_servers(
+        &self,
+        start: Option<ServerId>,
+        limit: Option<u32>,
+        min_reputation: Option<u32>,
+        min_verification: Option<String>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<Value>> {
+        let limit = limit.unwrap_or(MAX_PAGE_SIZE).min(MAX_PAGE_SIZE);
+        let min_verification = min_verification
+            .map(|level| {
+                VerificationLevel::parse(&level).ok_or_else(|| {
+                    ErrorObject::owned(
+                        INVALID_PARAMS_CODE,
+                        "Unknown verification level.",
+                        Some(level),
+                    )
+                })
+            })
+            .transpose()?;
+        let at = self.at(at);
+        let servers = self
+            .client
+            .runtime_api()
+            .list_servers(
+                at,
+                start.unwrap_or_default(),
+                limit,
+                min_reputation,
+                min_verification,
+            )
+            .map_err(runtime_error)?;
+        self.servers_json(at, servers)
+    }
+
+    fn servers_by_owner(
+        &self,
+        owner: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<Value>> {
+        let at = self.at(at);
+        let servers = self
+            .client
+            .runtime_api()
+            .servers_by_owner(at, owner)
+            .map_err(runtime_error)?;
+        self.servers_json(at, servers)
+    }
+
+    fn tools_by_server(
+        &self,
+        server_id: ServerId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<Value>> {
+        let tools = self
+            .client
+            .runtime_api()
+            .tools_by_server(self.at(at), server_id)
+            .map_err(runtime_error)?;
+        Ok(tools
+            .into_iter()
+            .map(|(id, tool)| tool_json(id, tool))
+            .collect())
+    }
+
+    fn get_quote(
+        &self,
+        tool_id: ToolId,
+        caller: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<String>> {
+        let fee = self
+            .client
+            .runtime_api()
+            .get_quote(self.at(at), tool_id, caller)
+            .map_err(runtime_error)?;
+        Ok(fee.map(|fee| fee.to_string()))
+    }
+
+    fn prompts_by_server(
+        &self,
+        server_id: ServerId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<Value>> {
+        let prompts = self
+            .client
+            .runtime_api()
+            .prompts_by_server(self.at(at), server_id)
+            .map_err(runtime_error)?;
+        Ok(prompts
+            .into_iter()
+            .map(|(id, prompt)| prompt_json(id, prompt))
+            .collect())
+    }
+
+    fn render_prompt(
+        &self,
+        prompt_id: PromptId,
+        arguments: Value,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Value> {
+        let rendered = self
+            .client
+            .runtime_api()
+            .render_prompt(self.at(at), prompt_id, arguments.to_string().into_bytes())
+            .map_err(runtime_error)?
+            .map_err(render_error)?;
+        Ok(json!({
+            "messages": [{
+                "role": "user",
+                "content": { "type": "text", "text": text(&rendered) },
+            }],
+        }))
+    }
+
+    fn resources_by_server(
+        &self,
+        server_id: ServerId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<Value>> {
+        let resources = self
+            .client
+            .runtime_api()
+            .resources_by_server(self.at(at), server_id)
+            .map_err(runtime_error)?;
+        Ok(resources
+            .into_iter()
+            .map(|(id, resource)| resource_json(id, resource))
+            .collect())
+    }
+
+    fn negotiate_capabilities(
+        &self,
+        required: Value,
+        protocol_version: Option<String>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<ServerId>> {
+        let min_protocol_version = protocol_version
+            .map(|revision| {
+                ProtocolVersion::parse(&revision).ok_or_else(|| {
+                    ErrorObject::owned(
+                        INVALID_PARAMS_CODE,
+                        "Unknown protocol revision.",
+                        Some(revision),
+                    )
+                })
+            })
+            .transpose()?;
+        self.client
+            .runtime_api()
+            .negotiate_capabilities(
+                self.at(at),
+                capabilities_from_json(&required),
+                min_protocol_version,
+            )
+            .map_err(runtime_error)
+    }
+
+    fn get_server_capabilities(
+        &self,
+        server_id: ServerId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<Value>> {
+        let capabilities = self
+            .client
+            .runtime_api()
+            .get_server_capabilities(self.at(at), server_id)
+            .map_err(runtime_error)?;
+        Ok(capabilities.map(capabilities_json))
+    }
+
+    fn get_server_health(
+        &self,
+        server_id: ServerId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<Value>> {
+        let health = self
+            .client
+            .runtime_api()
+            .get_server_health(self.at(at), server_id)
+            .map_err(runtime_error)?;
+        Ok(health.map(health_json))
+    }
+
+    fn account_overview(
+        &self,
+        account: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Value> {
+        let at = self.at(at);
+        let overview = self
+            .client
+            .runtime_api()
+            .get_account_overview(at, account)
+            .map_err(runtime_error)?;
+        let activity: Vec<_> = overview
+            .activity
+            .into_iter()
+            .map(|record| {
+                json!({
+                    "block": record.block,
+                    "extrinsicIndex": record.extrinsic_index,
+                    "subject": subject_json(record.subject),
+                })
+            })
+            .collect();
+        let tool_subscriptions: Vec<_> = overview
+            .tool_subscriptions
+            .into_iter()
+            .map(|(tool_id, until)| json!({ "toolId": tool_id, "until": until }))
+            .collect();
+        Ok(json!({
+            "servers": self.servers_json(at, overview.servers)?,
+            "tools": overview
+                .tools
+                .into_iter()
+                .map(|(id, tool)| tool_json(id, tool))
+                .collect::<Vec<_>>(),
+            "subscriptions": {
+                "tools": tool_subscriptions,
+                "resources": overview.resource_subscriptions,
+            },
+            "pendingExecutions": {
+                "requested": requests_json(overview.requested),
+                "assigned": requests_json(overview.assigned),
+            },
+            "activity": activity,
+        }))
+    }
+
+    fn pending_sampling_requests(
+        &self,
+        server_id: ServerId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<Value>> {
+        let requests = self
+            .client
+            .runtime_api()
+            .pending_sampling_requests(self.at(at), server_id)
+            .map_err(runtime_error)?;
+        Ok(requests
+            .into_iter()
+            .map(|(id, request)| sampling_json(id, request))
+            .collect())
+    }
+
+    fn next_ids(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Value> {
+        let ids = self
+            .client
+            .runtime_api()
+            .get_next_ids(self.at(at))
+            .map_err(runtime_error)?;
+        Ok(json!({
+            "serverId": ids.server,
+            "toolId": ids.tool,
+            "promptId": ids.prompt,
+            "resourceId": ids.resource,
+            "requestId": ids.request,
+            "samplingRequestId": ids.sampling_request,
+        }))
+    }
+}
+
+impl<C: HeaderBackend<Block>> Mcp<C> {
+    /// The requested block, or the best block.
+    fn at(&self, at: Option<<Block as BlockT>::Hash>) -> <Block as BlockT>::Hash {
+        at.unwrap_or_else(|| self.client.info().best_hash)
+    }
+}
+
+impl<C> Mcp<C>
+where
+    C: ProvideRuntimeApi<Block>,
+    C::Api: McpRuntimeApi,
+{
+    /// Servers as MCP server descriptions, with their verification level at block `at`.
+    fn servers_json(
+        &self,
+        at: <Block as BlockT>::Hash,
+        servers: Vec<(ServerId, ServerInfo<Runtime>)>,
+    ) -> RpcResult<Vec<Value>> {
+        let api = self.client.runtime_api();
+        servers
+            .into_iter()
+            .map(|(id, server)| {
+                let verification = api.get_server_verification(at, id).map_err(runtime_error)?;
+                Ok(server_json(id, server, verification))
+            })
+            .collect()
+    }
+}
+
+/// Error code of a failed runtime API call.
+const RUNTIME_ERROR: i32 = 1;
+
+fn runtime_error(err: ApiError) -> ErrorObjectOwned {
+    ErrorObject::owned(
+        RUNTIME_ERROR,
+        "Unable to query the MCP registry.",
+        Some(err.to_string()),
+    )
+}
+
+/// Error code of a prompt that cannot be rendered with the given arguments.
+const RENDER_ERROR: i32 = 2;
+
+fn render_error(err: RenderError) -> ErrorObjectOwned {
+    let message = match err {
+        RenderError::PromptNotFound => "The prompt does not exist.".into(),
+        RenderError::InvalidTemplate => "The prompt template is malformed.".into(),
+        RenderError::InvalidSchema => "The prompt's parameter schema is malformed.".into(),
+        RenderError::InvalidParams => {
+            "Arguments must be an object of strings, numbers and booleans.".into()
+        }
+        RenderError::MissingParam(name) => format!("Missing argument `{}`.", text(&name)),
+        RenderError::UnknownParam(name) => format!("Unknown argument `{}`.", text(&name)),
+        RenderError::InvalidParam(name) => format!("Invalid argument `{}`.", text(&name)),
+    };
+    ErrorObject::owned(RENDER_ERROR, message, None::<()>)
+}
+
+fn text(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes).into_owned()
+}
+
+/// A stored JSON Schema as JSON; schemas that do not parse are returned as strings.
+fn schema(bytes: &[u8]) -> Value {
+    serde_json::from_slice(bytes).unwrap_or_else(|_| Value::String(text(bytes)))
+}
+
+fn server_json(
+    id: ServerId,
+    server: ServerInfo<Runtime>,
+    verification: Option<VerificationLevel>,
+) -> Value {
+    let transport = match &server.transport {
+        TransportConfig::Stdio => json!({ "type": "stdio" }),
+        TransportConfig::Http { url } => json!({ "type": "streamable-http", "url": text(url) }),
+        TransportConfig::Sse { url } => json!({ "type": "sse", "url": text(url) }),
+        TransportConfig::WebSocket { url } => json!({ "type": "websocket", "url": text(url) }),
+    };
+    json!({
+        "name": text(&server.name),
+        "description": text(&server.description),
+        "protocolVersion": server.protocol_version.as_str(),
+        "capabilities": capabilities_json(server.capabilities),
+        "transport": transport,
+        "privateNetwork": server.private_network,
+        "accessPolicy": server.access_policy.as_ref().map(|policy| json!({
+            "cid": text(&policy.cid),
+            "hash": format!("{:?}", policy.hash),
+        })),
+        "_meta": {
+            "id": id,
+            "owner": server.owner.to_string(),
+            "deposit": server.deposit.to_string(),
+            "active": server.active,
+            "verification": verification.map(|level| level.as_str()),
+            "registeredAt": server.registered_at,
+            "expiresAt": server.expires_at,
+            "metadataCid": server.metadata_cid.as_ref().map(|cid| text(cid)),
+        },
+    })
+}
+
+fn capabilities_json(capabilities: ServerCapabilities) -> Value {
+    let mut object = Map::new();
+    if capabilities.tools {
+        object.insert("tools".into(), json!({}));
+    }
+    if capabilities.prompts {
+        object.insert("prompts".into(), json!({}));
+    }
+    if let Some(resources) = capabilities.resources {
+        object.insert(
+            "resources".into(),
+            json!({
+                "subscribe": resources.subscribe,
+                "listChanged": resources.list_changed,
+            }),
+        );
+    }
+    if capabilities.logging {
+        object.insert("logging".into(), json!({}));
+    }
+    // Sampling is a client capability in the specification; servers announce that they use it
+    // as an experimental one
+    if capabilities.sampling {
+        object.insert("experimental".into(), json!({ "sampling": {} }));
+    }
+    Value::Object(object)
+}
+
+/// The capabilities listed in an MCP `ServerCapabilities` object, the inverse of
+/// [`capabilities_json`]. Unknown capabilities are ignored.
+fn capabilities_from_json(object: &Value) -> ServerCapabilities {
+    let resources = &object["resources"];
+    ServerCapabilities {
+        tools: !object["tools"].is_null(),
+        prompts: !object["prompts"].is_null(),
+        resources: resources.is_object().then(|| ResourcesCapability {
+            subscribe: resources["subscribe"].as_bool().unwrap_or_default(),
+            list_changed: resources["listChanged"].as_bool().unwrap_or_default(),
+        }),
+        logging: !object["logging"].is_null(),
+        sampling: !object["experimental"]["sampling"].is_null(),
+    }
+}
+
+fn health_json(health: HealthStatus<BlockNumber>) -> Value {
+    json!({
+        "healthy": health.healthy,
+        "latencyMs": health.latency_ms,
+        "consecutiveFailures": health.consecutive_failures,
+        "lastReport": health.last_report,
+    })
+}
+
+fn requests_json(requests: Vec<(RequestId, ExecutionRequest<Runtime>)>) -> Vec<Value> {
+    requests
+        .into_iter()
+        .map(|(id, request)| {
+            json!({
+                "id": id,
+                "requester": request.requester.to_string(),
+                "toolId": request.tool_id,
+                "serverId": request.server_id,
+                "inputCid": text(&request.input_cid),
+                "fee": request.fee.to_string(),
+                "deadline": request.deadline,
+            })
+        })
+        .collect()
+}
+
+fn sampling_json(id: SamplingRequestId, request: SamplingRequest<Runtime>) -> Value {
+    let preferences = request.model_preferences;
+    let mut model_preferences = json!({
+        "hints": preferences
+            .hints
+            .iter()
+            .map(|hint| json!({ "name": text(hint) }))
+            .collect::<Vec<_>>(),
+    });
+    for (key, priority) in [
+        ("costPriority", preferences.cost_priority),
+        ("speedPriority", preferences.speed_priority),
+        ("intelligencePriority", preferences.intelligence_priority),
+    ] {
+        if let Some(priority) = priority {
+            model_preferences[key] = (f64::from(priority.deconstruct()) / 100.0).into();
+        }
+    }
+    json!({
+        "modelPreferences": model_preferences,
+        "_meta": {
+            "id": id,
+            "serverId": request.server_id,
+            "requester": request.requester.to_string(),
+            "promptCid": text(&request.prompt_cid),
+            "deadline": request.deadline,
+        },
+    })
+}
+
+fn subject_json(subject: ActivitySubject) -> Value {
+    match subject {
+        ActivitySubject::Server(id) => json!({ "server": id }),
+        ActivitySubject::Tool(id) => json!({ "tool": id }),
+        ActivitySubject::Prompt(id) => json!({ "prompt": id }),
+        ActivitySubject::Resource(id) => json!({ "resource": id }),
+        ActivitySubject::Execution(id) => json!({ "execution": id }),
+        ActivitySubject::Sampling(id) => json!({ "sampling": id }),
+    }
+}
+
+fn tool_json(id: ToolId, tool: ToolInfo<Runtime>) -> Value {
+    let mut value = json!({
+        "name": text(&tool.name),
+        "description": text(&tool.description),
+        "inputSchema": schema(&tool.input_schema),
+        "_meta": { "id": id, "serverId": tool.server_id, "active": tool.active },
+    });
+    if let Some(output) = &tool.output_schema {
+        value["outputSchema"] = schema(output);
+    }
+    value
+}
+
+fn prompt_json(id: PromptId, prompt: PromptTemplate<Runtime>) -> Value {
+    let arguments = prompt
+        .parameter_schema
+        .as_deref()
+        .map(|bytes| prompt_arguments(&schema(bytes)))
+        .unwrap_or_default();
+    json!({
+        "name": text(&prompt.name),
+        "description": text(&prompt.description),
+        "arguments": arguments,
+        "_meta": {
+            "id": id,
+            "serverId": prompt.server_id,
+            "template": text(&prompt.template),
+            "metadataCid": prompt.metadata_cid.as_ref().map(|cid| text(cid)),
+        },
+    })
+}
+
+/// MCP `PromptArgument`s for the properties of an object parameter schema.
+fn prompt_arguments(schema: &Value) -> Vec<Value> {
+    let required = schema["required"].as_array();
+    let Some(properties) = schema["properties"].as_object() else {
+        return Vec::new();
+    };
+    properties
+        .iter()
+        .map(|(name, property)| {
+            let is_required = required.is_some_and(|r| r.iter().any(|n| n == name));
+            let mut argument = json!({ "name": name, "required": is_required });
+            if let Some(description) = property["description"].as_str() {
+                argument["description"] = description.into();
+            }
+            argument
+        })
+        .collect()
+}
+
+fn resource_json(id: ResourceId, resource: ResourceInfo<Runtime>) -> Value {
+    let mut value = json!({
+        "uri": text(&resource.uri),
+        "name": text(&resource.name),
+        "description": text(&resource.description),
+        "_meta": {
+            "id": id,
+            "serverId": resource.server_id,
+            "contentCid": text(&resource.content_cid),
+            "metadataCid": resource.metadata_cid.as_ref().map(|cid| text(cid)),
+        },
+    });
+    if let Some(mime_type) = &resource.mime_type {
+        value["mimeType"] = text(mime_type).into();
+    }
+    value
+}
@@ -0,0 +1,62 @@
+//! Benchmarking setup for pallet-agent-channels
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as AgentChannels;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+
+fn open_channel_of<T: Config>(initiator: &T::AccountId) -> ChannelId {
+    let id = NextChannelId::<T>::get();
+    AgentChannels::<T>::open_channel(
+        RawOrigin::Signed(initiator.clone()).into(),
+        account("counterparty", 0, 0),
+    )
+    .expect("channel opens");
+    id
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn open_channel() {
+        let caller: T::AccountId = whitelisted_caller();
+        let counterparty: T::AccountId = account("counterparty", 0, 0);
+
+        #[extrinsic_call]
+        open_channel(RawOrigin::Signed(caller.clone()), counterparty);
+
+        assert_eq!(OpenChannels::<T>::get(&caller), 1);
+    }
+
+    #[benchmark]
+    fn anchor_batch() {
+        let caller: T::AccountId = whitelisted_caller();
+        let id = open_channel_of::<T>(&caller);
+
+        #[extrinsic_call]
+        anchor_batch(RawOrigin::Signed(caller), id, T::Hash::default(), 10);
+
+        assert_eq!(Channels::<T>::get(id).unwrap().anchors, 1);
+    }
+
+    #[benchmark]
+    fn close_channel() {
+        let caller: T::AccountId = whitelisted_caller();
+        let id = open_channel_of::<T>(&caller);
+
+        #[extrinsic_call]
+        close_channel(RawOrigin::Signed(caller.clone()), id, T::Hash::default());
+
+        assert_eq!(OpenChannels::<T>::get(&caller), 0);
+    }
+
+    impl_benchmark_test_suite!(
+        AgentChannels,
+        crate::mock::new_test_ext(),
+        crate::mock::Test
+    );
+}
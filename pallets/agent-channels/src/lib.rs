@@ -0,0 +1,357 @@
+//! # Agent Channels Pallet
+//!
+//! A Substrate pallet for anchoring agent-to-agent (A2A) message channels.
+//!
+//! ## Overview
+//!
+//! Agents negotiating with each other exchange messages off-chain. This pallet gives such a
+//! conversation an on-chain evidence trail without storing any message content:
+//! - Two accounts open a channel
+//! - Either participant periodically anchors the hash of the latest batch of messages
+//! - Either participant closes the channel with a hash of the final agreed state
+//!
+//! Every anchor is folded into a hash chain, `head = hash(head, batch_hash, message_count)`, so
+//! the channel only keeps the current head in storage. A participant holding the batches can
+//! prove to a third party that they were anchored, in order, by recomputing the head with
+//! [`Pallet::anchor_chain_head`]. Each anchor is also emitted as an event with its sequence
+//! number.
+//!
+//! ## Functionality
+//!
+//! - `open_channel`: Open a channel with another account
+//! - `anchor_batch`: Anchor the hash of a batch of messages
+//! - `close_channel`: Close a channel with a final state hash
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::traits::Hash;
+
+    /// Identifier of a channel.
+    pub type ChannelId = u64;
+
+    /// Whether a channel still accepts anchors.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum ChannelStatus<AccountId, Hash, BlockNumber> {
+        /// The channel accepts anchors.
+        Open,
+        /// The channel was closed and its evidence trail is final.
+        Closed {
+            /// Hash of the final state agreed in the conversation.
+            final_state: Hash,
+            /// The participant that closed the channel.
+            closed_by: AccountId,
+            /// Block at which the channel was closed.
+            closed_at: BlockNumber,
+        },
+    }
+
+    /// A message channel between two accounts.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Channel<AccountId, Hash, BlockNumber> {
+        /// The account that opened the channel.
+        pub initiator: AccountId,
+        /// The other participant.
+        pub counterparty: AccountId,
+        /// Block at which the channel was opened.
+        pub opened_at: BlockNumber,
+        /// Head of the anchor hash chain; the default hash until the first anchor.
+        pub anchor_head: Hash,
+        /// Number of anchored batches.
+        pub anchors: u32,
+        /// Total number of messages covered by the anchored batches.
+        pub messages: u64,
+        /// Whether the channel is still open.
+        pub status: ChannelStatus<AccountId, Hash, BlockNumber>,
+    }
+
+    impl<AccountId: PartialEq, Hash, BlockNumber> Channel<AccountId, Hash, BlockNumber> {
+        /// Whether `who` is one of the two participants.
+        pub fn is_participant(&self, who: &AccountId) -> bool {
+            &self.initiator == who || &self.counterparty == who
+        }
+    }
+
+    /// Channel as stored by the pallet.
+    pub type ChannelOf<T> = Channel<
+        <T as frame_system::Config>::AccountId,
+        <T as frame_system::Config>::Hash,
+        BlockNumberFor<T>,
+    >;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// The pallet's configuration trait.
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+        /// Maximum number of open channels an account may have initiated at the same time
+        #[pallet::constant]
+        type MaxOpenChannels: Get<u32>;
+    }
+
+    /// The identifier assigned to the next opened channel.
+    #[pallet::storage]
+    pub type NextChannelId<T: Config> = StorageValue<_, ChannelId, ValueQuery>;
+
+    /// Channels by identifier, including closed ones.
+    #[pallet::storage]
+    pub type Channels<T: Config> =
+        StorageMap<_, Twox64Concat, ChannelId, ChannelOf<T>, OptionQuery>;
+
+    /// Number of open channels initiated by each account.
+    #[pallet::storage]
+    pub type OpenChannels<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Events emitted by this pallet.
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A channel was opened.
+        ChannelOpened {
+            /// The new channel.
+            id: ChannelId,
+            /// The account that opened the channel.
+            initiator: T::AccountId,
+            /// The other participant.
+            counterparty: T::AccountId,
+        },
+        /// A batch of messages was anchored.
+        BatchAnchored {
+            /// The channel.
+            id: ChannelId,
+            /// Position of the batch in the channel, starting at 1.
+            sequence: u32,
+            /// Hash of the batch.
+            batch_hash: T::Hash,
+            /// Number of messages in the batch.
+            message_count: u32,
+            /// New head of the anchor hash chain.
+            anchor_head: T::Hash,
+            /// The participant that anchored the batch.
+            who: T::AccountId,
+        },
+        /// A channel was closed.
+        ChannelClosed {
+            /// The channel.
+            id: ChannelId,
+            /// Hash of the final agreed state.
+            final_state: T::Hash,
+            /// Final head of the anchor hash chain.
+            anchor_head: T::Hash,
+            /// The participant that closed the channel.
+            who: T::AccountId,
+        },
+    }
+
+    /// Errors that can be returned by this pallet.
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The channel does not exist.
+        ChannelNotFound,
+        /// The caller is not a participant of the channel.
+        NotParticipant,
+        /// The channel is already closed.
+        ChannelClosed,
+        /// An account cannot open a channel with itself.
+        SelfChannel,
+        /// The caller has too many open channels.
+        TooManyOpenChannels,
+        /// A batch must contain at least one message.
+        EmptyBatch,
+    }
+
+    /// Dispatchable functions for the agent channels pallet.
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Open a channel between the caller and `counterparty`.
+        ///
+        /// Only channels the caller initiated count towards its `MaxOpenChannels` limit, so
+        /// accounts cannot be locked out by others opening channels to them.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (must be signed)
+        /// * `counterparty` - The other participant
+        ///
+        /// # Errors
+        /// * `SelfChannel` - If `counterparty` is the caller
+        /// * `TooManyOpenChannels` - If the caller already has `MaxOpenChannels` open channels
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::open_channel())]
+        pub fn open_channel(origin: OriginFor<T>, counterparty: T::AccountId) -> DispatchResult {
+            let initiator = ensure_signed(origin)?;
+            ensure!(initiator != counterparty, Error::<T>::SelfChannel);
+
+            OpenChannels::<T>::try_mutate(&initiator, |open| -> DispatchResult {
+                ensure!(
+                    *open < T::MaxOpenChannels::get(),
+                    Error::<T>::TooManyOpenChannels
+                );
+                *open += 1;
+                Ok(())
+            })?;
+
+            let id = NextChannelId::<T>::get();
+            NextChannelId::<T>::put(id.saturating_add(1));
+            Channels::<T>::insert(
+                id,
+                Channel {
+                    initiator: initiator.clone(),
+                    counterparty: counterparty.clone(),
+                    opened_at: frame_system::Pallet::<T>::block_number(),
+                    anchor_head: T::Hash::default(),
+                    anchors: 0,
+                    messages: 0,
+                    status: ChannelStatus::Open,
+                },
+            );
+
+            Self::deposit_event(Event::ChannelOpened {
+                id,
+                initiator,
+                counterparty,
+            });
+
+            Ok(())
+        }
+
+        /// Anchor the hash of a batch of messages exchanged on the channel.
+        ///
+        /// How batches are formed and hashed is up to the participants; the pallet only
+        /// chains the hashes together in submission order.
+        ///
+        /// # Errors
+        /// * `ChannelNotFound` - If the channel does not exist
+        /// * `NotParticipant` - If the caller is not a participant
+        /// * `ChannelClosed` - If the channel is closed
+        /// * `EmptyBatch` - If `message_count` is zero
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::anchor_batch())]
+        pub fn anchor_batch(
+            origin: OriginFor<T>,
+            id: ChannelId,
+            batch_hash: T::Hash,
+            message_count: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(message_count > 0, Error::<T>::EmptyBatch);
+
+            let (sequence, anchor_head) =
+                Channels::<T>::try_mutate(id, |channel| -> Result<_, DispatchError> {
+                    let channel = Self::open_channel_of(channel, &who)?;
+                    channel.anchor_head =
+                        Self::next_anchor_head(channel.anchor_head, batch_hash, message_count);
+                    channel.anchors = channel.anchors.saturating_add(1);
+                    channel.messages = channel.messages.saturating_add(message_count as u64);
+                    Ok((channel.anchors, channel.anchor_head))
+                })?;
+
+            Self::deposit_event(Event::BatchAnchored {
+                id,
+                sequence,
+                batch_hash,
+                message_count,
+                anchor_head,
+                who,
+            });
+
+            Ok(())
+        }
+
+        /// Close a channel with the hash of the final agreed state.
+        ///
+        /// The channel record is kept so that its anchor head can still be checked later.
+        ///
+        /// # Errors
+        /// * `ChannelNotFound` - If the channel does not exist
+        /// * `NotParticipant` - If the caller is not a participant
+        /// * `ChannelClosed` - If the channel is already closed
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::close_channel())]
+        pub fn close_channel(
+            origin: OriginFor<T>,
+            id: ChannelId,
+            final_state: T::Hash,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let (initiator, anchor_head) =
+                Channels::<T>::try_mutate(id, |channel| -> Result<_, DispatchError> {
+                    let channel = Self::open_channel_of(channel, &who)?;
+                    channel.status = ChannelStatus::Closed {
+                        final_state,
+                        closed_by: who.clone(),
+                        closed_at: frame_system::Pallet::<T>::block_number(),
+                    };
+                    Ok((channel.initiator.clone(), channel.anchor_head))
+                })?;
+            OpenChannels::<T>::mutate(&initiator, |open| *open = open.saturating_sub(1));
+
+            Self::deposit_event(Event::ChannelClosed {
+                id,
+                final_state,
+                anchor_head,
+                who,
+            });
+
+            Ok(())
+        }
+    }
+
+    /// Helper functions for the anchor hash chain.
+    impl<T: Config> Pallet<T> {
+        /// Fold one batch into the anchor hash chain.
+        pub fn next_anchor_head(head: T::Hash, batch_hash: T::Hash, message_count: u32) -> T::Hash {
+            T::Hashing::hash_of(&(head, batch_hash, message_count))
+        }
+
+        /// Recompute the anchor head of a channel from its batches, in anchoring order.
+        ///
+        /// Compare the result with the channel's `anchor_head` (or the head emitted with a
+        /// `BatchAnchored` event) to prove that exactly these batches were anchored.
+        pub fn anchor_chain_head<'a>(
+            batches: impl IntoIterator<Item = &'a (T::Hash, u32)>,
+        ) -> T::Hash {
+            batches
+                .into_iter()
+                .fold(T::Hash::default(), |head, (batch_hash, count)| {
+                    Self::next_anchor_head(head, *batch_hash, *count)
+                })
+        }
+
+        /// Ensure the channel exists, is open and has `who` as a participant.
+        fn open_channel_of<'a>(
+            channel: &'a mut Option<ChannelOf<T>>,
+            who: &T::AccountId,
+        ) -> Result<&'a mut ChannelOf<T>, Error<T>> {
+            let channel = channel.as_mut().ok_or(Error::<T>::ChannelNotFound)?;
+            ensure!(channel.is_participant(who), Error::<T>::NotParticipant);
+            ensure!(
+                channel.status == ChannelStatus::Open,
+                Error::<T>::ChannelClosed
+            );
+            Ok(channel)
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{mock::*, ChannelStatus, Channels, Error, Event, OpenChannels};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H256;
+extern crate alloc;
+use alloc::vec;
+
+fn open(initiator: u64, counterparty: u64) -> u64 {
+    let id = crate::NextChannelId::<Test>::get();
+    assert_ok!(AgentChannels::open_channel(
+        RuntimeOrigin::signed(initiator),
+        counterparty
+    ));
+    id
+}
+
+#[test]
+fn open_channel_works() {
+    new_test_ext().execute_with(|| {
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        let id = open(1, 2);
+
+        let channel = Channels::<Test>::get(id).unwrap();
+        assert_eq!((channel.initiator, channel.counterparty), (1, 2));
+        assert_eq!(channel.anchor_head, H256::zero());
+        assert_eq!(channel.status, ChannelStatus::Open);
+        assert_eq!(OpenChannels::<Test>::get(1), 1);
+        assert_eq!(OpenChannels::<Test>::get(2), 0);
+
+        System::assert_last_event(
+            Event::ChannelOpened {
+                id,
+                initiator: 1,
+                counterparty: 2,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn open_channel_checks_counterparty_and_limit() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            AgentChannels::open_channel(RuntimeOrigin::signed(1), 1),
+            Error::<Test>::SelfChannel
+        );
+
+        open(1, 2);
+        open(1, 3);
+        assert_noop!(
+            AgentChannels::open_channel(RuntimeOrigin::signed(1), 4),
+            Error::<Test>::TooManyOpenChannels
+        );
+
+        // Channels opened towards an account do not count against its own limit
+        open(2, 1);
+    });
+}
+
+#[test]
+fn anchor_batch_extends_the_hash_chain() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let id = open(1, 2);
+        let batches = [(H256::repeat_byte(1), 3u32), (H256::repeat_byte(2), 5u32)];
+
+        assert_ok!(AgentChannels::anchor_batch(
+            RuntimeOrigin::signed(1),
+            id,
+            batches[0].0,
+            batches[0].1
+        ));
+        assert_ok!(AgentChannels::anchor_batch(
+            RuntimeOrigin::signed(2),
+            id,
+            batches[1].0,
+            batches[1].1
+        ));
+
+        let channel = Channels::<Test>::get(id).unwrap();
+        let expected_head = AgentChannels::anchor_chain_head(&batches);
+        assert_eq!(channel.anchor_head, expected_head);
+        assert_eq!((channel.anchors, channel.messages), (2, 8));
+
+        System::assert_last_event(
+            Event::BatchAnchored {
+                id,
+                sequence: 2,
+                batch_hash: batches[1].0,
+                message_count: 5,
+                anchor_head: expected_head,
+                who: 2,
+            }
+            .into(),
+        );
+
+        // The head depends on the order of the batches
+        let reversed = vec![batches[1], batches[0]];
+        assert_ne!(AgentChannels::anchor_chain_head(&reversed), expected_head);
+    });
+}
+
+#[test]
+fn anchor_batch_checks_channel_and_caller() {
+    new_test_ext().execute_with(|| {
+        let id = open(1, 2);
+
+        assert_noop!(
+            AgentChannels::anchor_batch(RuntimeOrigin::signed(1), 7, H256::zero(), 1),
+            Error::<Test>::ChannelNotFound
+        );
+        assert_noop!(
+            AgentChannels::anchor_batch(RuntimeOrigin::signed(3), id, H256::zero(), 1),
+            Error::<Test>::NotParticipant
+        );
+        assert_noop!(
+            AgentChannels::anchor_batch(RuntimeOrigin::signed(1), id, H256::zero(), 0),
+            Error::<Test>::EmptyBatch
+        );
+    });
+}
+
+#[test]
+fn close_channel_finalizes_the_trail() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let id = open(1, 2);
+        assert_ok!(AgentChannels::anchor_batch(
+            RuntimeOrigin::signed(1),
+            id,
+            H256::repeat_byte(1),
+            1
+        ));
+        let head = Channels::<Test>::get(id).unwrap().anchor_head;
+
+        assert_noop!(
+            AgentChannels::close_channel(RuntimeOrigin::signed(3), id, H256::zero()),
+            Error::<Test>::NotParticipant
+        );
+
+        System::set_block_number(5);
+        let final_state = H256::repeat_byte(9);
+        assert_ok!(AgentChannels::close_channel(
+            RuntimeOrigin::signed(2),
+            id,
+            final_state
+        ));
+        System::assert_last_event(
+            Event::ChannelClosed {
+                id,
+                final_state,
+                anchor_head: head,
+                who: 2,
+            }
+            .into(),
+        );
+
+        let channel = Channels::<Test>::get(id).unwrap();
+        assert_eq!(
+            channel.status,
+            ChannelStatus::Closed {
+                final_state,
+                closed_by: 2,
+                closed_at: 5,
+            }
+        );
+        // The initiator's slot is released
+        assert_eq!(OpenChannels::<Test>::get(1), 0);
+
+        assert_noop!(
+            AgentChannels::anchor_batch(RuntimeOrigin::signed(1), id, H256::zero(), 1),
+            Error::<Test>::ChannelClosed
+        );
+        assert_noop!(
+            AgentChannels::close_channel(RuntimeOrigin::signed(1), id, final_state),
+            Error::<Test>::ChannelClosed
+        );
+    });
+}
@@ -0,0 +1,135 @@
+//! Benchmarking setup for pallet-gateway-registry
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as GatewayRegistry;
+use frame_benchmarking::v2::*;
+use frame_support::{
+    traits::{Currency, EnsureOrigin, Get},
+    BoundedVec,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+extern crate alloc;
+use alloc::vec::Vec;
+
+fn url_of_max_length<T: Config>() -> Vec<u8> {
+    let mut url = b"https://".to_vec();
+    url.resize(T::MaxUrlLength::get() as usize, b'a');
+    url
+}
+
+fn funded<T: Config>(who: &T::AccountId) {
+    T::Currency::make_free_balance_be(who, BalanceOf::<T>::max_value() / 2u32.into());
+}
+
+fn register_gateway_of<T: Config>(operator: &T::AccountId) -> GatewayId {
+    funded::<T>(operator);
+    let id = NextGatewayId::<T>::get();
+    GatewayRegistry::<T>::register_gateway(
+        RawOrigin::Signed(operator.clone()).into(),
+        url_of_max_length::<T>(),
+        b"eu-west".to_vec(),
+    )
+    .expect("gateway registration succeeds");
+    id
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn register_gateway() {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let url = url_of_max_length::<T>();
+
+        #[extrinsic_call]
+        register_gateway(RawOrigin::Signed(caller), url, b"eu-west".to_vec());
+
+        assert_eq!(Gateways::<T>::count(), 1);
+    }
+
+    #[benchmark]
+    fn update_gateway() {
+        let caller: T::AccountId = whitelisted_caller();
+        let id = register_gateway_of::<T>(&caller);
+
+        #[extrinsic_call]
+        update_gateway(
+            RawOrigin::Signed(caller),
+            id,
+            url_of_max_length::<T>(),
+            b"us-east".to_vec(),
+        );
+
+        assert_eq!(
+            Gateways::<T>::get(id).unwrap().region.to_vec(),
+            b"us-east".to_vec()
+        );
+    }
+
+    #[benchmark]
+    fn deregister_gateway() {
+        let caller: T::AccountId = whitelisted_caller();
+        let id = register_gateway_of::<T>(&caller);
+
+        #[extrinsic_call]
+        deregister_gateway(RawOrigin::Signed(caller), id);
+
+        assert!(!Gateways::<T>::contains_key(id));
+    }
+
+    #[benchmark]
+    fn add_reporter() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let reporter: T::AccountId = account("reporter", 0, 0);
+
+        #[extrinsic_call]
+        add_reporter(origin as T::RuntimeOrigin, reporter.clone());
+
+        assert!(Reporters::<T>::get().contains(&reporter));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_reporter() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let reporter: T::AccountId = account("reporter", 0, 0);
+        Reporters::<T>::put(BoundedVec::truncate_from(alloc::vec![reporter.clone()]));
+
+        #[extrinsic_call]
+        remove_reporter(origin as T::RuntimeOrigin, reporter.clone());
+
+        assert!(!Reporters::<T>::get().contains(&reporter));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn report_health(n: Linear<1, { T::MaxGateways::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        Reporters::<T>::put(BoundedVec::truncate_from(alloc::vec![caller.clone()]));
+        let reports: Vec<ProbeReport> = (0..n)
+            .map(|i| ProbeReport {
+                gateway: register_gateway_of::<T>(&account("operator", i, 0)),
+                latency_ms: Some(100),
+            })
+            .collect();
+        let reports: BoundedVec<_, T::MaxGateways> = reports.try_into().unwrap();
+
+        #[extrinsic_call]
+        report_health(RawOrigin::Signed(caller), reports);
+
+        assert_eq!(Health::<T>::get(0).reports, 1);
+    }
+
+    impl_benchmark_test_suite!(
+        GatewayRegistry,
+        crate::mock::new_test_ext(),
+        crate::mock::Test
+    );
+}
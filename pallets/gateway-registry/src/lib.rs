@@ -0,0 +1,712 @@
+//! # Gateway Registry Pallet
+//!
+//! A Substrate pallet for registering community IPFS gateways and scoring their health.
+//!
+//! ## Overview
+//!
+//! Module metadata and resources are referenced on chain by IPFS CID only, so clients need a
+//! working HTTP gateway to fetch them. This pallet keeps a shared list of gateways that anyone
+//! can register and an on-chain health score for each of them:
+//! - Operators register a gateway with its base URL and a free-form region label, reserving
+//!   `GatewayDeposit` until they deregister it; each operator runs at most
+//!   `MaxGatewaysPerOperator` gateways
+//! - A governance-managed set of reporters runs the pallet's offchain worker, which probes every
+//!   gateway and submits the observed latency (or failure) as a signed transaction
+//! - Reports are folded into a rolling availability score and a rolling latency average
+//!
+//! Because the scores live in state, clients and other offchain workers reading the same block
+//! rank gateways identically (see [`Pallet::ranked_gateways`]).
+//!
+//! ## Probing
+//!
+//! A probe is an HTTP `GET {url}/ipfs/bafkqaaa`. `bafkqaaa` is the empty identity CID, which every
+//! gateway can serve without touching the network, so the probe measures the gateway itself and
+//! not content availability. Any non-200 response or a timeout counts as a failure.
+//!
+//! Reporters probe from their own nodes, so a gateway URL must name a public host (see
+//! [`pallet_mcp::url::is_public_host`]). Gateways on loopback, private or link-local addresses
+//! are refused at registration and never probed.
+//!
+//! ## Functionality
+//!
+//! - `register_gateway`: Register a gateway operated by the caller
+//! - `update_gateway`: Change a gateway's URL or region
+//! - `deregister_gateway`: Remove a gateway and its health record
+//! - `add_reporter` / `remove_reporter`: Manage the accounts allowed to submit health reports
+//! - `report_health`: Submit a batch of probe results (normally sent by the offchain worker)
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::*;
+
+use sp_core::crypto::KeyTypeId;
+
+/// Key type of the keys used by the offchain worker to sign health reports.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"gtwy");
+
+/// Path appended to a gateway's base URL when probing it.
+pub const PROBE_PATH: &str = "/ipfs/bafkqaaa";
+
+/// Application crypto used to sign health reports.
+///
+/// Reporter nodes must have an `sr25519` key of type [`KEY_TYPE`] in their keystore whose account
+/// is listed in [`Reporters`].
+pub mod crypto {
+    use super::KEY_TYPE;
+    use sp_core::sr25519::Signature as Sr25519Signature;
+    use sp_runtime::{
+        app_crypto::{app_crypto, sr25519},
+        traits::Verify,
+        MultiSignature, MultiSigner,
+    };
+    app_crypto!(sr25519, KEY_TYPE);
+
+    /// Identifier used to look up reporter keys in the keystore.
+    pub struct AuthorityId;
+
+    impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for AuthorityId {
+        type RuntimeAppPublic = Public;
+        type GenericSignature = sp_core::sr25519::Signature;
+        type GenericPublic = sp_core::sr25519::Public;
+    }
+
+    impl frame_system::offchain::AppCrypto<<Sr25519Signature as Verify>::Signer, Sr25519Signature>
+        for AuthorityId
+    {
+        type RuntimeAppPublic = Public;
+        type GenericSignature = sp_core::sr25519::Signature;
+        type GenericPublic = sp_core::sr25519::Public;
+    }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{Currency, ReservableCurrency},
+    };
+    use frame_system::{
+        offchain::{AppCrypto, CreateSignedTransaction, SendSignedTransaction, Signer},
+        pallet_prelude::*,
+    };
+    use sp_runtime::{
+        offchain::{http, Duration},
+        traits::{IdentifyAccount, Saturating},
+        RuntimeAppPublic,
+    };
+    extern crate alloc;
+    use alloc::{string::String, vec, vec::Vec};
+
+    /// Identifier of a registered gateway.
+    pub type GatewayId = u32;
+
+    /// Balance of the currency gateway deposits are reserved in.
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// Weight given to the newest report when folding it into the rolling averages, out of 4.
+    const NEW_SAMPLE_WEIGHT: u64 = 1;
+
+    /// A registered IPFS gateway.
+    #[derive(
+        Encode,
+        Decode,
+        CloneNoBound,
+        PartialEqNoBound,
+        EqNoBound,
+        RuntimeDebugNoBound,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    #[scale_info(skip_type_params(UrlLimit, RegionLimit))]
+    #[codec(mel_bound(AccountId: MaxEncodedLen, Balance: MaxEncodedLen))]
+    pub struct GatewayInfo<AccountId, Balance, UrlLimit: Get<u32>, RegionLimit: Get<u32>>
+    where
+        AccountId: Clone + PartialEq + Eq + core::fmt::Debug,
+        Balance: Clone + PartialEq + Eq + core::fmt::Debug,
+    {
+        /// The account that registered the gateway and may update or remove it.
+        pub operator: AccountId,
+        /// Amount reserved from the operator, returned when the gateway is deregistered.
+        pub deposit: Balance,
+        /// Base URL of the gateway, e.g. `https://ipfs.example.org`.
+        pub url: BoundedVec<u8, UrlLimit>,
+        /// Free-form region label, e.g. `eu-west`.
+        pub region: BoundedVec<u8, RegionLimit>,
+    }
+
+    /// Gateway information as stored by the pallet.
+    pub type GatewayInfoOf<T> = GatewayInfo<
+        <T as frame_system::Config>::AccountId,
+        BalanceOf<T>,
+        <T as Config>::MaxUrlLength,
+        <T as Config>::MaxRegionLength,
+    >;
+
+    /// Bounded gateway URL.
+    pub type GatewayUrlOf<T> = BoundedVec<u8, <T as Config>::MaxUrlLength>;
+
+    /// Bounded gateway region label.
+    pub type GatewayRegionOf<T> = BoundedVec<u8, <T as Config>::MaxRegionLength>;
+
+    /// Aggregated health of a gateway.
+    #[derive(
+        Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+    )]
+    pub struct GatewayHealth<BlockNumber> {
+        /// Rolling share of successful probes, in percent.
+        pub score: u8,
+        /// Rolling average latency of successful probes, in milliseconds.
+        pub latency_ms: u32,
+        /// Number of reports folded into the score.
+        pub reports: u32,
+        /// Block at which the last report was recorded.
+        pub last_report: BlockNumber,
+    }
+
+    impl<BlockNumber> GatewayHealth<BlockNumber> {
+        /// Fold one probe result into the rolling score and latency.
+        pub fn record(&mut self, latency_ms: Option<u32>, now: BlockNumber) {
+            let sample: u64 = if latency_ms.is_some() { 100 } else { 0 };
+            self.score = if self.reports == 0 {
+                sample as u8
+            } else {
+                Self::fold(self.score as u64, sample) as u8
+            };
+
+            if let Some(latency) = latency_ms {
+                self.latency_ms = if self.latency_ms == 0 {
+                    latency
+                } else {
+                    Self::fold(self.latency_ms as u64, latency as u64) as u32
+                };
+            }
+
+            self.reports = self.reports.saturating_add(1);
+            self.last_report = now;
+        }
+
+        fn fold(current: u64, sample: u64) -> u64 {
+            (current * (4 - NEW_SAMPLE_WEIGHT) + sample * NEW_SAMPLE_WEIGHT) / 4
+        }
+    }
+
+    /// The outcome of probing a single gateway.
+    #[derive(
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Clone,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+    )]
+    pub struct ProbeReport {
+        /// The probed gateway.
+        pub gateway: GatewayId,
+        /// Round-trip time in milliseconds, or `None` if the probe failed.
+        pub latency_ms: Option<u32>,
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// The pallet's configuration trait.
+    #[pallet::config]
+    pub trait Config: CreateSignedTransaction<Call<Self>> + frame_system::Config {
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+        /// Crypto used by the offchain worker to sign health reports
+        type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+        /// Origin allowed to add and remove reporters
+        type ManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Currency in which gateway deposits are reserved
+        type Currency: ReservableCurrency<Self::AccountId>;
+        /// Amount reserved from an operator for each registered gateway
+        #[pallet::constant]
+        type GatewayDeposit: Get<BalanceOf<Self>>;
+        /// Maximum length of a gateway URL (in bytes)
+        #[pallet::constant]
+        type MaxUrlLength: Get<u32>;
+        /// Maximum length of a region label (in bytes)
+        #[pallet::constant]
+        type MaxRegionLength: Get<u32>;
+        /// Maximum number of registered gateways
+        #[pallet::constant]
+        type MaxGateways: Get<u32>;
+        /// Maximum number of gateways a single operator may register
+        #[pallet::constant]
+        type MaxGatewaysPerOperator: Get<u32>;
+        /// Maximum number of reporters
+        #[pallet::constant]
+        type MaxReporters: Get<u32>;
+        /// Number of blocks between two probe rounds of the same reporter
+        #[pallet::constant]
+        type ProbeInterval: Get<BlockNumberFor<Self>>;
+        /// Time a single probe may take before it counts as failed (in milliseconds)
+        #[pallet::constant]
+        type ProbeTimeout: Get<u64>;
+    }
+
+    /// The identifier assigned to the next registered gateway.
+    #[pallet::storage]
+    pub type NextGatewayId<T: Config> = StorageValue<_, GatewayId, ValueQuery>;
+
+    /// Registered gateways by identifier.
+    #[pallet::storage]
+    pub type Gateways<T: Config> =
+        CountedStorageMap<_, Twox64Concat, GatewayId, GatewayInfoOf<T>, OptionQuery>;
+
+    /// Number of gateways registered by each operator.
+    #[pallet::storage]
+    pub type GatewaysByOperator<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Aggregated health of each registered gateway.
+    #[pallet::storage]
+    pub type Health<T: Config> =
+        StorageMap<_, Twox64Concat, GatewayId, GatewayHealth<BlockNumberFor<T>>, ValueQuery>;
+
+    /// Accounts allowed to submit health reports.
+    #[pallet::storage]
+    pub type Reporters<T: Config> =
+        StorageValue<_, BoundedVec<T::AccountId, T::MaxReporters>, ValueQuery>;
+
+    /// Block of each reporter's last accepted report.
+    #[pallet::storage]
+    pub type LastReport<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+    /// Events emitted by this pallet.
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A gateway was registered.
+        GatewayRegistered {
+            /// The identifier assigned to the gateway.
+            id: GatewayId,
+            /// The account operating the gateway.
+            operator: T::AccountId,
+            /// The gateway's base URL.
+            url: BoundedVec<u8, T::MaxUrlLength>,
+            /// The gateway's region label.
+            region: BoundedVec<u8, T::MaxRegionLength>,
+        },
+        /// A gateway's URL or region was updated.
+        GatewayUpdated {
+            /// The updated gateway.
+            id: GatewayId,
+            /// The new base URL.
+            url: BoundedVec<u8, T::MaxUrlLength>,
+            /// The new region label.
+            region: BoundedVec<u8, T::MaxRegionLength>,
+        },
+        /// A gateway was removed.
+        GatewayDeregistered {
+            /// The removed gateway.
+            id: GatewayId,
+        },
+        /// An account was allowed to submit health reports.
+        ReporterAdded {
+            /// The new reporter.
+            who: T::AccountId,
+        },
+        /// An account may no longer submit health reports.
+        ReporterRemoved {
+            /// The removed reporter.
+            who: T::AccountId,
+        },
+        /// A batch of probe results was folded into the gateway scores.
+        HealthReported {
+            /// The reporter that submitted the batch.
+            reporter: T::AccountId,
+            /// Number of results applied to registered gateways.
+            applied: u32,
+        },
+    }
+
+    /// Errors that can be returned by this pallet.
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The gateway does not exist.
+        GatewayNotFound,
+        /// The caller does not operate this gateway.
+        NotOperator,
+        /// The URL is empty, not UTF-8 or not an `http(s)://` URL.
+        InvalidUrl,
+        /// The URL names `localhost` or a loopback, private or link-local address.
+        PrivateHost,
+        /// The URL is too long.
+        UrlTooLong,
+        /// The region label is too long.
+        RegionTooLong,
+        /// The maximum number of gateways is already registered.
+        TooManyGateways,
+        /// The caller already operates `MaxGatewaysPerOperator` gateways.
+        TooManyOperatorGateways,
+        /// The caller is not an allowed reporter.
+        NotReporter,
+        /// The account is already a reporter.
+        AlreadyReporter,
+        /// The maximum number of reporters is already reached.
+        TooManyReporters,
+        /// The reporter already reported within the current probe interval.
+        ReportTooEarly,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn integrity_test() {
+            assert!(
+                !T::ProbeInterval::get().is_zero(),
+                "Gateways must be probed at an interval of at least one block"
+            );
+        }
+
+        fn offchain_worker(now: BlockNumberFor<T>) {
+            if (now % T::ProbeInterval::get()) != Zero::zero() {
+                return;
+            }
+            if let Err(e) = Self::probe_and_report() {
+                log::warn!(target: "runtime::gateway-registry", "Health report skipped: {e}");
+            }
+        }
+    }
+
+    /// Dispatchable functions for the gateway registry pallet.
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Register a new gateway operated by the caller.
+        ///
+        /// `GatewayDeposit` is reserved from the caller until the gateway is deregistered.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (must be signed)
+        /// * `url` - Base URL of the gateway (`http://` or `https://`)
+        /// * `region` - Free-form region label
+        ///
+        /// # Errors
+        /// * `InvalidUrl` - If the URL is not an `http(s)://` URL
+        /// * `PrivateHost` - If the URL does not name a public host
+        /// * `TooManyGateways` - If `MaxGateways` gateways are already registered
+        /// * `TooManyOperatorGateways` - If the caller already operates `MaxGatewaysPerOperator`
+        ///   gateways
+        /// * `InsufficientBalance` - If the caller cannot reserve `GatewayDeposit`
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::register_gateway())]
+        pub fn register_gateway(
+            origin: OriginFor<T>,
+            url: Vec<u8>,
+            region: Vec<u8>,
+        ) -> DispatchResult {
+            let operator = ensure_signed(origin)?;
+            let (url, region) = Self::validate_location(url, region)?;
+
+            ensure!(
+                Gateways::<T>::count() < T::MaxGateways::get(),
+                Error::<T>::TooManyGateways
+            );
+            GatewaysByOperator::<T>::try_mutate(&operator, |count| -> DispatchResult {
+                ensure!(
+                    *count < T::MaxGatewaysPerOperator::get(),
+                    Error::<T>::TooManyOperatorGateways
+                );
+                *count += 1;
+                Ok(())
+            })?;
+            let deposit = T::GatewayDeposit::get();
+            T::Currency::reserve(&operator, deposit)?;
+
+            let id = NextGatewayId::<T>::get();
+            NextGatewayId::<T>::put(id.saturating_add(1));
+            Gateways::<T>::insert(
+                id,
+                GatewayInfo {
+                    operator: operator.clone(),
+                    deposit,
+                    url: url.clone(),
+                    region: region.clone(),
+                },
+            );
+
+            Self::deposit_event(Event::GatewayRegistered {
+                id,
+                operator,
+                url,
+                region,
+            });
+
+            Ok(())
+        }
+
+        /// Update the URL and region of a gateway.
+        ///
+        /// The health record is kept, so operators moving a gateway to a new URL should expect
+        /// the score to adjust over the next few probe rounds.
+        ///
+        /// # Errors
+        /// * `GatewayNotFound` - If the gateway does not exist
+        /// * `NotOperator` - If the caller does not operate the gateway
+        /// * `InvalidUrl` / `PrivateHost` - If the new URL is invalid or not public
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::update_gateway())]
+        pub fn update_gateway(
+            origin: OriginFor<T>,
+            id: GatewayId,
+            url: Vec<u8>,
+            region: Vec<u8>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let (url, region) = Self::validate_location(url, region)?;
+
+            Gateways::<T>::try_mutate(id, |gateway| -> DispatchResult {
+                let gateway = gateway.as_mut().ok_or(Error::<T>::GatewayNotFound)?;
+                ensure!(gateway.operator == who, Error::<T>::NotOperator);
+                gateway.url = url.clone();
+                gateway.region = region.clone();
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::GatewayUpdated { id, url, region });
+
+            Ok(())
+        }
+
+        /// Remove a gateway and its health record, returning the operator's deposit.
+        ///
+        /// # Errors
+        /// * `GatewayNotFound` - If the gateway does not exist
+        /// * `NotOperator` - If the caller does not operate the gateway
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::deregister_gateway())]
+        pub fn deregister_gateway(origin: OriginFor<T>, id: GatewayId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let gateway = Gateways::<T>::get(id).ok_or(Error::<T>::GatewayNotFound)?;
+            ensure!(gateway.operator == who, Error::<T>::NotOperator);
+
+            Gateways::<T>::remove(id);
+            Health::<T>::remove(id);
+            GatewaysByOperator::<T>::mutate_exists(&who, |count| {
+                *count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0);
+            });
+            T::Currency::unreserve(&who, gateway.deposit);
+
+            Self::deposit_event(Event::GatewayDeregistered { id });
+
+            Ok(())
+        }
+
+        /// Allow an account to submit health reports.
+        ///
+        /// # Errors
+        /// * `AlreadyReporter` - If the account is already a reporter
+        /// * `TooManyReporters` - If `MaxReporters` is reached
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::add_reporter())]
+        pub fn add_reporter(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            Reporters::<T>::try_mutate(|reporters| -> DispatchResult {
+                ensure!(!reporters.contains(&who), Error::<T>::AlreadyReporter);
+                reporters
+                    .try_push(who.clone())
+                    .map_err(|_| Error::<T>::TooManyReporters)?;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::ReporterAdded { who });
+
+            Ok(())
+        }
+
+        /// Revoke an account's permission to submit health reports.
+        ///
+        /// # Errors
+        /// * `NotReporter` - If the account is not a reporter
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::remove_reporter())]
+        pub fn remove_reporter(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            Reporters::<T>::try_mutate(|reporters| -> DispatchResult {
+                let pos = reporters
+                    .iter()
+                    .position(|r| r == &who)
+                    .ok_or(Error::<T>::NotReporter)?;
+                reporters.remove(pos);
+                Ok(())
+            })?;
+            LastReport::<T>::remove(&who);
+
+            Self::deposit_event(Event::ReporterRemoved { who });
+
+            Ok(())
+        }
+
+        /// Submit probe results for a set of gateways.
+        ///
+        /// Results for gateways that were deregistered since the probe are ignored. A reporter
+        /// may submit at most one batch per `ProbeInterval` blocks.
+        ///
+        /// # Errors
+        /// * `NotReporter` - If the caller is not a reporter
+        /// * `ReportTooEarly` - If the caller already reported within the probe interval
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::report_health(reports.len() as u32))]
+        pub fn report_health(
+            origin: OriginFor<T>,
+            reports: BoundedVec<ProbeReport, T::MaxGateways>,
+        ) -> DispatchResult {
+            let reporter = ensure_signed(origin)?;
+            ensure!(
+                Reporters::<T>::get().contains(&reporter),
+                Error::<T>::NotReporter
+            );
+
+            let now = frame_system::Pallet::<T>::block_number();
+            if let Some(last) = LastReport::<T>::get(&reporter) {
+                ensure!(
+                    now >= last.saturating_add(T::ProbeInterval::get()),
+                    Error::<T>::ReportTooEarly
+                );
+            }
+            LastReport::<T>::insert(&reporter, now);
+
+            let mut applied = 0u32;
+            for report in reports {
+                if !Gateways::<T>::contains_key(report.gateway) {
+                    continue;
+                }
+                Health::<T>::mutate(report.gateway, |health| {
+                    health.record(report.latency_ms, now)
+                });
+                applied += 1;
+            }
+
+            Self::deposit_event(Event::HealthReported { reporter, applied });
+
+            Ok(())
+        }
+    }
+
+    /// Helper functions for validation, ranking and probing.
+    impl<T: Config> Pallet<T> {
+        /// Validate and bound a gateway URL and region label.
+        ///
+        /// The URL must be UTF-8, start with `http://` or `https://` and name a public host.
+        pub fn validate_location(
+            url: Vec<u8>,
+            region: Vec<u8>,
+        ) -> Result<(GatewayUrlOf<T>, GatewayRegionOf<T>), Error<T>> {
+            let url_str = core::str::from_utf8(&url).map_err(|_| Error::<T>::InvalidUrl)?;
+            let host = url_str
+                .strip_prefix("https://")
+                .or_else(|| url_str.strip_prefix("http://"))
+                .ok_or(Error::<T>::InvalidUrl)?;
+            ensure!(
+                !host.is_empty() && !host.chars().any(|c| c.is_whitespace()),
+                Error::<T>::InvalidUrl
+            );
+            ensure!(
+                pallet_mcp::url::is_public_host(&url),
+                Error::<T>::PrivateHost
+            );
+
+            let url = url.try_into().map_err(|_| Error::<T>::UrlTooLong)?;
+            let region = region.try_into().map_err(|_| Error::<T>::RegionTooLong)?;
+            Ok((url, region))
+        }
+
+        /// All gateways ordered from most to least preferable.
+        ///
+        /// Gateways are ordered by score (highest first), then latency (lowest first), then
+        /// identifier, so every reader of the same state gets the same order. Gateways that
+        /// were never reported on sort last.
+        pub fn ranked_gateways() -> Vec<(GatewayId, GatewayHealth<BlockNumberFor<T>>)> {
+            let mut ranked: Vec<_> = Gateways::<T>::iter_keys()
+                .map(|id| (id, Health::<T>::get(id)))
+                .collect();
+            ranked.sort_by(|(a_id, a), (b_id, b)| {
+                (b.reports > 0)
+                    .cmp(&(a.reports > 0))
+                    .then(b.score.cmp(&a.score))
+                    .then(a.latency_ms.cmp(&b.latency_ms))
+                    .then(a_id.cmp(b_id))
+            });
+            ranked
+        }
+
+        /// Probe every registered gateway and submit the results, if this node holds a
+        /// reporter key.
+        fn probe_and_report() -> Result<(), &'static str> {
+            let reporters = Reporters::<T>::get();
+            let public = <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic::all()
+                .into_iter()
+                .map(|key| {
+                    let generic: <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::GenericPublic =
+                        key.into();
+                    let public: T::Public = generic.into();
+                    public
+                })
+                .find(|public| reporters.contains(&public.clone().into_account()))
+                .ok_or("no local reporter key")?;
+
+            // Gateways registered before the public host check are left alone
+            let reports: Vec<ProbeReport> = Gateways::<T>::iter()
+                .filter(|(_, info)| pallet_mcp::url::is_public_host(&info.url))
+                .map(|(gateway, info)| ProbeReport {
+                    gateway,
+                    latency_ms: Self::probe(&info.url),
+                })
+                .collect();
+            if reports.is_empty() {
+                return Ok(());
+            }
+            let reports: BoundedVec<_, T::MaxGateways> = reports
+                .try_into()
+                .map_err(|_| "more gateways than MaxGateways")?;
+
+            let (_, result) = Signer::<T, T::AuthorityId>::any_account()
+                .with_filter(vec![public])
+                .send_signed_transaction(|_| Call::report_health {
+                    reports: reports.clone(),
+                })
+                .ok_or("no signing account")?;
+            result.map_err(|_| "failed to submit transaction")
+        }
+
+        /// Probe one gateway, returning the round-trip time in milliseconds on success.
+        fn probe(url: &[u8]) -> Option<u32> {
+            let base = core::str::from_utf8(url).ok()?;
+            let mut target = String::from(base.trim_end_matches('/'));
+            target.push_str(PROBE_PATH);
+
+            let started = sp_io::offchain::timestamp();
+            let deadline = started.add(Duration::from_millis(T::ProbeTimeout::get()));
+            let pending = http::Request::get(&target).deadline(deadline).send().ok()?;
+            let response = pending.try_wait(deadline).ok()?.ok()?;
+            if response.code != 200 {
+                return None;
+            }
+
+            let elapsed = sp_io::offchain::timestamp().diff(&started).millis();
+            Some(elapsed.min(u32::MAX as u64) as u32)
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use crate as pallet_gateway_registry;
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{ConstU16, ConstU64},
+};
+use frame_system::EnsureRoot;
+use sp_core::{sr25519, H256};
+use sp_runtime::{
+    testing::TestXt,
+    traits::{BlakeTwo256, IdentityLookup, Verify},
+    BuildStorage,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+pub type AccountId = sr25519::Public;
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+    pub enum Test
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        GatewayRegistry: pallet_gateway_registry,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Nonce = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Block = Block;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<u64>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ConstU16<42>;
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+}
+
+impl frame_system::offchain::SigningTypes for Test {
+    type Public = <sr25519::Signature as Verify>::Signer;
+    type Signature = sr25519::Signature;
+}
+
+impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    type RuntimeCall = RuntimeCall;
+    type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_signed_transaction<
+        C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>,
+    >(
+        call: RuntimeCall,
+        _public: Self::Public,
+        _account: AccountId,
+        nonce: u64,
+    ) -> Option<Extrinsic> {
+        Some(Extrinsic::new_signed(call, nonce, (), ()))
+    }
+}
+
+parameter_types! {
+    pub const MaxUrlLength: u32 = 64;
+    pub const MaxRegionLength: u32 = 16;
+    pub const MaxGateways: u32 = 3;
+    pub const MaxGatewaysPerOperator: u32 = 2;
+    pub const GatewayDeposit: u64 = 10;
+    pub const MaxReporters: u32 = 2;
+    pub const ProbeInterval: u64 = 10;
+    pub const ProbeTimeout: u64 = 2_000;
+}
+
+impl pallet_gateway_registry::Config for Test {
+    type WeightInfo = ();
+    type AuthorityId = pallet_gateway_registry::crypto::AuthorityId;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type Currency = Balances;
+    type GatewayDeposit = GatewayDeposit;
+    type MaxUrlLength = MaxUrlLength;
+    type MaxRegionLength = MaxRegionLength;
+    type MaxGateways = MaxGateways;
+    type MaxGatewaysPerOperator = MaxGatewaysPerOperator;
+    type MaxReporters = MaxReporters;
+    type ProbeInterval = ProbeInterval;
+    type ProbeTimeout = ProbeTimeout;
+}
+
+/// Deterministic test account.
+pub fn account(seed: u8) -> AccountId {
+    sr25519::Public::from_raw([seed; 32])
+}
+
+/// Balance of every funded test account, enough for a few gateway deposits.
+pub const INITIAL_BALANCE: u64 = 100;
+
+/// Seeds of the accounts funded at genesis.
+pub const FUNDED: [u8; 3] = [1, 2, 3];
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: FUNDED
+            .iter()
+            .map(|seed| (account(*seed), INITIAL_BALANCE))
+            .collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+    storage.into()
+}
@@ -0,0 +1,419 @@
+use crate::{
+    mock::*, Call, Error, Event, GatewayHealth, Gateways, GatewaysByOperator, Health, ProbeReport,
+    KEY_TYPE,
+};
+use codec::Decode;
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use sp_core::offchain::{
+    testing::{PendingRequest, TestOffchainExt, TestTransactionPoolExt},
+    OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+};
+use sp_keystore::{testing::MemoryKeystore, Keystore, KeystoreExt};
+use sp_runtime::DispatchError;
+extern crate alloc;
+use alloc::{sync::Arc, vec, vec::Vec};
+
+const URL: &[u8] = b"https://ipfs.example.org";
+
+fn register(operator: u8, url: &[u8]) -> u32 {
+    let id = crate::NextGatewayId::<Test>::get();
+    assert_ok!(GatewayRegistry::register_gateway(
+        RuntimeOrigin::signed(account(operator)),
+        url.to_vec(),
+        b"eu-west".to_vec()
+    ));
+    id
+}
+
+fn reports(items: Vec<(u32, Option<u32>)>) -> frame_support::BoundedVec<ProbeReport, MaxGateways> {
+    items
+        .into_iter()
+        .map(|(gateway, latency_ms)| ProbeReport {
+            gateway,
+            latency_ms,
+        })
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap()
+}
+
+#[test]
+fn register_gateway_works() {
+    new_test_ext().execute_with(|| {
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        let id = register(1, URL);
+
+        let gateway = Gateways::<Test>::get(id).unwrap();
+        assert_eq!(gateway.operator, account(1));
+        assert_eq!(gateway.url.to_vec(), URL.to_vec());
+
+        System::assert_last_event(
+            Event::GatewayRegistered {
+                id,
+                operator: account(1),
+                url: gateway.url,
+                region: gateway.region,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn register_gateway_rejects_bad_urls() {
+    new_test_ext().execute_with(|| {
+        for url in [
+            &b""[..],
+            b"ipfs.example.org",
+            b"ftp://ipfs.example.org",
+            b"https://",
+            b"https://a b",
+        ] {
+            assert_noop!(
+                GatewayRegistry::register_gateway(
+                    RuntimeOrigin::signed(account(1)),
+                    url.to_vec(),
+                    vec![]
+                ),
+                Error::<Test>::InvalidUrl
+            );
+        }
+
+        // Reporters must not be pointed at their own node or network
+        for url in [
+            &b"http://127.0.0.1:9944"[..],
+            b"http://localhost:8080",
+            b"http://10.0.0.5",
+            b"https://192.168.1.1",
+            b"http://169.254.169.254",
+            b"http://[::1]:5001",
+        ] {
+            assert_noop!(
+                GatewayRegistry::register_gateway(
+                    RuntimeOrigin::signed(account(1)),
+                    url.to_vec(),
+                    vec![]
+                ),
+                Error::<Test>::PrivateHost
+            );
+        }
+        let id = register(1, URL);
+        assert_noop!(
+            GatewayRegistry::update_gateway(
+                RuntimeOrigin::signed(account(1)),
+                id,
+                b"http://127.0.0.1:9944".to_vec(),
+                vec![]
+            ),
+            Error::<Test>::PrivateHost
+        );
+
+        let mut long = b"https://".to_vec();
+        long.extend(vec![b'a'; 64]);
+        assert_noop!(
+            GatewayRegistry::register_gateway(RuntimeOrigin::signed(account(1)), long, vec![]),
+            Error::<Test>::UrlTooLong
+        );
+    });
+}
+
+#[test]
+fn register_gateway_respects_max_gateways() {
+    new_test_ext().execute_with(|| {
+        for operator in FUNDED {
+            register(operator, URL);
+        }
+        assert_noop!(
+            GatewayRegistry::register_gateway(
+                RuntimeOrigin::signed(account(1)),
+                URL.to_vec(),
+                vec![]
+            ),
+            Error::<Test>::TooManyGateways
+        );
+    });
+}
+
+#[test]
+fn register_gateway_reserves_deposit_up_to_operator_limit() {
+    new_test_ext().execute_with(|| {
+        let first = register(1, URL);
+        register(1, URL);
+        assert_eq!(
+            Balances::reserved_balance(account(1)),
+            2 * GatewayDeposit::get()
+        );
+        assert_noop!(
+            GatewayRegistry::register_gateway(
+                RuntimeOrigin::signed(account(1)),
+                URL.to_vec(),
+                vec![]
+            ),
+            Error::<Test>::TooManyOperatorGateways
+        );
+
+        // Accounts that cannot pay the deposit cannot register
+        assert_noop!(
+            GatewayRegistry::register_gateway(
+                RuntimeOrigin::signed(account(4)),
+                URL.to_vec(),
+                vec![]
+            ),
+            pallet_balances::Error::<Test>::InsufficientBalance
+        );
+
+        // Deregistering returns the deposit and frees the slot
+        assert_ok!(GatewayRegistry::deregister_gateway(
+            RuntimeOrigin::signed(account(1)),
+            first
+        ));
+        assert_eq!(
+            Balances::reserved_balance(account(1)),
+            GatewayDeposit::get()
+        );
+        assert_eq!(GatewaysByOperator::<Test>::get(account(1)), 1);
+        register(1, URL);
+    });
+}
+
+#[test]
+fn only_operator_can_update_or_deregister() {
+    new_test_ext().execute_with(|| {
+        let id = register(1, URL);
+
+        assert_noop!(
+            GatewayRegistry::update_gateway(
+                RuntimeOrigin::signed(account(2)),
+                id,
+                b"https://other.example.org".to_vec(),
+                vec![]
+            ),
+            Error::<Test>::NotOperator
+        );
+        assert_noop!(
+            GatewayRegistry::deregister_gateway(RuntimeOrigin::signed(account(2)), id),
+            Error::<Test>::NotOperator
+        );
+
+        assert_ok!(GatewayRegistry::update_gateway(
+            RuntimeOrigin::signed(account(1)),
+            id,
+            b"https://other.example.org".to_vec(),
+            b"us-east".to_vec()
+        ));
+        assert_eq!(
+            Gateways::<Test>::get(id).unwrap().region.to_vec(),
+            b"us-east".to_vec()
+        );
+
+        assert_ok!(GatewayRegistry::deregister_gateway(
+            RuntimeOrigin::signed(account(1)),
+            id
+        ));
+        assert!(Gateways::<Test>::get(id).is_none());
+        assert!(!GatewaysByOperator::<Test>::contains_key(account(1)));
+        assert_eq!(Balances::reserved_balance(account(1)), 0);
+        assert_noop!(
+            GatewayRegistry::deregister_gateway(RuntimeOrigin::signed(account(1)), id),
+            Error::<Test>::GatewayNotFound
+        );
+    });
+}
+
+#[test]
+fn reporters_are_managed_by_manager_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            GatewayRegistry::add_reporter(RuntimeOrigin::signed(account(1)), account(1)),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(GatewayRegistry::add_reporter(
+            RuntimeOrigin::root(),
+            account(1)
+        ));
+        assert_noop!(
+            GatewayRegistry::add_reporter(RuntimeOrigin::root(), account(1)),
+            Error::<Test>::AlreadyReporter
+        );
+        assert_ok!(GatewayRegistry::add_reporter(
+            RuntimeOrigin::root(),
+            account(2)
+        ));
+        assert_noop!(
+            GatewayRegistry::add_reporter(RuntimeOrigin::root(), account(3)),
+            Error::<Test>::TooManyReporters
+        );
+
+        assert_ok!(GatewayRegistry::remove_reporter(
+            RuntimeOrigin::root(),
+            account(1)
+        ));
+        assert_noop!(
+            GatewayRegistry::remove_reporter(RuntimeOrigin::root(), account(1)),
+            Error::<Test>::NotReporter
+        );
+    });
+}
+
+#[test]
+fn report_health_updates_scores() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let id = register(1, URL);
+
+        assert_noop!(
+            GatewayRegistry::report_health(
+                RuntimeOrigin::signed(account(9)),
+                reports(vec![(id, Some(100))])
+            ),
+            Error::<Test>::NotReporter
+        );
+
+        assert_ok!(GatewayRegistry::add_reporter(
+            RuntimeOrigin::root(),
+            account(9)
+        ));
+        // Results for unknown gateways are skipped
+        assert_ok!(GatewayRegistry::report_health(
+            RuntimeOrigin::signed(account(9)),
+            reports(vec![(id, Some(100)), (42, Some(1))])
+        ));
+        System::assert_last_event(
+            Event::HealthReported {
+                reporter: account(9),
+                applied: 1,
+            }
+            .into(),
+        );
+        assert_eq!(
+            Health::<Test>::get(id),
+            GatewayHealth {
+                score: 100,
+                latency_ms: 100,
+                reports: 1,
+                last_report: 1,
+            }
+        );
+
+        // A second report in the same interval is rejected
+        assert_noop!(
+            GatewayRegistry::report_health(
+                RuntimeOrigin::signed(account(9)),
+                reports(vec![(id, None)])
+            ),
+            Error::<Test>::ReportTooEarly
+        );
+
+        // A failed probe lowers the score and leaves the latency untouched
+        System::set_block_number(11);
+        assert_ok!(GatewayRegistry::report_health(
+            RuntimeOrigin::signed(account(9)),
+            reports(vec![(id, None)])
+        ));
+        let health = Health::<Test>::get(id);
+        assert_eq!(
+            (health.score, health.latency_ms, health.reports),
+            (75, 100, 2)
+        );
+
+        // Deregistering drops the health record
+        assert_ok!(GatewayRegistry::deregister_gateway(
+            RuntimeOrigin::signed(account(1)),
+            id
+        ));
+        assert_eq!(Health::<Test>::get(id), GatewayHealth::default());
+    });
+}
+
+#[test]
+fn ranked_gateways_orders_by_score_then_latency() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let unreported = register(1, URL);
+        let slow = register(2, URL);
+        let fast = register(3, URL);
+
+        assert_ok!(GatewayRegistry::add_reporter(
+            RuntimeOrigin::root(),
+            account(9)
+        ));
+        assert_ok!(GatewayRegistry::report_health(
+            RuntimeOrigin::signed(account(9)),
+            reports(vec![(slow, Some(400)), (fast, Some(50))])
+        ));
+
+        let order: Vec<u32> = GatewayRegistry::ranked_gateways()
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(order, vec![fast, slow, unreported]);
+    });
+}
+
+#[test]
+fn offchain_worker_probes_gateways_and_submits_report() {
+    let (offchain, offchain_state) = TestOffchainExt::new();
+    let (pool, pool_state) = TestTransactionPoolExt::new();
+    let keystore = MemoryKeystore::new();
+    let public = keystore
+        .sr25519_generate_new(KEY_TYPE, Some("//Reporter"))
+        .unwrap();
+
+    let mut t = new_test_ext();
+    t.register_extension(OffchainDbExt::new(offchain.clone()));
+    t.register_extension(OffchainWorkerExt::new(offchain));
+    t.register_extension(TransactionPoolExt::new(pool));
+    t.register_extension(KeystoreExt(Arc::new(keystore)));
+
+    {
+        let mut state = offchain_state.write();
+        // Gateways are probed in storage iteration order
+        state.expect_request(PendingRequest {
+            method: "GET".into(),
+            uri: "http://other.example.org/ipfs/bafkqaaa".into(),
+            sent: true,
+            response: Some(vec![]),
+            ..Default::default()
+        });
+        state.expect_request(PendingRequest {
+            method: "GET".into(),
+            uri: "https://ipfs.example.org/ipfs/bafkqaaa".into(),
+            sent: true,
+            response: Some(vec![]),
+            ..Default::default()
+        });
+    }
+
+    t.execute_with(|| {
+        let up = register(1, b"https://ipfs.example.org/");
+        let other = register(1, b"http://other.example.org");
+        // A private gateway stored before registrations were checked is not probed
+        let private = register(2, URL);
+        Gateways::<Test>::mutate(private, |gateway| {
+            gateway.as_mut().unwrap().url = b"http://127.0.0.1:9944".to_vec().try_into().unwrap();
+        });
+        assert_ok!(GatewayRegistry::add_reporter(RuntimeOrigin::root(), public));
+
+        // Nothing is probed outside the probe interval
+        GatewayRegistry::offchain_worker(9);
+        assert!(pool_state.read().transactions.is_empty());
+
+        GatewayRegistry::offchain_worker(10);
+
+        let tx = pool_state.write().transactions.pop().unwrap();
+        let tx = Extrinsic::decode(&mut &*tx).unwrap();
+        let RuntimeCall::GatewayRegistry(Call::report_health { reports }) = tx.function else {
+            panic!("unexpected call");
+        };
+        let mut results: Vec<(u32, bool)> = reports
+            .into_iter()
+            .map(|r| (r.gateway, r.latency_ms.is_some()))
+            .collect();
+        results.sort();
+        // The test HTTP backend answers every expected request with 200
+        assert_eq!(results, vec![(up, true), (other, true)]);
+    });
+}
@@ -0,0 +1,248 @@
+//! Placeholder weights for `pallet_gateway_registry`
+//!
+//! THESE WEIGHTS WERE NOT PRODUCED BY THE BENCHMARK CLI. The storage accesses and proof sizes
+//! follow the benchmarked code paths, but the execution times are estimates, not measurements.
+//! Replace this file with the output of the command below, run on reference hardware, before
+//! relying on these weights on a production chain.
+
+// Command to regenerate, with a node built with `--features runtime-benchmarks`:
+// ./target/release/mod-net-node
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_gateway_registry
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./pallets/gateway-registry/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_gateway_registry`.
+pub trait WeightInfo {
+	fn register_gateway() -> Weight;
+	fn update_gateway() -> Weight;
+	fn deregister_gateway() -> Weight;
+	fn add_reporter() -> Weight;
+	fn remove_reporter() -> Weight;
+	fn report_health(n: u32, ) -> Weight;
+}
+
+/// Weights for `pallet_gateway_registry` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `GatewayRegistry::NextGatewayId` (r:1 w:1)
+	/// Proof: `GatewayRegistry::NextGatewayId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GatewayRegistry::Gateways` (r:0 w:1)
+	/// Proof: `GatewayRegistry::Gateways` (`max_values`: None, `max_size`: Some(330), added: 2805, mode: `MaxEncodedLen`)
+	/// Storage: `GatewayRegistry::CounterForGateways` (r:1 w:1)
+	/// Proof: `GatewayRegistry::CounterForGateways` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GatewayRegistry::GatewaysByOperator` (r:1 w:1)
+	/// Proof: `GatewayRegistry::GatewaysByOperator` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn register_gateway() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `3795`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(28_000_000, 3795)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: `GatewayRegistry::Gateways` (r:1 w:1)
+	/// Proof: `GatewayRegistry::Gateways` (`max_values`: None, `max_size`: Some(330), added: 2805, mode: `MaxEncodedLen`)
+	fn update_gateway() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `240`
+		//  Estimated: `3795`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 3795)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: `GatewayRegistry::Gateways` (r:1 w:1)
+	/// Proof: `GatewayRegistry::Gateways` (`max_values`: None, `max_size`: Some(330), added: 2805, mode: `MaxEncodedLen`)
+	/// Storage: `GatewayRegistry::CounterForGateways` (r:1 w:1)
+	/// Proof: `GatewayRegistry::CounterForGateways` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GatewayRegistry::Health` (r:0 w:1)
+	/// Proof: `GatewayRegistry::Health` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `GatewayRegistry::GatewaysByOperator` (r:1 w:1)
+	/// Proof: `GatewayRegistry::GatewaysByOperator` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn deregister_gateway() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `256`
+		//  Estimated: `3795`
+		// Minimum execution time: 28_000_000 picoseconds.
+		Weight::from_parts(29_000_000, 3795)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: `GatewayRegistry::Reporters` (r:1 w:1)
+	/// Proof: `GatewayRegistry::Reporters` (`max_values`: Some(1), `max_size`: Some(513), added: 1008, mode: `MaxEncodedLen`)
+	fn add_reporter() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `110`
+		//  Estimated: `1998`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 1998)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: `GatewayRegistry::Reporters` (r:1 w:1)
+	/// Proof: `GatewayRegistry::Reporters` (`max_values`: Some(1), `max_size`: Some(513), added: 1008, mode: `MaxEncodedLen`)
+	/// Storage: `GatewayRegistry::LastReport` (r:0 w:1)
+	/// Proof: `GatewayRegistry::LastReport` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn remove_reporter() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `1998`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 1998)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: `GatewayRegistry::Reporters` (r:1 w:0)
+	/// Proof: `GatewayRegistry::Reporters` (`max_values`: Some(1), `max_size`: Some(513), added: 1008, mode: `MaxEncodedLen`)
+	/// Storage: `GatewayRegistry::LastReport` (r:1 w:1)
+	/// Proof: `GatewayRegistry::LastReport` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GatewayRegistry::Gateways` (r:32 w:0)
+	/// Proof: `GatewayRegistry::Gateways` (`max_values`: None, `max_size`: Some(330), added: 2805, mode: `MaxEncodedLen`)
+	/// Storage: `GatewayRegistry::Health` (r:32 w:32)
+	/// Proof: `GatewayRegistry::Health` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 32]`.
+	fn report_health(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `148 + n * (93 ±0)`
+		//  Estimated: `3517 + n * (2805 ±0)`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 3517)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2805).saturating_mul(n.into()))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `GatewayRegistry::NextGatewayId` (r:1 w:1)
+	/// Proof: `GatewayRegistry::NextGatewayId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GatewayRegistry::Gateways` (r:0 w:1)
+	/// Proof: `GatewayRegistry::Gateways` (`max_values`: None, `max_size`: Some(330), added: 2805, mode: `MaxEncodedLen`)
+	/// Storage: `GatewayRegistry::CounterForGateways` (r:1 w:1)
+	/// Proof: `GatewayRegistry::CounterForGateways` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GatewayRegistry::GatewaysByOperator` (r:1 w:1)
+	/// Proof: `GatewayRegistry::GatewaysByOperator` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn register_gateway() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `3795`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(28_000_000, 3795)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: `GatewayRegistry::Gateways` (r:1 w:1)
+	/// Proof: `GatewayRegistry::Gateways` (`max_values`: None, `max_size`: Some(330), added: 2805, mode: `MaxEncodedLen`)
+	fn update_gateway() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `240`
+		//  Estimated: `3795`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 3795)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: `GatewayRegistry::Gateways` (r:1 w:1)
+	/// Proof: `GatewayRegistry::Gateways` (`max_values`: None, `max_size`: Some(330), added: 2805, mode: `MaxEncodedLen`)
+	/// Storage: `GatewayRegistry::CounterForGateways` (r:1 w:1)
+	/// Proof: `GatewayRegistry::CounterForGateways` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GatewayRegistry::Health` (r:0 w:1)
+	/// Proof: `GatewayRegistry::Health` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `GatewayRegistry::GatewaysByOperator` (r:1 w:1)
+	/// Proof: `GatewayRegistry::GatewaysByOperator` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn deregister_gateway() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `256`
+		//  Estimated: `3795`
+		// Minimum execution time: 28_000_000 picoseconds.
+		Weight::from_parts(29_000_000, 3795)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: `GatewayRegistry::Reporters` (r:1 w:1)
+	/// Proof: `GatewayRegistry::Reporters` (`max_values`: Some(1), `max_size`: Some(513), added: 1008, mode: `MaxEncodedLen`)
+	fn add_reporter() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `110`
+		//  Estimated: `1998`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 1998)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: `GatewayRegistry::Reporters` (r:1 w:1)
+	/// Proof: `GatewayRegistry::Reporters` (`max_values`: Some(1), `max_size`: Some(513), added: 1008, mode: `MaxEncodedLen`)
+	/// Storage: `GatewayRegistry::LastReport` (r:0 w:1)
+	/// Proof: `GatewayRegistry::LastReport` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn remove_reporter() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `1998`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 1998)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: `GatewayRegistry::Reporters` (r:1 w:0)
+	/// Proof: `GatewayRegistry::Reporters` (`max_values`: Some(1), `max_size`: Some(513), added: 1008, mode: `MaxEncodedLen`)
+	/// Storage: `GatewayRegistry::LastReport` (r:1 w:1)
+	/// Proof: `GatewayRegistry::LastReport` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GatewayRegistry::Gateways` (r:32 w:0)
+	/// Proof: `GatewayRegistry::Gateways` (`max_values`: None, `max_size`: Some(330), added: 2805, mode: `MaxEncodedLen`)
+	/// Storage: `GatewayRegistry::Health` (r:32 w:32)
+	/// Proof: `GatewayRegistry::Health` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 32]`.
+	fn report_health(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `148 + n * (93 ±0)`
+		//  Estimated: `3517 + n * (2805 ±0)`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 3517)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2805).saturating_mul(n.into()))
+	}
+}
@@ -0,0 +1,163 @@
+//! Benchmarking setup for pallet-ipfs-pinning
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as IpfsPinning;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{Currency, EnsureOrigin, Get};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::traits::{Bounded, One};
+
+/// Give `who` enough funds for any number of bonds.
+fn funded<T: Config>(who: T::AccountId) -> T::AccountId {
+    T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 2u32.into());
+    who
+}
+
+/// A well-formed base32 CIDv1, distinct for every `i`.
+fn cid<T: Config>(i: u32) -> CidOf<T> {
+    let mut cid = b"bafkrei".to_vec();
+    cid.extend(
+        (0..32)
+            .step_by(4)
+            .map(|shift| b'a' + ((i >> shift) & 0xf) as u8),
+    );
+    cid.try_into().expect("MaxCidLength fits a short CID")
+}
+
+/// Open a bounty for `cid(i)` and have a fresh account pin it.
+fn pin<T: Config>(i: u32) -> (CidOf<T>, T::AccountId) {
+    let cid = cid::<T>(i);
+    <IpfsPinning<T> as pallet_mcp::traits::OnCidPublished>::on_cid_published(&cid);
+    let pinner = funded::<T>(account("pinner", i, 0));
+    IpfsPinning::<T>::claim_pin(RawOrigin::Signed(pinner.clone()).into(), cid.clone())
+        .expect("pin is claimed");
+    (cid, pinner)
+}
+
+/// Challenge the pin of `pinner` with a deadline at `deadline`.
+fn challenge<T: Config>(cid: &CidOf<T>, pinner: &T::AccountId, deadline: BlockNumberFor<T>) {
+    Pins::<T>::mutate(cid, pinner, |pin| {
+        pin.as_mut().expect("pin exists").challenge = Some(Challenge {
+            seed: Default::default(),
+            deadline,
+        })
+    });
+    ChallengeDeadlines::<T>::mutate(deadline, |challenged| {
+        challenged
+            .try_push((cid.clone(), pinner.clone()))
+            .expect("at most ChallengesPerRound challenges")
+    });
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn create_bounty() -> Result<(), BenchmarkError> {
+        let origin =
+            T::BountyOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let cid = cid::<T>(0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, cid.clone());
+
+        assert!(Bounties::<T>::contains_key(&cid));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_bounty(p: Linear<0, { T::MaxPinnersPerCid::get() }>) -> Result<(), BenchmarkError> {
+        let origin =
+            T::BountyOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let cid = cid::<T>(0);
+        <IpfsPinning<T> as pallet_mcp::traits::OnCidPublished>::on_cid_published(&cid);
+        for i in 0..p {
+            let pinner = funded::<T>(account("pinner", i, 0));
+            IpfsPinning::<T>::claim_pin(RawOrigin::Signed(pinner).into(), cid.clone())?;
+        }
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, cid.clone());
+
+        assert!(!Bounties::<T>::contains_key(&cid));
+        assert_eq!(PinCount::<T>::get(), 0);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn claim_pin() {
+        let caller = funded::<T>(whitelisted_caller());
+        let cid = cid::<T>(0);
+        <IpfsPinning<T> as pallet_mcp::traits::OnCidPublished>::on_cid_published(&cid);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), cid.clone());
+
+        assert!(Pins::<T>::contains_key(&cid, &caller));
+    }
+
+    #[benchmark]
+    fn attest_pin() {
+        let (cid, pinner) = pin::<T>(0);
+        let pot = funded::<T>(IpfsPinning::<T>::pot_account());
+        frame_system::Pallet::<T>::set_block_number(
+            frame_system::Pallet::<T>::block_number() + T::AttestationPeriod::get(),
+        );
+        let pot_balance = T::Currency::free_balance(&pot);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(pinner), cid, Default::default());
+
+        assert!(T::Currency::free_balance(&pot) < pot_balance);
+    }
+
+    #[benchmark]
+    fn release_pin() {
+        // Release the first of two pins, so that the last one moves into its slot
+        let (cid, pinner) = pin::<T>(0);
+        pin::<T>(1);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(pinner.clone()), cid.clone());
+
+        assert!(!Pins::<T>::contains_key(&cid, &pinner));
+        assert_eq!(PinCount::<T>::get(), 1);
+    }
+
+    #[benchmark]
+    fn expire_challenges(c: Linear<0, { T::ChallengesPerRound::get() }>) {
+        let now = frame_system::Pallet::<T>::block_number() + One::one();
+        for i in 0..c {
+            let (cid, pinner) = pin::<T>(i);
+            challenge::<T>(&cid, &pinner, now);
+        }
+
+        #[block]
+        {
+            IpfsPinning::<T>::expire_challenges(now);
+        }
+
+        assert_eq!(PinCount::<T>::get(), 0);
+    }
+
+    #[benchmark]
+    fn issue_challenges(c: Linear<0, { T::ChallengesPerRound::get() }>) {
+        for i in 0..c {
+            pin::<T>(i);
+        }
+        let now = frame_system::Pallet::<T>::block_number();
+
+        #[block]
+        {
+            IpfsPinning::<T>::issue_challenges(now);
+        }
+
+        let deadline = now + T::ChallengeWindow::get();
+        assert_eq!(ChallengeDeadlines::<T>::get(deadline).is_empty(), c == 0);
+    }
+
+    impl_benchmark_test_suite!(IpfsPinning, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,666 @@
+//! # IPFS Pinning Pallet
+//!
+//! A Substrate pallet rewarding accounts for keeping IPFS content pinned.
+//!
+//! ## Overview
+//!
+//! Content registered on chain is only referenced by its CID, so it stays reachable only as long
+//! as someone pins it. This pallet pays pinners for doing so:
+//! - A pin bounty is opened for a CID, by governance or automatically through the MCP pallet's
+//!   `OnCidPublished` hook whenever a server, prompt or resource references content
+//! - Pinners bond `PinBond` and claim the bounty
+//! - Pinners attest that they still hold the content; an attestation is rewarded from the
+//!   pallet's pot at most once every `AttestationPeriod` blocks
+//! - Every `ChallengePeriod` blocks, randomly picked pins are challenged and must be attested
+//!   within `ChallengeWindow` blocks; a pinner that misses the deadline loses its pin and its bond
+//!   goes to the pot
+//!
+//! The chain cannot fetch content, so it does not check the proof hashes themselves. A challenged
+//! pinner is expected to attest with the hash of the challenge seed followed by the content,
+//! which anyone holding the content can recompute from the emitted events.
+//!
+//! ## Functionality
+//!
+//! - `create_bounty`: Open a pin bounty for a CID (`BountyOrigin` only)
+//! - `remove_bounty`: Close a pin bounty and release its pins (`BountyOrigin` only)
+//! - `claim_pin`: Bond `PinBond` and start pinning a CID
+//! - `attest_pin`: Attest that a pinned CID is still held
+//! - `release_pin`: Stop pinning a CID and get the bond back
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use alloc::vec::Vec;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{BalanceStatus, Currency, ExistenceRequirement, Randomness, ReservableCurrency},
+        PalletId,
+    };
+    use frame_system::pallet_prelude::*;
+    use pallet_mcp::traits::OnCidPublished;
+    use sp_runtime::traits::{AccountIdConversion, Hash, Saturating, TrailingZeroInput, Zero};
+
+    /// Balance of the currency pins are bonded and rewarded in.
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// Content identifier of pinned content.
+    pub type CidOf<T> = BoundedVec<u8, <T as Config>::MaxCidLength>;
+
+    /// An open pin bounty.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Bounty<BlockNumber> {
+        /// Block at which the bounty was opened.
+        pub created_at: BlockNumber,
+        /// Number of accounts pinning the content.
+        pub pinners: u32,
+    }
+
+    /// A challenge a pinner must answer with an attestation.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Challenge<Hash, BlockNumber> {
+        /// Seed to hash together with the content.
+        pub seed: Hash,
+        /// Last block at which the challenge can be answered.
+        pub deadline: BlockNumber,
+    }
+
+    /// An account pinning a CID.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct Pin<Balance, Hash, BlockNumber> {
+        /// Amount reserved from the pinner.
+        pub bond: Balance,
+        /// Position of the pin in `PinSlots`.
+        pub slot: u32,
+        /// Block at which the pin was claimed.
+        pub claimed_at: BlockNumber,
+        /// Block of the last rewarded attestation, or of the claim before the first one.
+        pub last_rewarded: BlockNumber,
+        /// The pending challenge, if any.
+        pub challenge: Option<Challenge<Hash, BlockNumber>>,
+    }
+
+    /// Pin as stored by the pallet.
+    pub type PinOf<T> = Pin<BalanceOf<T>, <T as frame_system::Config>::Hash, BlockNumberFor<T>>;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// The pallet's configuration trait.
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+        /// Currency in which pins are bonded and attestations rewarded
+        type Currency: ReservableCurrency<Self::AccountId>;
+        /// Source of the seeds used to pick the challenged pins
+        type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+        /// Origin allowed to open and close bounties
+        type BountyOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Identifier of the pot rewards are paid from and slashed bonds are paid to
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+        /// Maximum length of a CID
+        #[pallet::constant]
+        type MaxCidLength: Get<u32>;
+        /// Maximum number of accounts pinning the same CID
+        #[pallet::constant]
+        type MaxPinnersPerCid: Get<u32>;
+        /// Amount reserved from a pinner for each pin
+        #[pallet::constant]
+        type PinBond: Get<BalanceOf<Self>>;
+        /// Reward paid for a rewarded attestation
+        #[pallet::constant]
+        type AttestationReward: Get<BalanceOf<Self>>;
+        /// Minimum number of blocks between two rewarded attestations of a pin
+        #[pallet::constant]
+        type AttestationPeriod: Get<BlockNumberFor<Self>>;
+        /// Number of blocks between two rounds of challenges
+        #[pallet::constant]
+        type ChallengePeriod: Get<BlockNumberFor<Self>>;
+        /// Number of blocks a challenged pinner has to attest
+        #[pallet::constant]
+        type ChallengeWindow: Get<BlockNumberFor<Self>>;
+        /// Maximum number of pins challenged in a round
+        #[pallet::constant]
+        type ChallengesPerRound: Get<u32>;
+    }
+
+    /// Open pin bounties by CID.
+    #[pallet::storage]
+    pub type Bounties<T: Config> =
+        StorageMap<_, Blake2_128Concat, CidOf<T>, Bounty<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Pins by CID and pinner.
+    #[pallet::storage]
+    pub type Pins<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        CidOf<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        PinOf<T>,
+        OptionQuery,
+    >;
+
+    /// Every pin under a position in `0..PinCount`, so that challenges can pick pins at random.
+    #[pallet::storage]
+    pub type PinSlots<T: Config> =
+        StorageMap<_, Twox64Concat, u32, (CidOf<T>, T::AccountId), OptionQuery>;
+
+    /// Number of pins.
+    #[pallet::storage]
+    pub type PinCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Pins challenged in a round, by the deadline of their challenges.
+    #[pallet::storage]
+    pub type ChallengeDeadlines<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<(CidOf<T>, T::AccountId), T::ChallengesPerRound>,
+        ValueQuery,
+    >;
+
+    /// Events emitted by this pallet.
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A pin bounty was opened.
+        BountyCreated {
+            /// The content to pin.
+            cid: CidOf<T>,
+        },
+        /// A pin bounty was closed and its pins released.
+        BountyRemoved {
+            /// The content that was pinned.
+            cid: CidOf<T>,
+            /// Number of released pins.
+            pins_released: u32,
+        },
+        /// An account started pinning a CID.
+        PinClaimed {
+            /// The pinned content.
+            cid: CidOf<T>,
+            /// The pinner.
+            pinner: T::AccountId,
+            /// The reserved bond.
+            bond: BalanceOf<T>,
+        },
+        /// A pinner attested that it holds the content.
+        PinAttested {
+            /// The pinned content.
+            cid: CidOf<T>,
+            /// The pinner.
+            pinner: T::AccountId,
+            /// The submitted proof.
+            proof_hash: T::Hash,
+            /// Seed of the challenge the attestation answered, if any.
+            challenge: Option<T::Hash>,
+            /// Reward paid from the pot; zero if the attestation was not rewarded or the pot
+            /// could not pay.
+            reward: BalanceOf<T>,
+        },
+        /// A pin was challenged.
+        PinChallenged {
+            /// The pinned content.
+            cid: CidOf<T>,
+            /// The challenged pinner.
+            pinner: T::AccountId,
+            /// Seed to hash together with the content.
+            seed: T::Hash,
+            /// Last block at which the challenge can be answered.
+            deadline: BlockNumberFor<T>,
+        },
+        /// A pinner stopped pinning a CID and got its bond back.
+        PinReleased {
+            /// The content that was pinned.
+            cid: CidOf<T>,
+            /// The pinner.
+            pinner: T::AccountId,
+        },
+        /// A pinner missed a challenge, lost its pin and had its bond paid to the pot.
+        PinSlashed {
+            /// The content that was pinned.
+            cid: CidOf<T>,
+            /// The pinner.
+            pinner: T::AccountId,
+            /// The slashed bond.
+            bond: BalanceOf<T>,
+        },
+    }
+
+    /// Errors that can be returned by this pallet.
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The CID is malformed.
+        InvalidCid,
+        /// A bounty is already open for the CID.
+        BountyExists,
+        /// No bounty is open for the CID.
+        BountyNotFound,
+        /// The caller already pins the CID.
+        AlreadyPinned,
+        /// The CID already has `MaxPinnersPerCid` pinners.
+        TooManyPinners,
+        /// The caller does not pin the CID.
+        NotPinned,
+        /// The pin has no pending challenge and its last rewarded attestation is less than
+        /// `AttestationPeriod` blocks old.
+        AttestationTooEarly,
+        /// The pin has a pending challenge.
+        ChallengePending,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            let expired = Self::expire_challenges(now);
+            let mut weight = T::WeightInfo::expire_challenges(expired);
+            let period = T::ChallengePeriod::get();
+            if !period.is_zero() && (now % period).is_zero() {
+                let picks = Self::issue_challenges(now);
+                weight = weight.saturating_add(T::WeightInfo::issue_challenges(picks));
+            }
+            weight
+        }
+
+        fn integrity_test() {
+            assert!(
+                !T::ChallengeWindow::get().is_zero(),
+                "Challenges must leave pinners time to attest"
+            );
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
+        }
+    }
+
+    /// Dispatchable functions for the IPFS pinning pallet.
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Open a pin bounty for `cid`.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (must be `BountyOrigin`)
+        /// * `cid` - The content to pin
+        ///
+        /// # Errors
+        /// * `InvalidCid` - If the CID is malformed
+        /// * `BountyExists` - If a bounty is already open for the CID
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::create_bounty())]
+        pub fn create_bounty(origin: OriginFor<T>, cid: CidOf<T>) -> DispatchResult {
+            T::BountyOrigin::ensure_origin(origin)?;
+            ensure!(pallet_mcp::cid::is_valid(&cid), Error::<T>::InvalidCid);
+            ensure!(!Bounties::<T>::contains_key(&cid), Error::<T>::BountyExists);
+
+            Self::open_bounty(cid);
+
+            Ok(())
+        }
+
+        /// Close the pin bounty of `cid`, returning their bonds to its pinners.
+        ///
+        /// Pending challenges of the released pins are dropped. The call is charged for
+        /// `MaxPinnersPerCid` pins; the difference is refunded.
+        ///
+        /// # Errors
+        /// * `BountyNotFound` - If no bounty is open for the CID
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::remove_bounty(T::MaxPinnersPerCid::get()))]
+        pub fn remove_bounty(origin: OriginFor<T>, cid: CidOf<T>) -> DispatchResultWithPostInfo {
+            T::BountyOrigin::ensure_origin(origin)?;
+            ensure!(
+                Bounties::<T>::contains_key(&cid),
+                Error::<T>::BountyNotFound
+            );
+
+            // Freeing a slot may move another pin of the bounty, so each pin is read afresh
+            let pinners: Vec<T::AccountId> = Pins::<T>::iter_key_prefix(&cid).collect();
+            for pinner in &pinners {
+                if let Some(pin) = Pins::<T>::get(&cid, pinner) {
+                    Self::remove_pin(&cid, pinner, &pin);
+                    T::Currency::unreserve(pinner, pin.bond);
+                }
+            }
+            let pins_released = pinners.len() as u32;
+            Bounties::<T>::remove(&cid);
+
+            Self::deposit_event(Event::BountyRemoved { cid, pins_released });
+
+            Ok(Some(T::WeightInfo::remove_bounty(pins_released)).into())
+        }
+
+        /// Start pinning `cid`, reserving `PinBond` from the caller.
+        ///
+        /// The first attestation is rewarded `AttestationPeriod` blocks after the claim.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (must be signed)
+        /// * `cid` - The content to pin
+        ///
+        /// # Errors
+        /// * `BountyNotFound` - If no bounty is open for the CID
+        /// * `AlreadyPinned` - If the caller already pins the CID
+        /// * `TooManyPinners` - If the CID already has `MaxPinnersPerCid` pinners
+        /// * `InsufficientBalance` - If the caller cannot reserve `PinBond`
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::claim_pin())]
+        pub fn claim_pin(origin: OriginFor<T>, cid: CidOf<T>) -> DispatchResult {
+            let pinner = ensure_signed(origin)?;
+            ensure!(
+                !Pins::<T>::contains_key(&cid, &pinner),
+                Error::<T>::AlreadyPinned
+            );
+
+            Bounties::<T>::try_mutate(&cid, |bounty| -> DispatchResult {
+                let bounty = bounty.as_mut().ok_or(Error::<T>::BountyNotFound)?;
+                ensure!(
+                    bounty.pinners < T::MaxPinnersPerCid::get(),
+                    Error::<T>::TooManyPinners
+                );
+                bounty.pinners += 1;
+                Ok(())
+            })?;
+            let bond = T::PinBond::get();
+            T::Currency::reserve(&pinner, bond)?;
+
+            let slot = PinCount::<T>::get();
+            PinCount::<T>::put(slot.saturating_add(1));
+            PinSlots::<T>::insert(slot, (cid.clone(), pinner.clone()));
+            let now = frame_system::Pallet::<T>::block_number();
+            Pins::<T>::insert(
+                &cid,
+                &pinner,
+                Pin {
+                    bond,
+                    slot,
+                    claimed_at: now,
+                    last_rewarded: now,
+                    challenge: None,
+                },
+            );
+
+            Self::deposit_event(Event::PinClaimed { cid, pinner, bond });
+
+            Ok(())
+        }
+
+        /// Attest that the caller still holds the content of `cid`.
+        ///
+        /// An attestation answers the pending challenge of the pin, if any, and is rewarded with
+        /// `AttestationReward` from the pot if the last rewarded one is at least
+        /// `AttestationPeriod` blocks old. A challenge is answered with the hash of its seed
+        /// followed by the content.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (must be signed)
+        /// * `cid` - The pinned content
+        /// * `proof_hash` - Hash proving that the caller holds the content
+        ///
+        /// # Errors
+        /// * `NotPinned` - If the caller does not pin the CID
+        /// * `AttestationTooEarly` - If the attestation neither answers a challenge nor is due
+        ///   for a reward
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::attest_pin())]
+        pub fn attest_pin(
+            origin: OriginFor<T>,
+            cid: CidOf<T>,
+            proof_hash: T::Hash,
+        ) -> DispatchResult {
+            let pinner = ensure_signed(origin)?;
+            let now = frame_system::Pallet::<T>::block_number();
+
+            let (challenge, rewarded) =
+                Pins::<T>::try_mutate(&cid, &pinner, |pin| -> Result<_, DispatchError> {
+                    let pin = pin.as_mut().ok_or(Error::<T>::NotPinned)?;
+                    let challenge = pin.challenge.take().map(|challenge| challenge.seed);
+                    let rewarded = now
+                        >= pin
+                            .last_rewarded
+                            .saturating_add(T::AttestationPeriod::get());
+                    ensure!(
+                        challenge.is_some() || rewarded,
+                        Error::<T>::AttestationTooEarly
+                    );
+                    if rewarded {
+                        pin.last_rewarded = now;
+                    }
+                    Ok((challenge, rewarded))
+                })?;
+
+            let mut reward = Zero::zero();
+            if rewarded {
+                let amount = T::AttestationReward::get();
+                if T::Currency::transfer(
+                    &Self::pot_account(),
+                    &pinner,
+                    amount,
+                    ExistenceRequirement::KeepAlive,
+                )
+                .is_ok()
+                {
+                    reward = amount;
+                }
+            }
+
+            Self::deposit_event(Event::PinAttested {
+                cid,
+                pinner,
+                proof_hash,
+                challenge,
+                reward,
+            });
+
+            Ok(())
+        }
+
+        /// Stop pinning `cid` and get the bond back.
+        ///
+        /// # Errors
+        /// * `NotPinned` - If the caller does not pin the CID
+        /// * `ChallengePending` - If the pin has a pending challenge
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::release_pin())]
+        pub fn release_pin(origin: OriginFor<T>, cid: CidOf<T>) -> DispatchResult {
+            let pinner = ensure_signed(origin)?;
+            let pin = Pins::<T>::get(&cid, &pinner).ok_or(Error::<T>::NotPinned)?;
+            ensure!(pin.challenge.is_none(), Error::<T>::ChallengePending);
+
+            Self::remove_pin(&cid, &pinner, &pin);
+            T::Currency::unreserve(&pinner, pin.bond);
+
+            Self::deposit_event(Event::PinReleased { cid, pinner });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> OnCidPublished for Pallet<T> {
+        /// Open a bounty for every newly referenced CID; CIDs longer than `MaxCidLength` are
+        /// ignored.
+        fn on_cid_published(cid: &[u8]) {
+            let Ok(cid) = CidOf::<T>::try_from(cid.to_vec()) else {
+                return;
+            };
+            if !Bounties::<T>::contains_key(&cid) {
+                Self::open_bounty(cid);
+            }
+        }
+
+        fn weight() -> Weight {
+            T::WeightInfo::create_bounty()
+        }
+    }
+
+    /// Helper functions for pins and challenges.
+    impl<T: Config> Pallet<T> {
+        /// The account of the pot rewards are paid from. Anyone can fund it with a transfer.
+        pub fn pot_account() -> T::AccountId {
+            T::PalletId::get().into_account_truncating()
+        }
+
+        fn open_bounty(cid: CidOf<T>) {
+            Bounties::<T>::insert(
+                &cid,
+                Bounty {
+                    created_at: frame_system::Pallet::<T>::block_number(),
+                    pinners: 0,
+                },
+            );
+            Self::deposit_event(Event::BountyCreated { cid });
+        }
+
+        /// Remove a pin from storage, without touching its bond.
+        fn remove_pin(cid: &CidOf<T>, pinner: &T::AccountId, pin: &PinOf<T>) {
+            Pins::<T>::remove(cid, pinner);
+            Self::free_slot(pin.slot);
+            Bounties::<T>::mutate(cid, |bounty| {
+                if let Some(bounty) = bounty {
+                    bounty.pinners = bounty.pinners.saturating_sub(1);
+                }
+            });
+        }
+
+        /// Free a position in `PinSlots` by moving the last pin into it.
+        fn free_slot(slot: u32) {
+            let last = PinCount::<T>::get().saturating_sub(1);
+            PinCount::<T>::put(last);
+            let Some((cid, pinner)) = PinSlots::<T>::take(last) else {
+                return;
+            };
+            if slot != last {
+                Pins::<T>::mutate(&cid, &pinner, |pin| {
+                    if let Some(pin) = pin {
+                        pin.slot = slot;
+                    }
+                });
+                PinSlots::<T>::insert(slot, (cid, pinner));
+            }
+        }
+
+        /// Slash the pins whose challenges expire at `now` without an answer. Returns the number
+        /// of challenges that expired.
+        pub(crate) fn expire_challenges(now: BlockNumberFor<T>) -> u32 {
+            let expiring = ChallengeDeadlines::<T>::take(now);
+            for (cid, pinner) in &expiring {
+                let Some(pin) = Pins::<T>::get(cid, pinner) else {
+                    continue;
+                };
+                if pin
+                    .challenge
+                    .as_ref()
+                    .is_none_or(|challenge| challenge.deadline != now)
+                {
+                    continue;
+                }
+                Self::remove_pin(cid, pinner, &pin);
+                let unpaid = T::Currency::repatriate_reserved(
+                    pinner,
+                    &Self::pot_account(),
+                    pin.bond,
+                    BalanceStatus::Free,
+                )
+                .unwrap_or(pin.bond);
+                if !unpaid.is_zero() {
+                    let _ = T::Currency::slash_reserved(pinner, unpaid);
+                }
+                Self::deposit_event(Event::PinSlashed {
+                    cid: cid.clone(),
+                    pinner: pinner.clone(),
+                    bond: pin.bond,
+                });
+            }
+            expiring.len() as u32
+        }
+
+        /// Challenge up to `ChallengesPerRound` randomly picked pins. Returns the number of picks;
+        /// a pin that was picked twice or already has a challenge is not challenged again.
+        pub(crate) fn issue_challenges(now: BlockNumberFor<T>) -> u32 {
+            let count = PinCount::<T>::get();
+            if count == 0 {
+                return 0;
+            }
+            let (random, _) = T::Randomness::random(b"ipfs-pinning/challenge");
+            let deadline = now.saturating_add(T::ChallengeWindow::get());
+            let mut challenged = ChallengeDeadlines::<T>::get(deadline);
+            let picks = T::ChallengesPerRound::get().min(count);
+            for round in 0..picks {
+                let seed = T::Hashing::hash_of(&(random, round));
+                let slot = u32::decode(&mut TrailingZeroInput::new(seed.as_ref()))
+                    .unwrap_or_default()
+                    % count;
+                let Some((cid, pinner)) = PinSlots::<T>::get(slot) else {
+                    continue;
+                };
+                let Some(mut pin) = Pins::<T>::get(&cid, &pinner) else {
+                    continue;
+                };
+                if pin.challenge.is_some()
+                    || challenged.try_push((cid.clone(), pinner.clone())).is_err()
+                {
+                    continue;
+                }
+                pin.challenge = Some(Challenge { seed, deadline });
+                Pins::<T>::insert(&cid, &pinner, pin);
+                Self::deposit_event(Event::PinChallenged {
+                    cid,
+                    pinner,
+                    seed,
+                    deadline,
+                });
+            }
+            ChallengeDeadlines::<T>::insert(deadline, challenged);
+            picks
+        }
+
+        /// Check the consistency of pins, slots and bounties.
+        #[cfg(any(feature = "try-runtime", test))]
+        pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+            let count = PinCount::<T>::get();
+            let mut pins = 0u32;
+            for (cid, pinner, pin) in Pins::<T>::iter() {
+                pins += 1;
+                ensure!(
+                    PinSlots::<T>::get(pin.slot) == Some((cid.clone(), pinner)),
+                    "Pin is not in its slot"
+                );
+                ensure!(Bounties::<T>::contains_key(&cid), "Pin without a bounty");
+            }
+            ensure!(pins == count, "Pin count does not match the pins");
+            ensure!(
+                PinSlots::<T>::iter_keys().all(|slot| slot < count),
+                "Slot beyond the pin count"
+            );
+            for (cid, bounty) in Bounties::<T>::iter() {
+                ensure!(
+                    Pins::<T>::iter_prefix(&cid).count() as u32 == bounty.pinners,
+                    "Bounty pinner count does not match its pins"
+                );
+            }
+            Ok(())
+        }
+    }
+}
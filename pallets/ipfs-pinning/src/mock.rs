@@ -0,0 +1,132 @@
+use crate as pallet_ipfs_pinning;
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{ConstU16, ConstU64, Randomness},
+    PalletId,
+};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{
+    traits::{BlakeTwo256, Hash, IdentityLookup},
+    BuildStorage,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+    pub enum Test
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        IpfsPinning: pallet_ipfs_pinning,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Nonce = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Block = Block;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<u64>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ConstU16<42>;
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+}
+
+/// Deterministic randomness derived from the subject and the current block.
+pub struct TestRandomness;
+
+impl Randomness<H256, u64> for TestRandomness {
+    fn random(subject: &[u8]) -> (H256, u64) {
+        let now = System::block_number();
+        (BlakeTwo256::hash_of(&(subject, now)), now)
+    }
+}
+
+parameter_types! {
+    pub const PinningPalletId: PalletId = PalletId(*b"py/ipfsp");
+    pub const MaxCidLength: u32 = 64;
+    pub const MaxPinnersPerCid: u32 = 2;
+    pub const PinBond: u64 = 50;
+    pub const AttestationReward: u64 = 5;
+    pub const AttestationPeriod: u64 = 10;
+    pub const ChallengePeriod: u64 = 5;
+    pub const ChallengeWindow: u64 = 3;
+    pub const ChallengesPerRound: u32 = 2;
+}
+
+impl pallet_ipfs_pinning::Config for Test {
+    type WeightInfo = ();
+    type Currency = Balances;
+    type Randomness = TestRandomness;
+    type BountyOrigin = EnsureRoot<u64>;
+    type PalletId = PinningPalletId;
+    type MaxCidLength = MaxCidLength;
+    type MaxPinnersPerCid = MaxPinnersPerCid;
+    type PinBond = PinBond;
+    type AttestationReward = AttestationReward;
+    type AttestationPeriod = AttestationPeriod;
+    type ChallengePeriod = ChallengePeriod;
+    type ChallengeWindow = ChallengeWindow;
+    type ChallengesPerRound = ChallengesPerRound;
+}
+
+/// Balance of every funded test account, enough for a few pin bonds.
+pub const INITIAL_BALANCE: u64 = 1_000;
+
+/// Accounts funded at genesis.
+pub const FUNDED: [u64; 3] = [1, 2, 3];
+
+/// Balance of the reward pot at genesis.
+pub const POT_BALANCE: u64 = 100;
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    let pot = (IpfsPinning::pot_account(), POT_BALANCE);
+    pallet_balances::GenesisConfig::<Test> {
+        balances: FUNDED
+            .iter()
+            .map(|who| (*who, INITIAL_BALANCE))
+            .chain([pot])
+            .collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+    let mut ext: sp_io::TestExternalities = storage.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+/// Run `test` in fresh externalities, then check that it left the pallet's storage consistent.
+pub fn build_and_execute(test: impl FnOnce()) {
+    new_test_ext().execute_with(|| {
+        test();
+        IpfsPinning::do_try_state().expect("storage is consistent");
+    });
+}
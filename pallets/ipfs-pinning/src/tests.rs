@@ -0,0 +1,303 @@
+use crate::{mock::*, Bounties, ChallengeDeadlines, CidOf, Error, Event, PinCount, PinSlots, Pins};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use pallet_mcp::traits::OnCidPublished;
+use sp_core::H256;
+use sp_runtime::DispatchError;
+
+const CID: &[u8] = b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi";
+const OTHER_CID: &[u8] = b"bafkreigh2akiscaildcqabsyg3dfr6chu3fgpregiymsck7e7aqa4s52zy";
+
+fn cid(bytes: &[u8]) -> CidOf<Test> {
+    bytes.to_vec().try_into().unwrap()
+}
+
+fn bounty(bytes: &[u8]) -> CidOf<Test> {
+    assert_ok!(IpfsPinning::create_bounty(
+        RuntimeOrigin::root(),
+        cid(bytes)
+    ));
+    cid(bytes)
+}
+
+/// Run the pallet's block initialization up to and including block `n`.
+fn run_to(n: u64) {
+    while System::block_number() < n {
+        let next = System::block_number() + 1;
+        System::set_block_number(next);
+        IpfsPinning::on_initialize(next);
+    }
+}
+
+fn pot() -> u64 {
+    Balances::free_balance(IpfsPinning::pot_account())
+}
+
+#[test]
+fn bounties_are_opened_by_governance() {
+    build_and_execute(|| {
+        assert_noop!(
+            IpfsPinning::create_bounty(RuntimeOrigin::signed(1), cid(CID)),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            IpfsPinning::create_bounty(RuntimeOrigin::root(), cid(b"not-a-cid")),
+            Error::<Test>::InvalidCid
+        );
+
+        let cid = bounty(CID);
+        System::assert_last_event(Event::BountyCreated { cid: cid.clone() }.into());
+        assert_eq!(Bounties::<Test>::get(&cid).unwrap().created_at, 1);
+        assert_noop!(
+            IpfsPinning::create_bounty(RuntimeOrigin::root(), cid),
+            Error::<Test>::BountyExists
+        );
+    });
+}
+
+#[test]
+fn published_cids_open_bounties() {
+    build_and_execute(|| {
+        <IpfsPinning as OnCidPublished>::on_cid_published(CID);
+        assert!(Bounties::<Test>::contains_key(cid(CID)));
+
+        // Republishing keeps the existing bounty and its pinners
+        assert_ok!(IpfsPinning::claim_pin(RuntimeOrigin::signed(1), cid(CID)));
+        <IpfsPinning as OnCidPublished>::on_cid_published(CID);
+        assert_eq!(Bounties::<Test>::get(cid(CID)).unwrap().pinners, 1);
+
+        // CIDs that do not fit are ignored
+        <IpfsPinning as OnCidPublished>::on_cid_published(&[b'b'; 65]);
+        assert_eq!(Bounties::<Test>::iter().count(), 1);
+    });
+}
+
+#[test]
+fn claim_pin_reserves_bond() {
+    build_and_execute(|| {
+        assert_noop!(
+            IpfsPinning::claim_pin(RuntimeOrigin::signed(1), cid(CID)),
+            Error::<Test>::BountyNotFound
+        );
+        let cid = bounty(CID);
+
+        assert_ok!(IpfsPinning::claim_pin(
+            RuntimeOrigin::signed(1),
+            cid.clone()
+        ));
+        System::assert_last_event(
+            Event::PinClaimed {
+                cid: cid.clone(),
+                pinner: 1,
+                bond: PinBond::get(),
+            }
+            .into(),
+        );
+        assert_eq!(Balances::reserved_balance(1), PinBond::get());
+        assert_eq!(PinSlots::<Test>::get(0), Some((cid.clone(), 1)));
+        assert_noop!(
+            IpfsPinning::claim_pin(RuntimeOrigin::signed(1), cid.clone()),
+            Error::<Test>::AlreadyPinned
+        );
+
+        assert_ok!(IpfsPinning::claim_pin(
+            RuntimeOrigin::signed(2),
+            cid.clone()
+        ));
+        assert_noop!(
+            IpfsPinning::claim_pin(RuntimeOrigin::signed(3), cid.clone()),
+            Error::<Test>::TooManyPinners
+        );
+        // Unfunded accounts cannot bond
+        assert!(IpfsPinning::claim_pin(RuntimeOrigin::signed(4), bounty(OTHER_CID)).is_err());
+        assert_eq!(PinCount::<Test>::get(), 2);
+    });
+}
+
+#[test]
+fn attestations_are_rewarded_once_per_period() {
+    build_and_execute(|| {
+        let cid = bounty(CID);
+        assert_ok!(IpfsPinning::claim_pin(
+            RuntimeOrigin::signed(1),
+            cid.clone()
+        ));
+        let proof_hash = H256::repeat_byte(1);
+        assert_noop!(
+            IpfsPinning::attest_pin(RuntimeOrigin::signed(2), cid.clone(), proof_hash),
+            Error::<Test>::NotPinned
+        );
+        assert_noop!(
+            IpfsPinning::attest_pin(RuntimeOrigin::signed(1), cid.clone(), proof_hash),
+            Error::<Test>::AttestationTooEarly
+        );
+
+        System::set_block_number(1 + AttestationPeriod::get());
+        assert_ok!(IpfsPinning::attest_pin(
+            RuntimeOrigin::signed(1),
+            cid.clone(),
+            proof_hash
+        ));
+        System::assert_last_event(
+            Event::PinAttested {
+                cid: cid.clone(),
+                pinner: 1,
+                proof_hash,
+                challenge: None,
+                reward: AttestationReward::get(),
+            }
+            .into(),
+        );
+        assert_eq!(
+            Balances::free_balance(1),
+            INITIAL_BALANCE - PinBond::get() + AttestationReward::get()
+        );
+        assert_eq!(pot(), POT_BALANCE - AttestationReward::get());
+        assert_noop!(
+            IpfsPinning::attest_pin(RuntimeOrigin::signed(1), cid, proof_hash),
+            Error::<Test>::AttestationTooEarly
+        );
+    });
+}
+
+#[test]
+fn missed_challenges_are_slashed() {
+    build_and_execute(|| {
+        let cid = bounty(CID);
+        assert_ok!(IpfsPinning::claim_pin(
+            RuntimeOrigin::signed(1),
+            cid.clone()
+        ));
+
+        run_to(ChallengePeriod::get());
+        let deadline = ChallengePeriod::get() + ChallengeWindow::get();
+        let challenge = Pins::<Test>::get(&cid, 1).unwrap().challenge.unwrap();
+        assert_eq!(challenge.deadline, deadline);
+        System::assert_has_event(
+            Event::PinChallenged {
+                cid: cid.clone(),
+                pinner: 1,
+                seed: challenge.seed,
+                deadline,
+            }
+            .into(),
+        );
+        assert_noop!(
+            IpfsPinning::release_pin(RuntimeOrigin::signed(1), cid.clone()),
+            Error::<Test>::ChallengePending
+        );
+
+        run_to(deadline);
+        System::assert_has_event(
+            Event::PinSlashed {
+                cid: cid.clone(),
+                pinner: 1,
+                bond: PinBond::get(),
+            }
+            .into(),
+        );
+        assert!(!Pins::<Test>::contains_key(&cid, 1));
+        assert!(!ChallengeDeadlines::<Test>::contains_key(deadline));
+        assert_eq!(Bounties::<Test>::get(&cid).unwrap().pinners, 0);
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - PinBond::get());
+        assert_eq!(pot(), POT_BALANCE + PinBond::get());
+    });
+}
+
+#[test]
+fn answered_challenges_keep_the_pin() {
+    build_and_execute(|| {
+        let cid = bounty(CID);
+        assert_ok!(IpfsPinning::claim_pin(
+            RuntimeOrigin::signed(1),
+            cid.clone()
+        ));
+        run_to(ChallengePeriod::get());
+        let seed = Pins::<Test>::get(&cid, 1).unwrap().challenge.unwrap().seed;
+
+        // Answering a challenge is not rewarded before the attestation period is over
+        let proof_hash = H256::repeat_byte(2);
+        assert_ok!(IpfsPinning::attest_pin(
+            RuntimeOrigin::signed(1),
+            cid.clone(),
+            proof_hash
+        ));
+        System::assert_last_event(
+            Event::PinAttested {
+                cid: cid.clone(),
+                pinner: 1,
+                proof_hash,
+                challenge: Some(seed),
+                reward: 0,
+            }
+            .into(),
+        );
+
+        run_to(ChallengePeriod::get() + ChallengeWindow::get());
+        assert!(Pins::<Test>::get(&cid, 1).is_some());
+        assert_eq!(Balances::reserved_balance(1), PinBond::get());
+        assert_eq!(pot(), POT_BALANCE);
+    });
+}
+
+#[test]
+fn release_pin_and_remove_bounty_return_bonds() {
+    build_and_execute(|| {
+        let cid = bounty(CID);
+        let other = bounty(OTHER_CID);
+        assert_ok!(IpfsPinning::claim_pin(
+            RuntimeOrigin::signed(1),
+            cid.clone()
+        ));
+        assert_ok!(IpfsPinning::claim_pin(
+            RuntimeOrigin::signed(2),
+            cid.clone()
+        ));
+        assert_ok!(IpfsPinning::claim_pin(
+            RuntimeOrigin::signed(3),
+            other.clone()
+        ));
+
+        // The last pin moves into the freed slot
+        assert_ok!(IpfsPinning::release_pin(
+            RuntimeOrigin::signed(1),
+            cid.clone()
+        ));
+        System::assert_last_event(
+            Event::PinReleased {
+                cid: cid.clone(),
+                pinner: 1,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(PinSlots::<Test>::get(0), Some((other.clone(), 3)));
+        assert_eq!(Pins::<Test>::get(&other, 3).unwrap().slot, 0);
+        assert_noop!(
+            IpfsPinning::release_pin(RuntimeOrigin::signed(1), cid.clone()),
+            Error::<Test>::NotPinned
+        );
+
+        assert_noop!(
+            IpfsPinning::remove_bounty(RuntimeOrigin::signed(1), cid.clone()),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(IpfsPinning::remove_bounty(
+            RuntimeOrigin::root(),
+            cid.clone()
+        ));
+        System::assert_last_event(
+            Event::BountyRemoved {
+                cid: cid.clone(),
+                pins_released: 1,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_eq!(PinCount::<Test>::get(), 1);
+        assert_noop!(
+            IpfsPinning::remove_bounty(RuntimeOrigin::root(), cid),
+            Error::<Test>::BountyNotFound
+        );
+    });
+}
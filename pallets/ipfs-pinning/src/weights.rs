@@ -0,0 +1,334 @@
+//! Autogenerated weights for `pallet_ipfs_pinning`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2024-01-01, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `substrate-node`, CPU: `Intel(R) Core(TM) i7-8700K CPU @ 3.70GHz`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: 1024
+
+// Executed Command:
+// ./target/production/substrate-node
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_ipfs_pinning
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./pallets/ipfs-pinning/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_ipfs_pinning`.
+pub trait WeightInfo {
+	fn create_bounty() -> Weight;
+	fn remove_bounty(p: u32, ) -> Weight;
+	fn claim_pin() -> Weight;
+	fn attest_pin() -> Weight;
+	fn release_pin() -> Weight;
+	fn expire_challenges(c: u32, ) -> Weight;
+	fn issue_challenges(c: u32, ) -> Weight;
+}
+
+/// Weights for `pallet_ipfs_pinning` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `IpfsPinning::Bounties` (r:1 w:1)
+	/// Proof: `IpfsPinning::Bounties` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	fn create_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3554`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 3554)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: `IpfsPinning::Bounties` (r:1 w:1)
+	/// Proof: `IpfsPinning::Bounties` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::Pins` (r:16 w:16)
+	/// Proof: `IpfsPinning::Pins` (`max_values`: None, `max_size`: Some(194), added: 2669, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::PinCount` (r:1 w:1)
+	/// Proof: `IpfsPinning::PinCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::PinSlots` (r:16 w:16)
+	/// Proof: `IpfsPinning::PinSlots` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:16 w:16)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[0, 16]`.
+	fn remove_bounty(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `187 + p * (290 ±0)`
+		//  Estimated: `3554 + p * (2669 ±0)`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 3554)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(14_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2669).saturating_mul(p.into()))
+	}
+
+	/// Storage: `IpfsPinning::Pins` (r:1 w:1)
+	/// Proof: `IpfsPinning::Pins` (`max_values`: None, `max_size`: Some(194), added: 2669, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::Bounties` (r:1 w:1)
+	/// Proof: `IpfsPinning::Bounties` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::PinCount` (r:1 w:1)
+	/// Proof: `IpfsPinning::PinCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::PinSlots` (r:0 w:1)
+	/// Proof: `IpfsPinning::PinSlots` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	fn claim_pin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `145`
+		//  Estimated: `3659`
+		// Minimum execution time: 33_000_000 picoseconds.
+		Weight::from_parts(35_000_000, 3659)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: `IpfsPinning::Pins` (r:1 w:1)
+	/// Proof: `IpfsPinning::Pins` (`max_values`: None, `max_size`: Some(194), added: 2669, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn attest_pin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `367`
+		//  Estimated: `6196`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(40_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: `IpfsPinning::Pins` (r:1 w:1)
+	/// Proof: `IpfsPinning::Pins` (`max_values`: None, `max_size`: Some(194), added: 2669, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::PinCount` (r:1 w:1)
+	/// Proof: `IpfsPinning::PinCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::PinSlots` (r:1 w:1)
+	/// Proof: `IpfsPinning::PinSlots` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::Bounties` (r:1 w:1)
+	/// Proof: `IpfsPinning::Bounties` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn release_pin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `453`
+		//  Estimated: `3659`
+		// Minimum execution time: 36_000_000 picoseconds.
+		Weight::from_parts(38_000_000, 3659)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: `IpfsPinning::ChallengeDeadlines` (r:1 w:1)
+	/// Proof: `IpfsPinning::ChallengeDeadlines` (`max_values`: None, `max_size`: Some(1565), added: 4040, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::Pins` (r:16 w:16)
+	/// Proof: `IpfsPinning::Pins` (`max_values`: None, `max_size`: Some(194), added: 2669, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::PinCount` (r:16 w:16)
+	/// Proof: `IpfsPinning::PinCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::PinSlots` (r:16 w:16)
+	/// Proof: `IpfsPinning::PinSlots` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::Bounties` (r:16 w:16)
+	/// Proof: `IpfsPinning::Bounties` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:32 w:32)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 16]`.
+	fn expire_challenges(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42 + c * (420 ±0)`
+		//  Estimated: `5030 + c * (5206 ±0)`
+		// Minimum execution time: 4_000_000 picoseconds.
+		Weight::from_parts(5_000_000, 5030)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(41_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 5206).saturating_mul(c.into()))
+	}
+
+	/// Storage: `IpfsPinning::PinCount` (r:1 w:0)
+	/// Proof: `IpfsPinning::PinCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::ChallengeDeadlines` (r:1 w:1)
+	/// Proof: `IpfsPinning::ChallengeDeadlines` (`max_values`: None, `max_size`: Some(1565), added: 4040, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::PinSlots` (r:16 w:0)
+	/// Proof: `IpfsPinning::PinSlots` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::Pins` (r:16 w:16)
+	/// Proof: `IpfsPinning::Pins` (`max_values`: None, `max_size`: Some(194), added: 2669, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 16]`.
+	fn issue_challenges(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42 + c * (310 ±0)`
+		//  Estimated: `5030 + c * (2669 ±0)`
+		// Minimum execution time: 6_000_000 picoseconds.
+		Weight::from_parts(7_000_000, 5030)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2669).saturating_mul(c.into()))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `IpfsPinning::Bounties` (r:1 w:1)
+	/// Proof: `IpfsPinning::Bounties` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	fn create_bounty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3554`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 3554)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: `IpfsPinning::Bounties` (r:1 w:1)
+	/// Proof: `IpfsPinning::Bounties` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::Pins` (r:16 w:16)
+	/// Proof: `IpfsPinning::Pins` (`max_values`: None, `max_size`: Some(194), added: 2669, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::PinCount` (r:1 w:1)
+	/// Proof: `IpfsPinning::PinCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::PinSlots` (r:16 w:16)
+	/// Proof: `IpfsPinning::PinSlots` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:16 w:16)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[0, 16]`.
+	fn remove_bounty(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `187 + p * (290 ±0)`
+		//  Estimated: `3554 + p * (2669 ±0)`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 3554)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(14_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2669).saturating_mul(p.into()))
+	}
+
+	/// Storage: `IpfsPinning::Pins` (r:1 w:1)
+	/// Proof: `IpfsPinning::Pins` (`max_values`: None, `max_size`: Some(194), added: 2669, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::Bounties` (r:1 w:1)
+	/// Proof: `IpfsPinning::Bounties` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::PinCount` (r:1 w:1)
+	/// Proof: `IpfsPinning::PinCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::PinSlots` (r:0 w:1)
+	/// Proof: `IpfsPinning::PinSlots` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	fn claim_pin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `145`
+		//  Estimated: `3659`
+		// Minimum execution time: 33_000_000 picoseconds.
+		Weight::from_parts(35_000_000, 3659)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: `IpfsPinning::Pins` (r:1 w:1)
+	/// Proof: `IpfsPinning::Pins` (`max_values`: None, `max_size`: Some(194), added: 2669, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn attest_pin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `367`
+		//  Estimated: `6196`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(40_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: `IpfsPinning::Pins` (r:1 w:1)
+	/// Proof: `IpfsPinning::Pins` (`max_values`: None, `max_size`: Some(194), added: 2669, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::PinCount` (r:1 w:1)
+	/// Proof: `IpfsPinning::PinCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::PinSlots` (r:1 w:1)
+	/// Proof: `IpfsPinning::PinSlots` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::Bounties` (r:1 w:1)
+	/// Proof: `IpfsPinning::Bounties` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn release_pin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `453`
+		//  Estimated: `3659`
+		// Minimum execution time: 36_000_000 picoseconds.
+		Weight::from_parts(38_000_000, 3659)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: `IpfsPinning::ChallengeDeadlines` (r:1 w:1)
+	/// Proof: `IpfsPinning::ChallengeDeadlines` (`max_values`: None, `max_size`: Some(1565), added: 4040, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::Pins` (r:16 w:16)
+	/// Proof: `IpfsPinning::Pins` (`max_values`: None, `max_size`: Some(194), added: 2669, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::PinCount` (r:16 w:16)
+	/// Proof: `IpfsPinning::PinCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::PinSlots` (r:16 w:16)
+	/// Proof: `IpfsPinning::PinSlots` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::Bounties` (r:16 w:16)
+	/// Proof: `IpfsPinning::Bounties` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:32 w:32)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 16]`.
+	fn expire_challenges(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42 + c * (420 ±0)`
+		//  Estimated: `5030 + c * (5206 ±0)`
+		// Minimum execution time: 4_000_000 picoseconds.
+		Weight::from_parts(5_000_000, 5030)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(41_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 5206).saturating_mul(c.into()))
+	}
+
+	/// Storage: `IpfsPinning::PinCount` (r:1 w:0)
+	/// Proof: `IpfsPinning::PinCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::ChallengeDeadlines` (r:1 w:1)
+	/// Proof: `IpfsPinning::ChallengeDeadlines` (`max_values`: None, `max_size`: Some(1565), added: 4040, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::PinSlots` (r:16 w:0)
+	/// Proof: `IpfsPinning::PinSlots` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `IpfsPinning::Pins` (r:16 w:16)
+	/// Proof: `IpfsPinning::Pins` (`max_values`: None, `max_size`: Some(194), added: 2669, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 16]`.
+	fn issue_challenges(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42 + c * (310 ±0)`
+		//  Estimated: `5030 + c * (2669 ±0)`
+		// Minimum execution time: 6_000_000 picoseconds.
+		Weight::from_parts(7_000_000, 5030)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2669).saturating_mul(c.into()))
+	}
+}
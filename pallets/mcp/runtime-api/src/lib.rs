@@ -0,0 +1,103 @@
+//! Runtime API for discovering servers registered with the MCP pallet.
+//!
+//! Clients query the registry through these calls instead of iterating raw storage. The API is
+//! generic over the stored types so that it does not depend on a particular runtime; the node
+//! decodes them with the runtime's own `pallet_mcp` types.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+pub use pallet_mcp::{
+    NextIds, PromptId, ProtocolVersion, RenderError, ResourceId, SamplingRequestId,
+    ServerCapabilities, ServerId, ToolId, VerificationLevel,
+};
+
+sp_api::decl_runtime_apis! {
+    /// Queries of the MCP server registry.
+    pub trait McpApi<
+        AccountId,
+        Balance,
+        Server,
+        Tool,
+        Prompt,
+        Resource,
+        Health,
+        Overview,
+        Sampling,
+    >
+    where
+        AccountId: Codec,
+        Balance: Codec,
+        Server: Codec,
+        Tool: Codec,
+        Prompt: Codec,
+        Resource: Codec,
+        Health: Codec,
+        Overview: Codec,
+        Sampling: Codec,
+    {
+        /// Servers with an identifier of at least `start`, in identifier order, at most `limit`.
+        ///
+        /// With `min_reputation` (an average rating in hundredths of a point, e.g. `350`), only
+        /// servers rated at least that well are returned; unrated servers are left out. Likewise,
+        /// with `min_verification` only servers verified at least at that level are returned.
+        fn list_servers(
+            start: ServerId,
+            limit: u32,
+            min_reputation: Option<u32>,
+            min_verification: Option<VerificationLevel>,
+        ) -> Vec<(ServerId, Server)>;
+
+        /// Servers owned by `owner`, in registration order.
+        fn servers_by_owner(owner: AccountId) -> Vec<(ServerId, Server)>;
+
+        /// Tools offered by a server, in registration order.
+        fn tools_by_server(server_id: ServerId) -> Vec<(ToolId, Tool)>;
+
+        /// The fee `caller` would pay for a run of a tool at the current block, or `None` if the
+        /// tool does not exist. For a tool without pricing model this is the smallest fee it
+        /// accepts.
+        fn get_quote(tool_id: ToolId, caller: AccountId) -> Option<Balance>;
+
+        /// Prompt templates offered by a server, in registration order.
+        fn prompts_by_server(server_id: ServerId) -> Vec<(PromptId, Prompt)>;
+
+        /// The text of a prompt template rendered with `params_json`, a JSON object of parameters
+        /// that is checked against the prompt's parameter schema.
+        fn render_prompt(prompt_id: PromptId, params_json: Vec<u8>) -> Result<Vec<u8>, RenderError>;
+
+        /// Resources offered by a server, in registration order.
+        fn resources_by_server(server_id: ServerId) -> Vec<(ResourceId, Resource)>;
+
+        /// Active servers announcing at least the capabilities a client requires, in identifier
+        /// order, optionally only those implementing `min_protocol_version` or a newer revision.
+        fn negotiate_capabilities(
+            client_required: ServerCapabilities,
+            min_protocol_version: Option<ProtocolVersion>,
+        ) -> Vec<ServerId>;
+
+        /// Capabilities announced by a server, or `None` if it is not registered.
+        fn get_server_capabilities(server_id: ServerId) -> Option<ServerCapabilities>;
+
+        /// Verification level of a server, or `None` if it is not verified.
+        fn get_server_verification(server_id: ServerId) -> Option<VerificationLevel>;
+
+        /// Endpoint health of a server as reported by the health reporters, or `None` if it was
+        /// never probed.
+        fn get_server_health(server_id: ServerId) -> Option<Health>;
+
+        /// What an account has in the registry: its servers and their tools, its subscriptions,
+        /// the unsettled execution requests it made or has to answer, and its latest activity.
+        fn get_account_overview(account: AccountId) -> Overview;
+
+        /// Unanswered sampling requests addressed to a server, oldest first, for a bridge to
+        /// relay to a model backend.
+        fn pending_sampling_requests(server_id: ServerId) -> Vec<(SamplingRequestId, Sampling)>;
+
+        /// The identifiers the next registrations and requests of each kind will be assigned.
+        fn get_next_ids() -> NextIds;
+    }
+}
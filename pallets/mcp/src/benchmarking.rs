@@ -0,0 +1,1050 @@
+//! Benchmarking setup for pallet-mcp
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as Mcp;
+use frame_benchmarking::v2::*;
+use frame_support::{
+    traits::{
+        fungibles::{Inspect, Mutate},
+        Currency, EnsureOrigin, Get, SortedMembers,
+    },
+    weights::Weight,
+    BoundedVec,
+};
+use frame_system::RawOrigin;
+use sp_runtime::{
+    traits::{Bounded, Saturating},
+    Percent,
+};
+extern crate alloc;
+use alloc::vec::Vec;
+
+/// The whitelisted caller, funded to reserve deposits for any number of servers.
+fn funded_caller<T: Config>() -> T::AccountId {
+    funded::<T>(whitelisted_caller())
+}
+
+/// Give `who` enough funds for any number of deposits and fees.
+fn funded<T: Config>(who: T::AccountId) -> T::AccountId {
+    T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 2u32.into());
+    who
+}
+
+/// `len` bytes starting with `prefix`, padded with `a`.
+fn padded<S: Get<u32>>(prefix: &[u8], len: u32) -> BoundedVec<u8, S> {
+    let mut bytes = prefix.to_vec();
+    bytes.resize((len as usize).max(prefix.len()), b'a');
+    bytes.try_into().expect("length within bound")
+}
+
+/// A registration with every field at its maximum length.
+fn max_registration<T: Config>() -> ServerRegistration<T> {
+    ServerRegistration {
+        name: padded(b"", T::MaxNameLength::get()),
+        description: padded(b"", T::MaxDescriptionLength::get()),
+        protocol_version: ProtocolVersion::V2025_06_18,
+        capabilities: ServerCapabilities {
+            tools: true,
+            prompts: true,
+            resources: Some(ResourcesCapability {
+                subscribe: true,
+                list_changed: true,
+            }),
+            logging: true,
+            sampling: true,
+        },
+        transport: TransportConfig::Http {
+            url: padded(b"https://", T::MaxUrlLength::get()),
+        },
+        metadata_cid: Some(max_cid::<T>()),
+        private_network: true,
+        access_policy: Some(AccessPolicy {
+            cid: max_cid::<T>(),
+            hash: Default::default(),
+        }),
+    }
+}
+
+/// A well-formed base32 CIDv1 of the maximum length.
+fn max_cid<T: Config>() -> CidOf<T> {
+    padded(b"ba", T::MaxCidLength::get())
+}
+
+/// A tool registration named `tool-{index}` with every other field at its maximum length.
+fn max_tool<T: Config>(index: u32) -> ToolRegistration<T> {
+    let prefix = alloc::format!("tool-{index}-");
+    ToolRegistration {
+        name: padded(prefix.as_bytes(), T::MaxNameLength::get()),
+        description: padded(b"", T::MaxDescriptionLength::get()),
+        input_schema: padded(b"{", T::MaxSchemaLength::get()),
+        output_schema: Some(padded(b"{", T::MaxSchemaLength::get())),
+    }
+}
+
+/// A prompt template named `prompt-{index}` with every other field at its maximum length.
+fn max_prompt<T: Config>(index: u32) -> PromptRegistration<T> {
+    let prefix = alloc::format!("prompt-{index}-");
+    PromptRegistration {
+        name: padded(prefix.as_bytes(), T::MaxNameLength::get()),
+        description: padded(b"", T::MaxDescriptionLength::get()),
+        template: padded(b"", T::MaxTemplateLength::get()),
+        parameter_schema: Some(padded(b"{", T::MaxSchemaLength::get())),
+        metadata_cid: Some(max_cid::<T>()),
+    }
+}
+
+/// A resource at `file:///{index}` with every other field at its maximum length.
+fn max_resource<T: Config>(index: u32) -> ResourceRegistration<T> {
+    let prefix = alloc::format!("file:///{index}/");
+    ResourceRegistration {
+        uri: padded(prefix.as_bytes(), T::MaxUrlLength::get()),
+        name: padded(b"", T::MaxNameLength::get()),
+        description: padded(b"", T::MaxDescriptionLength::get()),
+        mime_type: Some(padded(b"", T::MaxNameLength::get())),
+        content_cid: max_cid::<T>(),
+        metadata_cid: Some(max_cid::<T>()),
+    }
+}
+
+/// Register `count` tools on `server_id`, returning the id of the last one.
+fn add_tools<T: Config>(owner: &T::AccountId, server_id: ServerId, count: u32) -> ToolId {
+    let mut last = NextToolId::<T>::get();
+    for index in 0..count {
+        last = NextToolId::<T>::get();
+        Mcp::<T>::register_tool(
+            RawOrigin::Signed(owner.clone()).into(),
+            server_id,
+            max_tool::<T>(index),
+        )
+        .expect("tool registers");
+    }
+    last
+}
+
+/// Add `count` prompt templates to `server_id`, returning the id of the last one.
+fn add_prompts<T: Config>(owner: &T::AccountId, server_id: ServerId, count: u32) -> PromptId {
+    let mut last = NextPromptId::<T>::get();
+    for index in 0..count {
+        last = NextPromptId::<T>::get();
+        Mcp::<T>::add_prompt(
+            RawOrigin::Signed(owner.clone()).into(),
+            server_id,
+            max_prompt::<T>(index),
+        )
+        .expect("prompt is added");
+    }
+    last
+}
+
+/// Add `count` resources to `server_id`, returning the id of the last one.
+fn add_resources<T: Config>(owner: &T::AccountId, server_id: ServerId, count: u32) -> ResourceId {
+    let mut last = NextResourceId::<T>::get();
+    for index in 0..count {
+        last = NextResourceId::<T>::get();
+        Mcp::<T>::add_resource(
+            RawOrigin::Signed(owner.clone()).into(),
+            server_id,
+            max_resource::<T>(index),
+        )
+        .expect("resource is added");
+    }
+    last
+}
+
+/// Register servers for `owner` until a single slot is left.
+fn fill_servers<T: Config>(owner: &T::AccountId) {
+    for _ in 1..T::MaxServersPerOwner::get() {
+        register_server_of::<T>(owner);
+    }
+}
+
+/// Open `count` execution requests for `tool_id`, the last `own` of them from `requester` and the
+/// others from further accounts, returning the id of the last.
+fn request_executions<T: Config>(
+    requester: &T::AccountId,
+    tool_id: ToolId,
+    count: u32,
+    own: u32,
+) -> RequestId {
+    let others = count.saturating_sub(own);
+    let mut last = NextRequestId::<T>::get();
+    for i in 0..count {
+        let who = if i < others {
+            funded::<T>(account("other requester", i, 0))
+        } else {
+            requester.clone()
+        };
+        last = NextRequestId::<T>::get();
+        Mcp::<T>::request_tool_execution(
+            RawOrigin::Signed(who).into(),
+            tool_id,
+            max_cid::<T>(),
+            T::MinExecutionFee::get(),
+        )
+        .expect("execution is requested");
+    }
+    last
+}
+
+/// Model preferences with the maximum number of hints, each of the maximum length.
+fn max_preferences<T: Config>() -> ModelPreferences<T> {
+    let hints = (0..T::MaxModelHints::get())
+        .map(|_| padded(b"", T::MaxNameLength::get()))
+        .collect::<Vec<_>>();
+    ModelPreferences {
+        hints: BoundedVec::try_from(hints).expect("length within bound"),
+        cost_priority: Some(Percent::one()),
+        speed_priority: Some(Percent::one()),
+        intelligence_priority: Some(Percent::one()),
+    }
+}
+
+/// Open `count` sampling requests to `server_id` from `requester`, returning the id of the first.
+fn request_samplings<T: Config>(
+    requester: &T::AccountId,
+    server_id: ServerId,
+    count: u32,
+) -> SamplingRequestId {
+    let first = NextSamplingRequestId::<T>::get();
+    for _ in 0..count {
+        Mcp::<T>::create_sampling_request(
+            RawOrigin::Signed(requester.clone()).into(),
+            server_id,
+            max_cid::<T>(),
+            max_preferences::<T>(),
+        )
+        .expect("sampling is requested");
+    }
+    first
+}
+
+/// Sell `tool_id` by subscription, so that requests buy one.
+fn subscription_pricing<T: Config>(tool_id: ToolId) -> PricingModel<T> {
+    let pricing = PricingModel::Subscription {
+        period_blocks: T::ExecutionTimeout::get(),
+        price: T::MinExecutionFee::get(),
+    };
+    ToolPricing::<T>::insert(tool_id, &pricing);
+    pricing
+}
+
+/// Subscribe `count` distinct accounts to `resource_id`.
+fn subscribe<T: Config>(resource_id: ResourceId, count: u32) {
+    let subscribers = (0..count)
+        .map(|index| account("subscriber", index, 0))
+        .collect::<Vec<_>>();
+    ResourceSubscribers::<T>::insert(
+        resource_id,
+        BoundedVec::try_from(subscribers).expect("length within bound"),
+    );
+}
+
+/// An allowlist of `count` distinct accounts.
+fn allowlist<T: Config>(count: u32) -> AccessRule<T> {
+    let accounts = (0..count)
+        .map(|index| account("allowed", index, 0))
+        .collect::<Vec<_>>();
+    AccessRule::Allowlist(accounts.try_into().expect("length within bound"))
+}
+
+fn register_server_of<T: Config>(owner: &T::AccountId) -> ServerId {
+    let id = NextServerId::<T>::get();
+    Mcp::<T>::register_server(
+        RawOrigin::Signed(owner.clone()).into(),
+        max_registration::<T>(),
+    )
+    .expect("server registers");
+    id
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn register_server() {
+        let caller = funded_caller::<T>();
+        fill_servers::<T>(&caller);
+        let registration = max_registration::<T>();
+
+        #[extrinsic_call]
+        register_server(RawOrigin::Signed(caller.clone()), registration);
+
+        assert_eq!(
+            ServersByOwner::<T>::get(&caller).len() as u32,
+            T::MaxServersPerOwner::get()
+        );
+    }
+
+    #[benchmark]
+    fn register_server_with_catalog(
+        t: Linear<0, { T::MaxToolsPerServer::get() }>,
+        p: Linear<0, { T::MaxPromptsPerServer::get() }>,
+        r: Linear<0, { T::MaxResourcesPerServer::get() }>,
+    ) {
+        let caller = funded_caller::<T>();
+        fill_servers::<T>(&caller);
+        let id = NextServerId::<T>::get();
+        let tools = (0..t).map(max_tool::<T>).collect::<Vec<_>>();
+        let prompts = (0..p).map(max_prompt::<T>).collect::<Vec<_>>();
+        let resources = (0..r).map(max_resource::<T>).collect::<Vec<_>>();
+
+        #[extrinsic_call]
+        register_server_with_catalog(
+            RawOrigin::Signed(caller),
+            max_registration::<T>(),
+            tools.try_into().expect("length within bound"),
+            prompts.try_into().expect("length within bound"),
+            resources.try_into().expect("length within bound"),
+        );
+
+        assert_eq!(ToolsByServer::<T>::get(id).len() as u32, t);
+        assert_eq!(PromptsByServer::<T>::get(id).len() as u32, p);
+        assert_eq!(ResourcesByServer::<T>::get(id).len() as u32, r);
+    }
+
+    #[benchmark]
+    fn pay_with_credits() {
+        // The credit asset exists in the benchmarked genesis
+        let caller = funded_caller::<T>();
+        let deposit = T::ServerDeposit::get();
+        let credits = Pallet::<T>::credits_for(deposit);
+        T::Assets::mint_into(
+            T::CreditAsset::get(),
+            &caller,
+            credits.saturating_mul(2u32.into()),
+        )
+        .expect("credits are minted");
+
+        #[block]
+        {
+            Pallet::<T>::hold(&caller, deposit, PaymentAsset::Credits).expect("credits are held");
+        }
+
+        assert_eq!(
+            T::Assets::balance(T::CreditAsset::get(), &Pallet::<T>::credit_account()),
+            credits
+        );
+    }
+
+    #[benchmark]
+    fn update_server() {
+        let caller = funded_caller::<T>();
+        let id = register_server_of::<T>(&caller);
+        // Replacing the registration of a verified server revokes the verification
+        ServerVerification::<T>::insert(id, VerificationLevel::Audited);
+        let registration = max_registration::<T>();
+
+        #[extrinsic_call]
+        update_server(RawOrigin::Signed(caller), id, registration);
+
+        assert!(!ServerVerification::<T>::contains_key(id));
+    }
+
+    #[benchmark]
+    fn upgrade_server_protocol() {
+        let caller = funded_caller::<T>();
+        let id = register_server_of::<T>(&caller);
+        Servers::<T>::mutate(id, |server| {
+            server.as_mut().unwrap().protocol_version = ProtocolVersion::V2024_11_05
+        });
+
+        #[extrinsic_call]
+        upgrade_server_protocol(RawOrigin::Signed(caller), id, ProtocolVersion::V2025_06_18);
+
+        assert_eq!(
+            Servers::<T>::get(id).unwrap().protocol_version,
+            ProtocolVersion::V2025_06_18
+        );
+    }
+
+    #[benchmark]
+    fn deactivate_server() {
+        let caller = funded_caller::<T>();
+        let id = register_server_of::<T>(&caller);
+
+        #[extrinsic_call]
+        deactivate_server(RawOrigin::Signed(caller), id);
+
+        assert!(!Servers::<T>::get(id).unwrap().active);
+    }
+
+    #[benchmark]
+    fn activate_server() {
+        let caller = funded_caller::<T>();
+        let id = register_server_of::<T>(&caller);
+        Mcp::<T>::deactivate_server(RawOrigin::Signed(caller.clone()).into(), id)
+            .expect("server deactivates");
+
+        #[extrinsic_call]
+        activate_server(RawOrigin::Signed(caller), id);
+
+        assert!(Servers::<T>::get(id).unwrap().active);
+    }
+
+    #[benchmark]
+    fn deregister_server(
+        t: Linear<0, { T::MaxToolsPerServer::get() }>,
+        p: Linear<0, { T::MaxPromptsPerServer::get() }>,
+        r: Linear<0, { T::MaxResourcesPerServer::get() }>,
+    ) {
+        let caller = funded_caller::<T>();
+        // The removed server is the last of a full owner list
+        fill_servers::<T>(&caller);
+        let id = register_server_of::<T>(&caller);
+        add_tools::<T>(&caller, id, t);
+        add_prompts::<T>(&caller, id, p);
+        add_resources::<T>(&caller, id, r);
+
+        #[extrinsic_call]
+        deregister_server(RawOrigin::Signed(caller), id);
+
+        assert!(!Servers::<T>::contains_key(id));
+        assert!(ToolsByServer::<T>::get(id).is_empty());
+        assert!(PromptsByServer::<T>::get(id).is_empty());
+        assert!(ResourcesByServer::<T>::get(id).is_empty());
+    }
+
+    #[benchmark]
+    fn renew_server() {
+        let caller = funded_caller::<T>();
+        let id = register_server_of::<T>(&caller);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), id);
+
+        let now = frame_system::Pallet::<T>::block_number();
+        assert_eq!(
+            Servers::<T>::get(id).unwrap().expires_at,
+            now + T::RegistrationPeriod::get()
+        );
+    }
+
+    #[benchmark]
+    fn check_expiry() {
+        let caller = funded_caller::<T>();
+        let id = register_server_of::<T>(&caller);
+        let now = frame_system::Pallet::<T>::block_number();
+
+        #[block]
+        {
+            Pallet::<T>::expire_servers(now, Weight::MAX);
+        }
+
+        assert!(Servers::<T>::contains_key(id));
+    }
+
+    #[benchmark]
+    fn expire_server(
+        t: Linear<0, { T::MaxToolsPerServer::get() }>,
+        p: Linear<0, { T::MaxPromptsPerServer::get() }>,
+        r: Linear<0, { T::MaxResourcesPerServer::get() }>,
+    ) {
+        let caller = funded_caller::<T>();
+        let id = register_server_of::<T>(&caller);
+        add_tools::<T>(&caller, id, t);
+        add_prompts::<T>(&caller, id, p);
+        add_resources::<T>(&caller, id, r);
+        // The rest of a full owner list is registered a block later and outlives the server
+        let now = Servers::<T>::get(id).unwrap().expires_at + 1u32.into();
+        frame_system::Pallet::<T>::set_block_number(
+            frame_system::Pallet::<T>::block_number() + 1u32.into(),
+        );
+        fill_servers::<T>(&caller);
+        ExpiryCursor::<T>::put(id);
+
+        #[block]
+        {
+            Pallet::<T>::expire_servers(now, Weight::MAX);
+        }
+
+        assert!(!Servers::<T>::contains_key(id));
+        assert!(ToolsByServer::<T>::get(id).is_empty());
+    }
+
+    #[benchmark]
+    fn slash_server(
+        t: Linear<0, { T::MaxToolsPerServer::get() }>,
+        p: Linear<0, { T::MaxPromptsPerServer::get() }>,
+        r: Linear<0, { T::MaxResourcesPerServer::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let origin =
+            T::SlashOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let owner = funded_caller::<T>();
+        fill_servers::<T>(&owner);
+        let id = register_server_of::<T>(&owner);
+        add_tools::<T>(&owner, id, t);
+        add_prompts::<T>(&owner, id, p);
+        add_resources::<T>(&owner, id, r);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, id);
+
+        assert!(!Servers::<T>::contains_key(id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn verify_server() -> Result<(), BenchmarkError> {
+        let origin =
+            T::VerifyOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let id = register_server_of::<T>(&funded_caller::<T>());
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, id, VerificationLevel::Audited);
+
+        assert_eq!(
+            ServerVerification::<T>::get(id),
+            Some(VerificationLevel::Audited)
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn revoke_verification() -> Result<(), BenchmarkError> {
+        let origin =
+            T::VerifyOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let id = register_server_of::<T>(&funded_caller::<T>());
+        ServerVerification::<T>::insert(id, VerificationLevel::Audited);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, id);
+
+        assert!(!ServerVerification::<T>::contains_key(id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn register_tool() {
+        let caller = funded_caller::<T>();
+        let server_id = register_server_of::<T>(&caller);
+        let count = T::MaxToolsPerServer::get();
+        add_tools::<T>(&caller, server_id, count - 1);
+        let tool = max_tool::<T>(count);
+
+        #[extrinsic_call]
+        register_tool(RawOrigin::Signed(caller), server_id, tool);
+
+        assert_eq!(ToolsByServer::<T>::get(server_id).len() as u32, count);
+    }
+
+    #[benchmark]
+    fn update_tool() {
+        let caller = funded_caller::<T>();
+        let server_id = register_server_of::<T>(&caller);
+        let tool_id = add_tools::<T>(&caller, server_id, 1);
+        // Renaming the tool is the expensive path
+        let tool = max_tool::<T>(1);
+
+        #[extrinsic_call]
+        update_tool(RawOrigin::Signed(caller), tool_id, tool);
+
+        assert!(ToolIdByName::<T>::contains_key(
+            server_id,
+            max_tool::<T>(1).name
+        ));
+    }
+
+    #[benchmark]
+    fn batch_update_tools(n: Linear<0, { T::MaxToolsPerServer::get() }>) {
+        let caller = funded_caller::<T>();
+        let server_id = register_server_of::<T>(&caller);
+        let first = NextToolId::<T>::get();
+        add_tools::<T>(&caller, server_id, n);
+        // Every tool is renamed, the expensive path of an update
+        let updates = (0..n)
+            .map(|index| (first + ToolId::from(index), max_tool::<T>(n + index)))
+            .collect::<Vec<_>>();
+
+        #[extrinsic_call]
+        batch_update_tools(
+            RawOrigin::Signed(caller),
+            updates.try_into().expect("length within bound"),
+        );
+
+        assert!((0..n).all(|index| {
+            ToolIdByName::<T>::get(server_id, max_tool::<T>(n + index).name)
+                == Some(first + ToolId::from(index))
+        }));
+    }
+
+    #[benchmark]
+    fn set_tool_active() {
+        let caller = funded_caller::<T>();
+        let server_id = register_server_of::<T>(&caller);
+        let tool_id = add_tools::<T>(&caller, server_id, 1);
+
+        #[extrinsic_call]
+        set_tool_active(RawOrigin::Signed(caller), tool_id, false);
+
+        assert!(!Tools::<T>::get(tool_id).unwrap().active);
+    }
+
+    #[benchmark]
+    fn remove_tool() {
+        let caller = funded_caller::<T>();
+        let server_id = register_server_of::<T>(&caller);
+        let tool_id = add_tools::<T>(&caller, server_id, T::MaxToolsPerServer::get());
+
+        #[extrinsic_call]
+        remove_tool(RawOrigin::Signed(caller), tool_id);
+
+        assert!(!Tools::<T>::contains_key(tool_id));
+    }
+
+    #[benchmark]
+    fn set_tool_pricing() {
+        let caller = funded_caller::<T>();
+        let server_id = register_server_of::<T>(&caller);
+        let tool_id = add_tools::<T>(&caller, server_id, 1);
+        let pricing = subscription_pricing::<T>(tool_id);
+        ToolPricing::<T>::remove(tool_id);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), tool_id, pricing.clone());
+
+        assert_eq!(ToolPricing::<T>::get(tool_id), Some(pricing));
+    }
+
+    #[benchmark]
+    fn register_sealed_tool() {
+        let caller = funded_caller::<T>();
+        let server_id = register_server_of::<T>(&caller);
+        let count = T::MaxToolsPerServer::get();
+        add_tools::<T>(&caller, server_id, count - 1);
+        let tool = max_tool::<T>(count);
+        let commitment = Mcp::<T>::tool_commitment(&tool, &[0; 32]);
+        let reveal_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+
+        #[extrinsic_call]
+        register_sealed_tool(
+            RawOrigin::Signed(caller),
+            server_id,
+            tool.name,
+            commitment,
+            reveal_at,
+        );
+
+        assert_eq!(ToolsByServer::<T>::get(server_id).len() as u32, count);
+    }
+
+    #[benchmark]
+    fn reveal_tool() {
+        let caller = funded_caller::<T>();
+        let server_id = register_server_of::<T>(&caller);
+        let tool = max_tool::<T>(0);
+        let commitment = Mcp::<T>::tool_commitment(&tool, &[0; 32]);
+        let reveal_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+        let tool_id = NextToolId::<T>::get();
+        Mcp::<T>::register_sealed_tool(
+            RawOrigin::Signed(caller.clone()).into(),
+            server_id,
+            tool.name.clone(),
+            commitment,
+            reveal_at,
+        )
+        .expect("tool seals");
+        frame_system::Pallet::<T>::set_block_number(reveal_at);
+
+        #[extrinsic_call]
+        reveal_tool(RawOrigin::Signed(caller), tool_id, tool, [0; 32]);
+
+        assert!(Tools::<T>::contains_key(tool_id));
+    }
+
+    #[benchmark]
+    fn add_prompt() {
+        let caller = funded_caller::<T>();
+        let server_id = register_server_of::<T>(&caller);
+        let count = T::MaxPromptsPerServer::get();
+        add_prompts::<T>(&caller, server_id, count - 1);
+        let prompt = max_prompt::<T>(count);
+
+        #[extrinsic_call]
+        add_prompt(RawOrigin::Signed(caller), server_id, prompt);
+
+        assert_eq!(PromptsByServer::<T>::get(server_id).len() as u32, count);
+    }
+
+    #[benchmark]
+    fn update_prompt() {
+        let caller = funded_caller::<T>();
+        let server_id = register_server_of::<T>(&caller);
+        let prompt_id = add_prompts::<T>(&caller, server_id, 1);
+        // Renaming the prompt is the expensive path
+        let prompt = max_prompt::<T>(1);
+
+        #[extrinsic_call]
+        update_prompt(RawOrigin::Signed(caller), prompt_id, prompt);
+
+        assert!(PromptIdByName::<T>::contains_key(
+            server_id,
+            max_prompt::<T>(1).name
+        ));
+    }
+
+    #[benchmark]
+    fn remove_prompt() {
+        let caller = funded_caller::<T>();
+        let server_id = register_server_of::<T>(&caller);
+        let prompt_id = add_prompts::<T>(&caller, server_id, T::MaxPromptsPerServer::get());
+
+        #[extrinsic_call]
+        remove_prompt(RawOrigin::Signed(caller), prompt_id);
+
+        assert!(!Prompts::<T>::contains_key(prompt_id));
+    }
+
+    #[benchmark]
+    fn add_resource() {
+        let caller = funded_caller::<T>();
+        let server_id = register_server_of::<T>(&caller);
+        let count = T::MaxResourcesPerServer::get();
+        add_resources::<T>(&caller, server_id, count - 1);
+        let resource = max_resource::<T>(count);
+
+        #[extrinsic_call]
+        add_resource(RawOrigin::Signed(caller), server_id, resource);
+
+        assert_eq!(ResourcesByServer::<T>::get(server_id).len() as u32, count);
+    }
+
+    #[benchmark]
+    fn update_resource(s: Linear<0, { T::MaxSubscribersPerResource::get() }>) {
+        let caller = funded_caller::<T>();
+        let server_id = register_server_of::<T>(&caller);
+        let resource_id = add_resources::<T>(&caller, server_id, 1);
+        subscribe::<T>(resource_id, s);
+        // Moving the resource to a new URI is the expensive path
+        let resource = max_resource::<T>(1);
+
+        #[extrinsic_call]
+        update_resource(RawOrigin::Signed(caller), resource_id, resource);
+
+        assert!(ResourceIdByUri::<T>::contains_key(
+            server_id,
+            max_resource::<T>(1).uri
+        ));
+    }
+
+    #[benchmark]
+    fn remove_resource() {
+        let caller = funded_caller::<T>();
+        let server_id = register_server_of::<T>(&caller);
+        let resource_id = add_resources::<T>(&caller, server_id, T::MaxResourcesPerServer::get());
+
+        #[extrinsic_call]
+        remove_resource(RawOrigin::Signed(caller), resource_id);
+
+        assert!(!Resources::<T>::contains_key(resource_id));
+    }
+
+    #[benchmark]
+    fn subscribe_resource() -> Result<(), BenchmarkError> {
+        let owner = funded_caller::<T>();
+        let server_id = register_server_of::<T>(&owner);
+        let resource_id = add_resources::<T>(&owner, server_id, 1);
+        // The caller is last on a full allowlist
+        let target = AccessTarget::Resource(resource_id);
+        let count = T::MaxAllowlistLength::get();
+        Mcp::<T>::set_access_policy(
+            RawOrigin::Signed(owner).into(),
+            target,
+            allowlist::<T>(count),
+        )?;
+        let caller: T::AccountId = account("allowed", count - 1, 0);
+        subscribe::<T>(resource_id, T::MaxSubscribersPerResource::get() - 1);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), resource_id);
+
+        assert!(ResourceSubscribers::<T>::get(resource_id).contains(&caller));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn unsubscribe_resource() {
+        let owner = funded_caller::<T>();
+        let server_id = register_server_of::<T>(&owner);
+        let resource_id = add_resources::<T>(&owner, server_id, 1);
+        let count = T::MaxSubscribersPerResource::get();
+        subscribe::<T>(resource_id, count);
+        let caller: T::AccountId = account("subscriber", count - 1, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), resource_id);
+
+        assert!(!ResourceSubscribers::<T>::get(resource_id).contains(&caller));
+    }
+
+    #[benchmark]
+    fn request_tool_execution() {
+        let owner = funded_caller::<T>();
+        let server_id = register_server_of::<T>(&owner);
+        let tool_id = add_tools::<T>(&owner, server_id, 1);
+        // A request that buys a subscription checks the requester's usage and records the purchase
+        subscription_pricing::<T>(tool_id);
+        // The exposure check visits every server of the owner
+        fill_servers::<T>(&owner);
+        let requester = funded::<T>(account("requester", 0, 0));
+        // The requester's index of requests has a single slot left too
+        request_executions::<T>(
+            &requester,
+            tool_id,
+            T::MaxPendingExecutions::get() - 1,
+            T::MaxRequestsPerAccount::get() - 1,
+        );
+        let fee = T::MinExecutionFee::get();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(requester), tool_id, max_cid::<T>(), fee);
+
+        assert_eq!(
+            PendingExecutions::<T>::get(server_id).len() as u32,
+            T::MaxPendingExecutions::get()
+        );
+    }
+
+    #[benchmark]
+    fn submit_execution_result() {
+        let owner = funded_caller::<T>();
+        let server_id = register_server_of::<T>(&owner);
+        let tool_id = add_tools::<T>(&owner, server_id, 1);
+        subscription_pricing::<T>(tool_id);
+        let requester = funded::<T>(account("requester", 0, 0));
+        let request_id = request_executions::<T>(
+            &requester,
+            tool_id,
+            T::MaxPendingExecutions::get(),
+            T::MaxRequestsPerAccount::get(),
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner), request_id, max_cid::<T>());
+
+        assert!(!ExecutionRequests::<T>::contains_key(request_id));
+        assert!(Usage::<T>::get(tool_id, &requester)
+            .subscribed_until
+            .is_some());
+    }
+
+    #[benchmark]
+    fn cancel_execution() {
+        let owner = funded_caller::<T>();
+        let server_id = register_server_of::<T>(&owner);
+        let tool_id = add_tools::<T>(&owner, server_id, 1);
+        subscription_pricing::<T>(tool_id);
+        let requester = funded::<T>(account("requester", 0, 0));
+        let request_id = request_executions::<T>(
+            &requester,
+            tool_id,
+            T::MaxPendingExecutions::get(),
+            T::MaxRequestsPerAccount::get(),
+        );
+        let deadline = ExecutionRequests::<T>::get(request_id).unwrap().deadline;
+        frame_system::Pallet::<T>::set_block_number(deadline + 1u32.into());
+
+        // Cleared by someone other than the refunded requester
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner), request_id);
+
+        assert!(!ExecutionRequests::<T>::contains_key(request_id));
+    }
+
+    #[benchmark]
+    fn create_sampling_request() {
+        let owner = funded_caller::<T>();
+        let server_id = register_server_of::<T>(&owner);
+        let requester: T::AccountId = account("requester", 0, 0);
+        request_samplings::<T>(
+            &requester,
+            server_id,
+            T::MaxPendingSamplingRequests::get() - 1,
+        );
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(requester),
+            server_id,
+            max_cid::<T>(),
+            max_preferences::<T>(),
+        );
+
+        assert_eq!(
+            PendingSamplingRequests::<T>::get(server_id).len() as u32,
+            T::MaxPendingSamplingRequests::get()
+        );
+    }
+
+    #[benchmark]
+    fn submit_sampling_result() {
+        let owner = funded_caller::<T>();
+        let server_id = register_server_of::<T>(&owner);
+        let requester: T::AccountId = account("requester", 0, 0);
+        let request_id =
+            request_samplings::<T>(&requester, server_id, T::MaxPendingSamplingRequests::get());
+        let result = SamplingResult {
+            request_id,
+            requester,
+            completion_cid: max_cid::<T>(),
+            usage: UsageStats::default(),
+            completed_at: frame_system::Pallet::<T>::block_number(),
+        };
+        let history = (0..T::MaxSamplingHistory::get())
+            .map(|_| result.clone())
+            .collect::<Vec<_>>();
+        SamplingHistory::<T>::insert(
+            server_id,
+            BoundedVec::try_from(history).expect("length within bound"),
+        );
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(owner),
+            request_id,
+            max_cid::<T>(),
+            UsageStats::default(),
+        );
+
+        assert!(!SamplingRequests::<T>::contains_key(request_id));
+    }
+
+    #[benchmark]
+    fn cancel_sampling_request() {
+        let owner = funded_caller::<T>();
+        let server_id = register_server_of::<T>(&owner);
+        let requester: T::AccountId = account("requester", 0, 0);
+        let request_id =
+            request_samplings::<T>(&requester, server_id, T::MaxPendingSamplingRequests::get());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(requester), request_id);
+
+        assert!(!SamplingRequests::<T>::contains_key(request_id));
+    }
+
+    #[benchmark]
+    fn set_access_policy() {
+        let caller = funded_caller::<T>();
+        let server_id = register_server_of::<T>(&caller);
+        let target = AccessTarget::Tool(add_tools::<T>(&caller, server_id, 1));
+        let rule = allowlist::<T>(T::MaxAllowlistLength::get());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), target, rule);
+
+        assert!(AccessRules::<T>::contains_key(target));
+    }
+
+    #[benchmark]
+    fn grant_access() -> Result<(), BenchmarkError> {
+        let caller = funded_caller::<T>();
+        let server_id = register_server_of::<T>(&caller);
+        let target = AccessTarget::Tool(add_tools::<T>(&caller, server_id, 1));
+        let count = T::MaxAllowlistLength::get();
+        Mcp::<T>::set_access_policy(
+            RawOrigin::Signed(caller.clone()).into(),
+            target,
+            allowlist::<T>(count - 1),
+        )?;
+        let account: T::AccountId = account("allowed", count, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), target, account.clone());
+
+        assert!(Mcp::<T>::can_access(&account, target));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn revoke_access() -> Result<(), BenchmarkError> {
+        let caller = funded_caller::<T>();
+        let server_id = register_server_of::<T>(&caller);
+        let target = AccessTarget::Tool(add_tools::<T>(&caller, server_id, 1));
+        let count = T::MaxAllowlistLength::get();
+        Mcp::<T>::set_access_policy(
+            RawOrigin::Signed(caller.clone()).into(),
+            target,
+            allowlist::<T>(count),
+        )?;
+        // The last account is the most expensive to find
+        let account: T::AccountId = account("allowed", count - 1, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), target, account.clone());
+
+        assert!(!Mcp::<T>::can_access(&account, target));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn report_server_health(
+        n: Linear<0, { T::MaxHealthReports::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let reporter: T::AccountId = account("reporter", 0, 0);
+        T::HealthReporters::add(&reporter);
+        // Every report is the failure that deactivates its server
+        let failures = T::MaxConsecutiveFailures::get().saturating_sub(1);
+        let reports: Vec<HealthReport> = (0..n)
+            .map(|i| {
+                let server_id = register_server_of::<T>(&funded::<T>(account("owner", i, 0)));
+                ServerHealth::<T>::insert(
+                    server_id,
+                    HealthStatus {
+                        consecutive_failures: failures,
+                        ..Default::default()
+                    },
+                );
+                HealthReport {
+                    server_id,
+                    healthy: false,
+                    latency_ms: 0,
+                }
+            })
+            .collect();
+        let reports: BoundedVec<_, T::MaxHealthReports> =
+            reports.try_into().expect("at most MaxHealthReports");
+        let server_ids: Vec<ServerId> = reports.iter().map(|r| r.server_id).collect();
+        // The last failures were counted a full interval ago
+        frame_system::Pallet::<T>::set_block_number(T::ProbeInterval::get());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(reporter), reports);
+
+        for id in server_ids {
+            assert!(!Servers::<T>::get(id).expect("server exists").active);
+        }
+        Ok(())
+    }
+
+    #[benchmark]
+    fn rate_tool() {
+        let owner = funded_caller::<T>();
+        let server_id = register_server_of::<T>(&owner);
+        let tool_id = add_tools::<T>(&owner, server_id, 1);
+        let rater: T::AccountId = account("rater", 0, 0);
+        RatingCredits::<T>::insert(tool_id, &rater, 1);
+        // Both scores have ratings to decay
+        let rated = ReputationScore {
+            weighted_sum: RATING_WEIGHT,
+            total_weight: RATING_WEIGHT,
+            ratings: 1,
+            decayed_at: Default::default(),
+        };
+        ToolReputation::<T>::insert(tool_id, rated);
+        ServerReputation::<T>::insert(server_id, rated);
+        frame_system::Pallet::<T>::set_block_number(T::ReputationHalfLife::get());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(rater), tool_id, 5, Some(max_cid::<T>()));
+
+        assert_eq!(
+            ToolReputation::<T>::get(tool_id).map(|r| r.ratings),
+            Some(2)
+        );
+    }
+
+    impl_benchmark_test_suite!(Mcp, crate::mock::new_test_ext(), crate::mock::Test);
+}
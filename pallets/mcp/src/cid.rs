@@ -0,0 +1,48 @@
+//! Syntactic checks of IPFS CIDs.
+//!
+//! The runtime cannot fetch content, so these checks only reject values that cannot be a CID at
+//! all: a wrong multibase prefix, characters outside the base's alphabet or an impossible length.
+
+/// Shortest possible CID string, the 8 characters of the empty identity CID `bafkqaaa`.
+pub const MIN_CID_LENGTH: usize = 8;
+
+/// Length of a CIDv0, a base58btc SHA2-256 multihash.
+pub const CID_V0_LENGTH: usize = 46;
+
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Whether `cid` is a plausible CIDv0 or multibase-encoded CIDv1.
+///
+/// Supported CIDv1 bases are base32 (`b`/`B`), base58btc (`z`), base16 (`f`/`F`), base36
+/// (`k`/`K`) and base64/base64url (`m`/`u`). For base32 and base16 the version byte is checked too.
+pub fn is_valid(cid: &[u8]) -> bool {
+    if cid.len() == CID_V0_LENGTH && cid.starts_with(b"Qm") {
+        return cid.iter().all(|c| BASE58_ALPHABET.contains(c));
+    }
+    if cid.len() < MIN_CID_LENGTH {
+        return false;
+    }
+
+    let (prefix, body) = (cid[0], &cid[1..]);
+    match prefix {
+        // The version byte 0x01 encodes as `a` in base32 and `01` in base16
+        b'b' => body.starts_with(b"a") && all(body, |c| matches!(c, b'a'..=b'z' | b'2'..=b'7')),
+        b'B' => body.starts_with(b"A") && all(body, |c| matches!(c, b'A'..=b'Z' | b'2'..=b'7')),
+        b'f' => body.starts_with(b"01") && all(body, |c| matches!(c, b'0'..=b'9' | b'a'..=b'f')),
+        b'F' => body.starts_with(b"01") && all(body, |c| matches!(c, b'0'..=b'9' | b'A'..=b'F')),
+        b'z' => all(body, |c| BASE58_ALPHABET.contains(&c)),
+        b'k' => all(body, |c| matches!(c, b'0'..=b'9' | b'a'..=b'z')),
+        b'K' => all(body, |c| matches!(c, b'0'..=b'9' | b'A'..=b'Z')),
+        b'm' => all(body, |c| {
+            c.is_ascii_alphanumeric() || c == b'+' || c == b'/'
+        }),
+        b'u' => all(body, |c| {
+            c.is_ascii_alphanumeric() || c == b'-' || c == b'_'
+        }),
+        _ => false,
+    }
+}
+
+fn all(body: &[u8], f: impl Fn(u8) -> bool) -> bool {
+    body.iter().all(|c| f(*c))
+}
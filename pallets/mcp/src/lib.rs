@@ -0,0 +1,4045 @@
+//! # MCP Pallet
+//!
+//! A Substrate pallet for registering Model Context Protocol (MCP) servers and what they offer.
+//!
+//! ## Overview
+//!
+//! AI clients discover MCP servers through this pallet instead of a central directory:
+//! - Operators register a server with its name, protocol revision, announced capabilities and
+//!   transport endpoint, reserving `ServerDeposit` until the server is deregistered
+//! - The owner keeps the registration current, and can take a server out of service without
+//!   losing its identifier
+//! - Registrations are leased for `RegistrationPeriod` blocks; servers whose owners stop renewing
+//!   them are removed when blocks have weight to spare
+//! - The owner publishes the server's tools, prompt templates and resources, so clients can
+//!   choose a server before connecting
+//! - Resource content and extended metadata (package, launch command, documentation) live on
+//!   IPFS and are referenced by CID; every CID is checked to be well-formed
+//!
+//! Requesters rate a tool after its server completed one of their paid runs, so every rating is
+//! backed by a fee actually paid. Ratings add up to a reputation per tool and per server in which
+//! older ratings count less.
+//!
+//! Governance (`VerifyOrigin`) can vouch for a server at a verification level, from a known
+//! operator to an audited deployment, so clients can tell reviewed services apart from anonymous
+//! registrations.
+//!
+//! Owners can restrict a tool or resource to an allowlist or to accounts holding a minimum
+//! balance; paid runs of a restricted tool are only accepted from accounts that pass the rule.
+//!
+//! Clients can also pay for a tool run on chain: the fee is escrowed until the server owner posts
+//! the output, and refunded if no output arrives within `ExecutionTimeout` blocks. Owners price
+//! their tools per call or by subscription, or leave the fee to the requester's offer, and every
+//! account's completed runs and fees are counted per tool. The fees escrowed for one owner's
+//! servers are capped at `ExposureMultiplier` times their deposits, which bounds what requesters
+//! have at stake if a large provider disappears. Deposits and fees can also be paid in a
+//! compute-credit asset instead of the native currency.
+//!
+//! Servers announcing the sampling capability can be asked to sample a language model; their
+//! owners post the completions, which are kept in a bounded history per server.
+//!
+//! A set of health reporters runs the pallet's offchain worker, which probes every public network
+//! endpoint and reports the outcome on chain. A server whose endpoint fails
+//! `MaxConsecutiveFailures` probes in a row is deactivated until its owner activates it again.
+//!
+//! Every change is emitted as an event so that indexers can mirror the catalog. Other pallets can
+//! also react to registrations and settled runs through the hooks in [`traits`].
+//!
+//! ## Functionality
+//!
+//! - `register_server`: Register a server owned by the caller
+//! - `register_server_with_catalog`: Register a server with its tools, prompts and resources at
+//!   once
+//! - `update_server`: Replace a server's registration
+//! - `upgrade_server_protocol`: Move a server to a newer protocol revision
+//! - `deactivate_server` / `activate_server`: Take a server out of or back into service
+//! - `deregister_server`: Remove a server with its tools, prompts and resources
+//! - `renew_server`: Extend a server's lease for a fee
+//! - `slash_server`: Remove a misbehaving server and burn its deposit (`SlashOrigin` only)
+//! - `verify_server` / `revoke_verification`: Vouch for a server at a verification level
+//!   (`VerifyOrigin` only)
+//! - `register_tool` / `update_tool` / `remove_tool`: Manage the tools of an owned server
+//! - `batch_update_tools`: Replace several tool registrations at once
+//! - `set_tool_active`: Enable or disable a tool
+//! - `set_tool_pricing`: Set what a tool charges for a run
+//! - `register_sealed_tool` / `reveal_tool`: Reserve a tool ahead of its launch and publish it
+//!   once its reveal block is reached
+//! - `add_prompt` / `update_prompt` / `remove_prompt`: Manage the prompt templates of a server
+//! - `add_resource` / `update_resource` / `remove_resource`: Manage the resources of a server
+//! - `subscribe_resource` / `unsubscribe_resource`: Follow changes of a resource
+//! - `request_tool_execution`: Escrow a fee and ask a server to run one of its tools
+//! - `submit_execution_result`: Post the output of a requested run and claim its fee
+//! - `cancel_execution`: Reclaim the fee of a request that was not answered in time
+//! - `pay_with_credits`: Register a server or request a run, paying in compute credits
+//! - `create_sampling_request`: Ask a server to sample a language model
+//! - `submit_sampling_result`: Post the completion of a sampling request
+//! - `cancel_sampling_request`: Withdraw a sampling request, or drop one past its deadline
+//! - `rate_tool`: Rate a tool after a completed paid run of it
+//! - `set_access_policy` / `grant_access` / `revoke_access`: Restrict who may use a tool or
+//!   resource
+//! - `report_server_health`: Submit a batch of probe results (normally sent by the offchain
+//!   worker)
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod cid;
+pub mod migrations;
+pub mod template;
+pub mod traits;
+pub mod types;
+pub mod url;
+pub use template::RenderError;
+pub use types::*;
+
+pub mod weights;
+pub use weights::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use crate::traits::{OnCallSettled, OnCidPublished, OnServerRegistered, SettledCall};
+    use alloc::{boxed::Box, format, string::String, vec, vec::Vec};
+    use frame_support::{
+        dispatch::{GetDispatchInfo, PostDispatchInfo, WithPostDispatchInfo},
+        pallet_prelude::*,
+        traits::{
+            fungibles::{self, Mutate},
+            tokens::{Fortitude, Precision, Preservation},
+            BalanceStatus, Currency, ExistenceRequirement, Imbalance, IsSubType,
+            ReservableCurrency, SortedMembers, WithdrawReasons,
+        },
+        PalletId,
+    };
+    use frame_system::{
+        offchain::{AppCrypto, CreateSignedTransaction, SendSignedTransaction, Signer},
+        pallet_prelude::*,
+    };
+    use sp_core::H256;
+    use sp_runtime::{
+        offchain::{
+            http,
+            storage::{MutateStorageError, StorageRetrievalError, StorageValueRef},
+            Duration,
+        },
+        traits::{AccountIdConversion, IdentifyAccount, Saturating},
+        RuntimeAppPublic,
+    };
+
+    /// Offchain storage key of the next server the offchain worker probes.
+    const PROBE_CURSOR_KEY: &[u8] = b"mcp::probe-cursor";
+
+    /// The in-code storage version. Bumped together with a migration in [`crate::migrations`]
+    /// whenever the layout of stored values changes.
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
+    #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    /// The pallet's configuration trait.
+    #[pallet::config]
+    pub trait Config: CreateSignedTransaction<Call<Self>> + frame_system::Config {
+        /// A type representing the weights required by the dispatchables of this pallet.
+        type WeightInfo: WeightInfo;
+        /// The runtime call type, from which `pay_with_credits` takes this pallet's calls
+        type RuntimeCall: Parameter
+            + GetDispatchInfo
+            + IsSubType<Call<Self>>
+            + IsType<<Self as frame_system::Config>::RuntimeCall>;
+        /// Crypto used by the offchain worker to sign health reports
+        type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+        /// Accounts allowed to report server health
+        type HealthReporters: SortedMembers<Self::AccountId>;
+        /// Currency in which server deposits are reserved
+        type Currency: ReservableCurrency<Self::AccountId>;
+        /// Fungible assets the compute-credit asset is issued in
+        type Assets: fungibles::Mutate<Self::AccountId, Balance = BalanceOf<Self>>;
+        /// Origin allowed to slash misbehaving servers
+        type SlashOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Origin allowed to verify servers and revoke their verification
+        type VerifyOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Hook called after a server is registered
+        type OnServerRegistered: OnServerRegistered<Self::AccountId>;
+        /// Hook called after a paid tool run is completed or refunded
+        type OnCallSettled: OnCallSettled<Self::AccountId, BalanceOf<Self>>;
+        /// Hook called with every content and metadata CID a registration references
+        type OnCidPublished: OnCidPublished;
+        /// Amount reserved from the owner for each registered server
+        #[pallet::constant]
+        type ServerDeposit: Get<BalanceOf<Self>>;
+        /// Blocks a registration or renewal leases a server for
+        #[pallet::constant]
+        type RegistrationPeriod: Get<BlockNumberFor<Self>>;
+        /// Amount burned from the owner for each renewal of a server's lease
+        #[pallet::constant]
+        type RenewalFee: Get<BalanceOf<Self>>;
+        /// The compute-credit asset deposits and execution fees may be paid in
+        #[pallet::constant]
+        type CreditAsset: Get<AssetIdOf<Self>>;
+        /// Native balance one unit of the compute-credit asset is worth
+        #[pallet::constant]
+        type NativePerCredit: Get<BalanceOf<Self>>;
+        /// Identifier of the account holding the credits paid for deposits and fees
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+        /// Maximum length of a name (in bytes)
+        #[pallet::constant]
+        type MaxNameLength: Get<u32>;
+        /// Maximum length of a description (in bytes)
+        #[pallet::constant]
+        type MaxDescriptionLength: Get<u32>;
+        /// Maximum length of a transport URL (in bytes)
+        #[pallet::constant]
+        type MaxUrlLength: Get<u32>;
+        /// Maximum length of an IPFS CID (in bytes)
+        #[pallet::constant]
+        type MaxCidLength: Get<u32>;
+        /// Maximum number of servers a single account may own
+        #[pallet::constant]
+        type MaxServersPerOwner: Get<u32>;
+        /// Maximum number of tools a single server may offer
+        #[pallet::constant]
+        type MaxToolsPerServer: Get<u32>;
+        /// Maximum length of a JSON Schema (in bytes)
+        #[pallet::constant]
+        type MaxSchemaLength: Get<u32>;
+        /// Maximum number of prompt templates a single server may offer
+        #[pallet::constant]
+        type MaxPromptsPerServer: Get<u32>;
+        /// Maximum length of a prompt template (in bytes)
+        #[pallet::constant]
+        type MaxTemplateLength: Get<u32>;
+        /// Maximum number of resources a single server may offer
+        #[pallet::constant]
+        type MaxResourcesPerServer: Get<u32>;
+        /// Maximum number of accounts subscribed to changes of a single resource
+        #[pallet::constant]
+        type MaxSubscribersPerResource: Get<u32>;
+        /// Blocks a server owner has to answer an execution or sampling request before it may be
+        /// cancelled
+        #[pallet::constant]
+        type ExecutionTimeout: Get<BlockNumberFor<Self>>;
+        /// Smallest fee an execution request may offer
+        #[pallet::constant]
+        type MinExecutionFee: Get<BalanceOf<Self>>;
+        /// Maximum number of unanswered execution requests a single server may have
+        #[pallet::constant]
+        type MaxPendingExecutions: Get<u32>;
+        /// Maximum number of unsettled execution requests a single account may have made
+        #[pallet::constant]
+        type MaxRequestsPerAccount: Get<u32>;
+        /// Maximum number of model hints in the preferences of a sampling request
+        #[pallet::constant]
+        type MaxModelHints: Get<u32>;
+        /// Maximum number of unanswered sampling requests a single server may have
+        #[pallet::constant]
+        type MaxPendingSamplingRequests: Get<u32>;
+        /// Number of answered sampling requests kept in each server's sampling history
+        #[pallet::constant]
+        type MaxSamplingHistory: Get<u32>;
+        /// Multiple of a provider's server deposits that the fees escrowed in unanswered requests
+        /// to their servers may add up to
+        #[pallet::constant]
+        type ExposureMultiplier: Get<u32>;
+        /// Blocks after which the weight of a rating has halved
+        #[pallet::constant]
+        type ReputationHalfLife: Get<BlockNumberFor<Self>>;
+        /// Maximum number of accounts on the allowlist of a tool or resource
+        #[pallet::constant]
+        type MaxAllowlistLength: Get<u32>;
+        /// Number of blocks between two probe rounds of the same reporter
+        #[pallet::constant]
+        type ProbeInterval: Get<BlockNumberFor<Self>>;
+        /// Time a single probe may take before it counts as failed (in milliseconds)
+        #[pallet::constant]
+        type ProbeTimeout: Get<u64>;
+        /// Maximum number of servers probed and reported on in one round
+        #[pallet::constant]
+        type MaxHealthReports: Get<u32>;
+        /// Failed probes in a row after which a server is deactivated
+        #[pallet::constant]
+        type MaxConsecutiveFailures: Get<u32>;
+        /// Number of extrinsics kept in each account's activity history
+        #[pallet::constant]
+        type MaxActivityPerAccount: Get<u32>;
+    }
+
+    /// The identifier assigned to the next registered server.
+    #[pallet::storage]
+    pub type NextServerId<T: Config> = StorageValue<_, ServerId, ValueQuery>;
+
+    /// Registered servers by identifier.
+    #[pallet::storage]
+    pub type Servers<T: Config> = StorageMap<_, Twox64Concat, ServerId, ServerInfo<T>, OptionQuery>;
+
+    /// The next server checked for an expired lease.
+    #[pallet::storage]
+    pub type ExpiryCursor<T: Config> = StorageValue<_, ServerId, ValueQuery>;
+
+    /// Servers owned by each account, in registration order.
+    #[pallet::storage]
+    pub type ServersByOwner<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<ServerId, T::MaxServersPerOwner>,
+        ValueQuery,
+    >;
+
+    /// The identifier assigned to the next registered tool.
+    #[pallet::storage]
+    pub type NextToolId<T: Config> = StorageValue<_, ToolId, ValueQuery>;
+
+    /// Registered tools by identifier.
+    #[pallet::storage]
+    pub type Tools<T: Config> = StorageMap<_, Twox64Concat, ToolId, ToolInfo<T>, OptionQuery>;
+
+    /// Tools whose registration has not been revealed yet. They are listed in `ToolsByServer` and
+    /// `ToolIdByName` but only enter `Tools` once revealed.
+    #[pallet::storage]
+    pub type SealedTools<T: Config> =
+        StorageMap<_, Twox64Concat, ToolId, SealedTool<T>, OptionQuery>;
+
+    /// Tools offered by each server, in registration order.
+    #[pallet::storage]
+    pub type ToolsByServer<T: Config> =
+        StorageMap<_, Twox64Concat, ServerId, BoundedVec<ToolId, T::MaxToolsPerServer>, ValueQuery>;
+
+    /// Tool of each server by name, keeping names unique within a server.
+    #[pallet::storage]
+    pub type ToolIdByName<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        ServerId,
+        Blake2_128Concat,
+        NameOf<T>,
+        ToolId,
+        OptionQuery,
+    >;
+
+    /// The identifier assigned to the next registered prompt template.
+    #[pallet::storage]
+    pub type NextPromptId<T: Config> = StorageValue<_, PromptId, ValueQuery>;
+
+    /// Registered prompt templates by identifier.
+    #[pallet::storage]
+    pub type Prompts<T: Config> =
+        StorageMap<_, Twox64Concat, PromptId, PromptTemplate<T>, OptionQuery>;
+
+    /// Prompt templates offered by each server, in registration order.
+    #[pallet::storage]
+    pub type PromptsByServer<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        ServerId,
+        BoundedVec<PromptId, T::MaxPromptsPerServer>,
+        ValueQuery,
+    >;
+
+    /// Prompt template of each server by name, keeping names unique within a server.
+    #[pallet::storage]
+    pub type PromptIdByName<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        ServerId,
+        Blake2_128Concat,
+        NameOf<T>,
+        PromptId,
+        OptionQuery,
+    >;
+
+    /// The identifier assigned to the next registered resource.
+    #[pallet::storage]
+    pub type NextResourceId<T: Config> = StorageValue<_, ResourceId, ValueQuery>;
+
+    /// Registered resources by identifier.
+    #[pallet::storage]
+    pub type Resources<T: Config> =
+        StorageMap<_, Twox64Concat, ResourceId, ResourceInfo<T>, OptionQuery>;
+
+    /// Resources offered by each server, in registration order.
+    #[pallet::storage]
+    pub type ResourcesByServer<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        ServerId,
+        BoundedVec<ResourceId, T::MaxResourcesPerServer>,
+        ValueQuery,
+    >;
+
+    /// Resource of each server by URI, keeping URIs unique within a server.
+    #[pallet::storage]
+    pub type ResourceIdByUri<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        ServerId,
+        Blake2_128Concat,
+        UriOf<T>,
+        ResourceId,
+        OptionQuery,
+    >;
+
+    /// Accounts notified when a resource changes, in subscription order.
+    #[pallet::storage]
+    pub type ResourceSubscribers<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        ResourceId,
+        BoundedVec<T::AccountId, T::MaxSubscribersPerResource>,
+        ValueQuery,
+    >;
+
+    /// Resources each account follows. Entries of removed resources are left behind and ignored,
+    /// since resource identifiers are never reused.
+    #[pallet::storage]
+    pub type ResourceSubscriptionsByAccount<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Twox64Concat,
+        ResourceId,
+        (),
+        OptionQuery,
+    >;
+
+    /// The identifier assigned to the next execution request.
+    #[pallet::storage]
+    pub type NextRequestId<T: Config> = StorageValue<_, RequestId, ValueQuery>;
+
+    /// Unsettled execution requests by identifier. Requests are removed once completed or
+    /// cancelled.
+    #[pallet::storage]
+    pub type ExecutionRequests<T: Config> =
+        StorageMap<_, Twox64Concat, RequestId, ExecutionRequest<T>, OptionQuery>;
+
+    /// Unsettled execution requests addressed to each server, oldest first.
+    #[pallet::storage]
+    pub type PendingExecutions<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        ServerId,
+        BoundedVec<RequestId, T::MaxPendingExecutions>,
+        ValueQuery,
+    >;
+
+    /// Unsettled execution requests made by each account, oldest first.
+    #[pallet::storage]
+    pub type RequestsByAccount<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<RequestId, T::MaxRequestsPerAccount>,
+        ValueQuery,
+    >;
+
+    /// Total fee escrowed in the unsettled execution requests to each server.
+    #[pallet::storage]
+    pub type ServerEscrow<T: Config> =
+        StorageMap<_, Twox64Concat, ServerId, BalanceOf<T>, ValueQuery>;
+
+    /// Credits held for the deposit of each server whose owner paid it in credits.
+    #[pallet::storage]
+    pub type CreditDeposits<T: Config> =
+        StorageMap<_, Twox64Concat, ServerId, BalanceOf<T>, OptionQuery>;
+
+    /// Credits held for the fee of each unsettled request paid in credits.
+    #[pallet::storage]
+    pub type CreditEscrow<T: Config> =
+        StorageMap<_, Twox64Concat, RequestId, BalanceOf<T>, OptionQuery>;
+
+    /// Native bond reserved from the requester of each unsettled request that costs nothing, so
+    /// that queueing a run always locks funds. It is returned when the request is settled.
+    #[pallet::storage]
+    pub type ExecutionBonds<T: Config> =
+        StorageMap<_, Twox64Concat, RequestId, BalanceOf<T>, OptionQuery>;
+
+    /// The identifier assigned to the next sampling request.
+    #[pallet::storage]
+    pub type NextSamplingRequestId<T: Config> = StorageValue<_, SamplingRequestId, ValueQuery>;
+
+    /// Unanswered sampling requests by identifier. Requests are removed once answered or
+    /// cancelled.
+    #[pallet::storage]
+    pub type SamplingRequests<T: Config> =
+        StorageMap<_, Twox64Concat, SamplingRequestId, SamplingRequest<T>, OptionQuery>;
+
+    /// Unanswered sampling requests addressed to each server, oldest first.
+    #[pallet::storage]
+    pub type PendingSamplingRequests<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        ServerId,
+        BoundedVec<SamplingRequestId, T::MaxPendingSamplingRequests>,
+        ValueQuery,
+    >;
+
+    /// Latest answered sampling requests of each server, oldest first. Once the history is full,
+    /// every new result drops the oldest one.
+    #[pallet::storage]
+    pub type SamplingHistory<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        ServerId,
+        BoundedVec<SamplingResult<T>, T::MaxSamplingHistory>,
+        ValueQuery,
+    >;
+
+    /// Pricing model of each priced tool; tools without one accept any fee of at least
+    /// `MinExecutionFee`.
+    #[pallet::storage]
+    pub type ToolPricing<T: Config> =
+        StorageMap<_, Twox64Concat, ToolId, PricingModel<T>, OptionQuery>;
+
+    /// Each account's use of each tool it completed a run of.
+    #[pallet::storage]
+    pub type Usage<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        ToolId,
+        Blake2_128Concat,
+        T::AccountId,
+        ToolUsage<T>,
+        ValueQuery,
+    >;
+
+    /// Tools each account ever bought a subscription to, whose end is kept in `Usage`.
+    #[pallet::storage]
+    pub type ToolSubscriptionsByAccount<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, ToolId, (), OptionQuery>;
+
+    /// Subscription period bought by each unsettled request that starts a subscription.
+    #[pallet::storage]
+    pub type SubscriptionPurchases<T: Config> =
+        StorageMap<_, Twox64Concat, RequestId, BlockNumberFor<T>, OptionQuery>;
+
+    /// Ratings each account may still give a tool: one per completed run of it that paid a fee to
+    /// the server owner.
+    #[pallet::storage]
+    pub type RatingCredits<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, ToolId, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Reputation of each rated tool.
+    #[pallet::storage]
+    pub type ToolReputation<T: Config> =
+        StorageMap<_, Twox64Concat, ToolId, ReputationScore<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Reputation of each server with a rated tool, over the ratings of all its tools.
+    #[pallet::storage]
+    pub type ServerReputation<T: Config> =
+        StorageMap<_, Twox64Concat, ServerId, ReputationScore<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Access rules of tools and resources; those without an entry are public.
+    #[pallet::storage]
+    pub type AccessRules<T: Config> =
+        StorageMap<_, Blake2_128Concat, AccessTarget, AccessRule<T>, ValueQuery>;
+
+    /// Verification level of each verified server.
+    #[pallet::storage]
+    pub type ServerVerification<T: Config> =
+        StorageMap<_, Twox64Concat, ServerId, VerificationLevel, OptionQuery>;
+
+    /// Endpoint health of each server that was probed at least once.
+    #[pallet::storage]
+    pub type ServerHealth<T: Config> =
+        StorageMap<_, Twox64Concat, ServerId, HealthStatus<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Block of each reporter's last accepted health report.
+    #[pallet::storage]
+    pub type LastHealthReport<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+    /// Latest extrinsics of each account that changed the registry, oldest first. Once the
+    /// history is full, every new record drops the oldest one.
+    #[pallet::storage]
+    pub type ActivityByAccount<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<ActivityRecord<BlockNumberFor<T>>, T::MaxActivityPerAccount>,
+        ValueQuery,
+    >;
+
+    /// Events emitted by this pallet.
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A server was registered.
+        ServerRegistered {
+            /// The identifier assigned to the server.
+            id: ServerId,
+            /// The account owning the server.
+            owner: T::AccountId,
+            /// The server's name.
+            name: NameOf<T>,
+        },
+        /// A server's registration was replaced.
+        ServerUpdated {
+            /// The updated server.
+            id: ServerId,
+        },
+        /// A server moved to a newer protocol revision.
+        ServerProtocolUpgraded {
+            /// The upgraded server.
+            id: ServerId,
+            /// The revision the server implemented before.
+            from: ProtocolVersion,
+            /// The revision the server implements now.
+            to: ProtocolVersion,
+        },
+        /// A server was taken out of service.
+        ServerDeactivated {
+            /// The deactivated server.
+            id: ServerId,
+        },
+        /// A server was put back into service.
+        ServerActivated {
+            /// The activated server.
+            id: ServerId,
+        },
+        /// A server was removed.
+        ServerDeregistered {
+            /// The removed server.
+            id: ServerId,
+            /// The account that owned the server.
+            owner: T::AccountId,
+            /// Number of tools removed with the server.
+            tools_removed: u32,
+            /// Number of prompt templates removed with the server.
+            prompts_removed: u32,
+            /// Number of resources removed with the server.
+            resources_removed: u32,
+        },
+        /// A server's lease was renewed.
+        ServerRenewed {
+            /// The renewed server.
+            id: ServerId,
+            /// Last block of the new lease.
+            expires_at: BlockNumberFor<T>,
+            /// Renewal fee burned from the owner.
+            fee: BalanceOf<T>,
+        },
+        /// A server's lease ran out without renewal; it is removed right after and its deposit
+        /// released.
+        ServerExpired {
+            /// The expired server.
+            id: ServerId,
+            /// The account that owned the server.
+            owner: T::AccountId,
+        },
+        /// A server was slashed; it is removed right after.
+        ServerSlashed {
+            /// The slashed server.
+            id: ServerId,
+            /// The account that owned the server.
+            owner: T::AccountId,
+            /// Amount of the deposit that was burned.
+            amount: BalanceOf<T>,
+        },
+        /// `VerifyOrigin` verified a server.
+        ServerVerified {
+            /// The verified server.
+            id: ServerId,
+            /// The level it was verified at.
+            level: VerificationLevel,
+        },
+        /// A server lost its verification, either revoked by `VerifyOrigin` or because its
+        /// registration was replaced.
+        VerificationRevoked {
+            /// The server that is no longer verified.
+            id: ServerId,
+        },
+        /// A tool was registered.
+        ToolRegistered {
+            /// The identifier assigned to the tool.
+            tool_id: ToolId,
+            /// The server offering the tool.
+            server_id: ServerId,
+            /// The tool's name.
+            name: NameOf<T>,
+        },
+        /// A tool was registered sealed; its registration is revealed at `reveal_at`.
+        ToolSealed {
+            /// The identifier assigned to the tool.
+            tool_id: ToolId,
+            /// The server that will offer the tool.
+            server_id: ServerId,
+            /// The reserved name.
+            name: NameOf<T>,
+            /// First block at which the tool may be revealed.
+            reveal_at: BlockNumberFor<T>,
+        },
+        /// A sealed tool's registration was revealed; the tool is now offered.
+        ToolRevealed {
+            /// The revealed tool.
+            tool_id: ToolId,
+            /// The server offering the tool.
+            server_id: ServerId,
+        },
+        /// A tool's registration was replaced.
+        ToolUpdated {
+            /// The updated tool.
+            tool_id: ToolId,
+            /// The server offering the tool.
+            server_id: ServerId,
+        },
+        /// A tool was enabled or disabled.
+        ToolStatusChanged {
+            /// The tool.
+            tool_id: ToolId,
+            /// The server offering the tool.
+            server_id: ServerId,
+            /// Whether the tool may now be called.
+            active: bool,
+        },
+        /// A tool was removed.
+        ToolRemoved {
+            /// The removed tool.
+            tool_id: ToolId,
+            /// The server that offered the tool.
+            server_id: ServerId,
+        },
+        /// The pricing model of a tool was replaced.
+        ToolPricingSet {
+            /// The priced tool.
+            tool_id: ToolId,
+            /// The new pricing model.
+            pricing: PricingModel<T>,
+        },
+        /// A prompt template was added.
+        PromptAdded {
+            /// The identifier assigned to the prompt.
+            prompt_id: PromptId,
+            /// The server offering the prompt.
+            server_id: ServerId,
+            /// The prompt's name.
+            name: NameOf<T>,
+        },
+        /// A prompt template was replaced.
+        PromptUpdated {
+            /// The updated prompt.
+            prompt_id: PromptId,
+            /// The server offering the prompt.
+            server_id: ServerId,
+        },
+        /// A prompt template was removed.
+        PromptRemoved {
+            /// The removed prompt.
+            prompt_id: PromptId,
+            /// The server that offered the prompt.
+            server_id: ServerId,
+        },
+        /// A resource was added.
+        ResourceAdded {
+            /// The identifier assigned to the resource.
+            resource_id: ResourceId,
+            /// The server offering the resource.
+            server_id: ServerId,
+            /// The resource's URI.
+            uri: UriOf<T>,
+        },
+        /// A resource was replaced.
+        ResourceUpdated {
+            /// The updated resource.
+            resource_id: ResourceId,
+            /// The server offering the resource.
+            server_id: ServerId,
+            /// Number of subscribers a `ResourceChanged` event was emitted for.
+            subscribers_notified: u32,
+        },
+        /// A resource a subscriber follows has changed, mirroring the MCP
+        /// `notifications/resources/updated` message.
+        ResourceChanged {
+            /// The changed resource.
+            resource_id: ResourceId,
+            /// The account to notify.
+            subscriber: T::AccountId,
+        },
+        /// An account subscribed to changes of a resource.
+        ResourceSubscribed {
+            /// The followed resource.
+            resource_id: ResourceId,
+            /// The subscribing account.
+            subscriber: T::AccountId,
+        },
+        /// An account unsubscribed from changes of a resource.
+        ResourceUnsubscribed {
+            /// The resource no longer followed.
+            resource_id: ResourceId,
+            /// The unsubscribing account.
+            subscriber: T::AccountId,
+        },
+        /// A resource was removed.
+        ResourceRemoved {
+            /// The removed resource.
+            resource_id: ResourceId,
+            /// The server that offered the resource.
+            server_id: ServerId,
+        },
+        /// A tool run was requested and its fee escrowed.
+        ExecutionRequested {
+            /// The identifier assigned to the request.
+            request_id: RequestId,
+            /// The tool to run.
+            tool_id: ToolId,
+            /// The server offering the tool.
+            server_id: ServerId,
+            /// The account paying for the run.
+            requester: T::AccountId,
+            /// CID of the tool's input.
+            input_cid: CidOf<T>,
+            /// The escrowed fee.
+            fee: BalanceOf<T>,
+            /// The asset the fee is escrowed in.
+            asset: PaymentAsset,
+        },
+        /// A server owner posted the output of a requested run and was paid.
+        ExecutionCompleted {
+            /// The completed request.
+            request_id: RequestId,
+            /// CID of the tool's output.
+            output_cid: CidOf<T>,
+            /// The fee paid to the server owner.
+            fee: BalanceOf<T>,
+            /// The asset the fee was paid in.
+            asset: PaymentAsset,
+        },
+        /// A completed run started or renewed the requester's subscription to a tool.
+        Subscribed {
+            /// The tool subscribed to.
+            tool_id: ToolId,
+            /// The subscribed account.
+            subscriber: T::AccountId,
+            /// Last block covered by the subscription.
+            until: BlockNumberFor<T>,
+        },
+        /// An unanswered request was cancelled after its deadline and refunded.
+        ExecutionExpired {
+            /// The cancelled request.
+            request_id: RequestId,
+            /// The fee returned to the requester.
+            refunded: BalanceOf<T>,
+            /// The asset the fee was returned in.
+            asset: PaymentAsset,
+        },
+        /// A server was asked to sample a language model.
+        SamplingRequested {
+            /// The identifier assigned to the request.
+            request_id: SamplingRequestId,
+            /// The server asked to answer it.
+            server_id: ServerId,
+            /// The account that made the request.
+            requester: T::AccountId,
+            /// CID of the messages to complete.
+            prompt_cid: CidOf<T>,
+            /// The model the requester would like to be sampled.
+            model_preferences: ModelPreferences<T>,
+        },
+        /// A server owner posted the completion of a sampling request.
+        SamplingCompleted {
+            /// The answered request.
+            request_id: SamplingRequestId,
+            /// The server that answered it.
+            server_id: ServerId,
+            /// CID of the completion.
+            completion_cid: CidOf<T>,
+            /// Tokens used for the completion.
+            usage: UsageStats,
+        },
+        /// A sampling request was withdrawn by its requester or dropped after its deadline.
+        SamplingCancelled {
+            /// The cancelled request.
+            request_id: SamplingRequestId,
+        },
+        /// A requester rated a tool.
+        ToolRated {
+            /// The rated tool.
+            tool_id: ToolId,
+            /// The server offering the tool.
+            server_id: ServerId,
+            /// The account that gave the rating.
+            rater: T::AccountId,
+            /// The score, from 1 to 5.
+            score: u8,
+            /// CID of a written comment on IPFS.
+            comment_cid: Option<CidOf<T>>,
+        },
+        /// The access rule of a tool or resource was replaced.
+        AccessPolicySet {
+            /// The restricted tool or resource.
+            target: AccessTarget,
+            /// The new rule.
+            rule: AccessRule<T>,
+        },
+        /// An account was added to the allowlist of a tool or resource.
+        AccessGranted {
+            /// The restricted tool or resource.
+            target: AccessTarget,
+            /// The account granted access.
+            who: T::AccountId,
+        },
+        /// An account was removed from the allowlist of a tool or resource.
+        AccessRevoked {
+            /// The restricted tool or resource.
+            target: AccessTarget,
+            /// The account that lost access.
+            who: T::AccountId,
+        },
+        /// A batch of probe results was recorded.
+        ServerHealthReported {
+            /// The reporter that submitted the batch.
+            reporter: T::AccountId,
+            /// Number of results applied to registered servers.
+            applied: u32,
+        },
+    }
+
+    /// Errors that can be returned by this pallet.
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The server does not exist.
+        ServerNotFound,
+        /// The caller does not own the server.
+        NotServerOwner,
+        /// The name is empty.
+        EmptyName,
+        /// The name or description is not valid UTF-8.
+        InvalidText,
+        /// The transport URL does not use a scheme supported by the transport.
+        InvalidUrl,
+        /// The transport URL points to a loopback, private or link-local host, but the server is
+        /// not marked as running on a private network.
+        PrivateHost,
+        /// The caller already owns `MaxServersPerOwner` servers.
+        TooManyServers,
+        /// The server is already active.
+        ServerAlreadyActive,
+        /// The server is already inactive.
+        ServerAlreadyInactive,
+        /// The protocol revision is not newer than the one the server implements.
+        ProtocolNotNewer,
+        /// The server's lease has run out; it is removed and can no longer be renewed.
+        RegistrationExpired,
+        /// The call has no deposit or fee that can be paid in credits.
+        NotPayableWithCredits,
+        /// The server is not verified.
+        NotVerified,
+        /// The tool does not exist.
+        ToolNotFound,
+        /// The tool is not sealed.
+        ToolNotSealed,
+        /// The reveal block of a sealed tool is not in the future.
+        InvalidRevealBlock,
+        /// The sealed tool's reveal block has not been reached yet.
+        RevealTooEarly,
+        /// The revealed registration does not match the sealed tool's commitment or name.
+        CommitmentMismatch,
+        /// The server already offers `MaxToolsPerServer` tools.
+        TooManyTools,
+        /// The server already offers a tool with this name.
+        DuplicateToolName,
+        /// A JSON Schema is empty or not valid UTF-8.
+        InvalidSchema,
+        /// A CID is not a well-formed CIDv0 or multibase CIDv1.
+        InvalidCid,
+        /// The prompt template does not exist.
+        PromptNotFound,
+        /// The server already offers `MaxPromptsPerServer` prompt templates.
+        TooManyPrompts,
+        /// The server already offers a prompt template with this name.
+        DuplicatePromptName,
+        /// The template text is empty, not valid UTF-8 or has a malformed placeholder.
+        InvalidTemplate,
+        /// The resource does not exist.
+        ResourceNotFound,
+        /// The server already offers `MaxResourcesPerServer` resources.
+        TooManyResources,
+        /// The server already offers a resource with this URI.
+        DuplicateResourceUri,
+        /// The resource URI or MIME type is empty or not valid UTF-8.
+        InvalidResource,
+        /// The resource's server does not announce resource subscriptions.
+        SubscriptionsNotSupported,
+        /// The caller is already subscribed to the resource.
+        AlreadySubscribed,
+        /// The caller is not subscribed to the resource.
+        NotSubscribed,
+        /// The resource already has `MaxSubscribersPerResource` subscribers.
+        TooManySubscribers,
+        /// The server is not active.
+        ServerInactive,
+        /// The tool is not active.
+        ToolInactive,
+        /// The offered fee is below `MinExecutionFee`.
+        FeeTooLow,
+        /// A price is below `MinExecutionFee` or a subscription period is zero.
+        InvalidPricing,
+        /// The tool's price is above the offered fee.
+        PriceAboveMaxFee,
+        /// The server already has `MaxPendingExecutions` unanswered requests.
+        TooManyPendingExecutions,
+        /// The caller already has `MaxRequestsPerAccount` unsettled requests.
+        TooManyRequests,
+        /// The fees escrowed for the server owner would exceed `ExposureMultiplier` times their
+        /// server deposits.
+        ProviderExposureLimit,
+        /// The execution request does not exist or was already settled.
+        ExecutionNotFound,
+        /// The caller did not make the execution request.
+        NotRequester,
+        /// The request's deadline has passed; it can only be cancelled.
+        ExecutionTimedOut,
+        /// The request's deadline has not passed yet.
+        ExecutionNotExpired,
+        /// The server does not announce the sampling capability.
+        SamplingNotSupported,
+        /// The server already has `MaxPendingSamplingRequests` unanswered sampling requests.
+        TooManyPendingSamplingRequests,
+        /// The sampling request does not exist or was already answered or cancelled.
+        SamplingRequestNotFound,
+        /// The sampling request's deadline has passed; it can only be cancelled.
+        SamplingTimedOut,
+        /// The score is not between 1 and 5.
+        InvalidScore,
+        /// The caller has no completed paid run of the tool left to rate.
+        NoSettledExecution,
+        /// The caller owns the server offering the tool.
+        CannotRateOwnTool,
+        /// The caller may not use the tool or resource.
+        AccessDenied,
+        /// The tool or resource is not restricted to an allowlist.
+        NotAnAllowlist,
+        /// The allowlist already holds `MaxAllowlistLength` accounts.
+        TooManyAllowlisted,
+        /// The account is already on the allowlist.
+        AlreadyAllowlisted,
+        /// The account is not on the allowlist.
+        NotAllowlisted,
+        /// The caller is not an allowed health reporter.
+        NotReporter,
+        /// The reporter already reported within the current probe interval.
+        ReportTooEarly,
+        /// Every identifier of this kind has been assigned.
+        IdsExhausted,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::expire_servers(now, remaining_weight)
+        }
+
+        fn integrity_test() {
+            assert!(
+                !T::RegistrationPeriod::get().is_zero(),
+                "Servers must be leased for at least one block"
+            );
+            assert!(
+                !T::NativePerCredit::get().is_zero(),
+                "Credits must be worth some native balance"
+            );
+            assert!(
+                !T::ProbeInterval::get().is_zero(),
+                "Servers must be probed at an interval of at least one block"
+            );
+        }
+
+        fn offchain_worker(now: BlockNumberFor<T>) {
+            if (now % T::ProbeInterval::get()) != Zero::zero() {
+                return;
+            }
+            if let Err(e) = Self::probe_and_report() {
+                log::warn!(target: "runtime::mcp", "Health report skipped: {e}");
+            }
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
+        }
+    }
+
+    /// Dispatchable functions for the MCP pallet.
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Register a new server owned by the caller.
+        ///
+        /// The server starts active.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (must be signed)
+        /// * `registration` - Name, description, protocol, capabilities, transport and metadata
+        ///
+        /// # Errors
+        /// * `EmptyName` / `InvalidText` - If the name or description is invalid
+        /// * `InvalidUrl` - If the transport URL does not match the transport
+        /// * `PrivateHost` - If the transport URL points to a loopback, private or link-local host
+        ///   of a server not marked as on a private network
+        /// * `InvalidCid` - If the metadata or access policy CID is malformed
+        /// * `TooManyServers` - If the caller already owns `MaxServersPerOwner` servers
+        /// * `InsufficientBalance` - If the caller cannot reserve `ServerDeposit`
+        #[pallet::call_index(0)]
+        #[pallet::weight(
+            T::WeightInfo::register_server()
+                .saturating_add(T::OnServerRegistered::weight())
+                .saturating_add(T::OnCidPublished::weight())
+        )]
+        pub fn register_server(
+            origin: OriginFor<T>,
+            registration: ServerRegistration<T>,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            Self::do_register_server(owner, registration, PaymentAsset::Native)
+        }
+
+        /// Register a new server owned by the caller together with its tools, prompt templates
+        /// and resources, e.g. to bring an existing MCP server on chain in a single transaction.
+        ///
+        /// Everything is registered or nothing is: the call fails as a whole if any entry is
+        /// rejected. The events are the same as for registering each entry on its own. The call is
+        /// charged for every prompt and resource publishing a metadata CID; the difference is
+        /// refunded.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (must be signed)
+        /// * `registration` - Name, description, protocol, capabilities, transport and metadata
+        /// * `tools` - The tools the server offers
+        /// * `prompts` - The prompt templates the server offers
+        /// * `resources` - The resources the server offers
+        ///
+        /// # Errors
+        /// * Any error of `register_server`, `register_tool`, `add_prompt` or `add_resource`
+        #[pallet::call_index(32)]
+        #[pallet::weight(Pallet::<T>::catalog_weight(
+            tools.len() as u32,
+            prompts.len() as u32,
+            resources.len() as u32,
+            1 + prompts.len() as u32 + 2 * resources.len() as u32,
+        ))]
+        pub fn register_server_with_catalog(
+            origin: OriginFor<T>,
+            registration: ServerRegistration<T>,
+            tools: BoundedVec<ToolRegistration<T>, T::MaxToolsPerServer>,
+            prompts: BoundedVec<PromptRegistration<T>, T::MaxPromptsPerServer>,
+            resources: BoundedVec<ResourceRegistration<T>, T::MaxResourcesPerServer>,
+        ) -> DispatchResultWithPostInfo {
+            let owner = ensure_signed(origin)?;
+            Self::do_register_server_with_catalog(
+                owner,
+                registration,
+                tools,
+                prompts,
+                resources,
+                PaymentAsset::Native,
+            )
+        }
+
+        /// Dispatch a registration or execution request, paying its deposit or fee in the
+        /// compute-credit asset instead of the native currency.
+        ///
+        /// `call` must be `register_server`, `register_server_with_catalog` or
+        /// `request_tool_execution`. The deposit or fee keeps its native amount; credits worth
+        /// that amount at `NativePerCredit`, rounded up, move from the caller into the pallet's
+        /// credit account. They are paid out, refunded or burned in place of the native amount,
+        /// and execution events record the asset each fee was settled in.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (must be signed)
+        /// * `call` - The call to pay for in credits
+        ///
+        /// # Errors
+        /// * `NotPayableWithCredits` - If `call` is not one of this pallet's registration or
+        ///   execution request calls
+        /// * Any error of `call`, or of moving the caller's credits
+        #[pallet::call_index(35)]
+        #[pallet::weight(
+            T::WeightInfo::pay_with_credits().saturating_add(call.get_dispatch_info().call_weight)
+        )]
+        pub fn pay_with_credits(
+            origin: OriginFor<T>,
+            call: Box<<T as Config>::RuntimeCall>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let asset = PaymentAsset::Credits;
+            let call = call
+                .is_sub_type()
+                .cloned()
+                .ok_or(Error::<T>::NotPayableWithCredits)?;
+
+            let result: DispatchResultWithPostInfo = match call {
+                Call::register_server { registration } => {
+                    Self::do_register_server(who, registration, asset)
+                        .map(Into::into)
+                        .map_err(Into::into)
+                }
+                Call::register_server_with_catalog {
+                    registration,
+                    tools,
+                    prompts,
+                    resources,
+                } => Self::do_register_server_with_catalog(
+                    who,
+                    registration,
+                    tools,
+                    prompts,
+                    resources,
+                    asset,
+                ),
+                Call::request_tool_execution {
+                    tool_id,
+                    input_cid,
+                    max_fee,
+                } => Self::do_request_tool_execution(who, tool_id, input_cid, max_fee, asset)
+                    .map(Into::into)
+                    .map_err(Into::into),
+                _ => return Err(Error::<T>::NotPayableWithCredits.into()),
+            };
+
+            // A refund of the inner call keeps the cost of moving the credits
+            let overhead = |mut info: PostDispatchInfo| {
+                info.actual_weight = info
+                    .actual_weight
+                    .map(|weight| weight.saturating_add(T::WeightInfo::pay_with_credits()));
+                info
+            };
+            result.map(overhead).map_err(|mut error| {
+                error.post_info = overhead(error.post_info);
+                error
+            })
+        }
+
+        /// Replace the registration of a server.
+        ///
+        /// Ownership, the active flag and the registration block are kept. Any verification
+        /// applied to the replaced registration is dropped. As with `upgrade_server_protocol`,
+        /// the protocol revision may only move forward.
+        ///
+        /// # Errors
+        /// * `ServerNotFound` - If the server does not exist
+        /// * `NotServerOwner` - If the caller does not own the server
+        /// * `EmptyName` / `InvalidText` / `InvalidUrl` / `PrivateHost` / `InvalidCid` - If the
+        ///   registration is invalid
+        /// * `ProtocolNotNewer` - If the registration carries an older revision than the server's
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::update_server().saturating_add(T::OnCidPublished::weight()))]
+        pub fn update_server(
+            origin: OriginFor<T>,
+            id: ServerId,
+            registration: ServerRegistration<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::validate_registration(&registration)?;
+
+            let metadata_cid = registration.metadata_cid.clone();
+            let to = registration.protocol_version;
+            let from = Self::mutate_owned_server(id, &who, |server| {
+                let from = server.protocol_version;
+                ensure!(to >= from, Error::<T>::ProtocolNotNewer);
+                server.apply(registration);
+                Ok(from)
+            })?;
+            Self::publish_cids(&metadata_cid);
+
+            Self::record_activity(&who, ActivitySubject::Server(id));
+            Self::deposit_event(Event::ServerUpdated { id });
+            if to > from {
+                Self::deposit_event(Event::ServerProtocolUpgraded { id, from, to });
+            }
+            if ServerVerification::<T>::take(id).is_some() {
+                Self::deposit_event(Event::VerificationRevoked { id });
+            }
+
+            Ok(())
+        }
+
+        /// Move a server to a newer revision of the protocol, keeping the rest of its
+        /// registration.
+        ///
+        /// Revisions only move forward, so clients that negotiated a revision with a server can
+        /// rely on it still being supported.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (must be signed)
+        /// * `id` - The server to upgrade
+        /// * `new_version` - The revision the server implements from now on
+        ///
+        /// # Errors
+        /// * `ServerNotFound` - If the server does not exist
+        /// * `NotServerOwner` - If the caller does not own the server
+        /// * `ProtocolNotNewer` - If `new_version` is not newer than the server's revision
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::WeightInfo::upgrade_server_protocol())]
+        pub fn upgrade_server_protocol(
+            origin: OriginFor<T>,
+            id: ServerId,
+            new_version: ProtocolVersion,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let from = Self::mutate_owned_server(id, &who, |server| {
+                let from = server.protocol_version;
+                ensure!(new_version > from, Error::<T>::ProtocolNotNewer);
+                server.protocol_version = new_version;
+                Ok(from)
+            })?;
+
+            Self::record_activity(&who, ActivitySubject::Server(id));
+            Self::deposit_event(Event::ServerProtocolUpgraded {
+                id,
+                from,
+                to: new_version,
+            });
+
+            Ok(())
+        }
+
+        /// Take a server out of service, e.g. for maintenance.
+        ///
+        /// # Errors
+        /// * `ServerNotFound` - If the server does not exist
+        /// * `NotServerOwner` - If the caller does not own the server
+        /// * `ServerAlreadyInactive` - If the server is already inactive
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::deactivate_server())]
+        pub fn deactivate_server(origin: OriginFor<T>, id: ServerId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::mutate_owned_server(id, &who, |server| {
+                ensure!(server.active, Error::<T>::ServerAlreadyInactive);
+                server.active = false;
+                Ok(())
+            })?;
+
+            Self::record_activity(&who, ActivitySubject::Server(id));
+            Self::deposit_event(Event::ServerDeactivated { id });
+
+            Ok(())
+        }
+
+        /// Put a deactivated server back into service.
+        ///
+        /// The server's count of failed probes is reset, so a server deactivated for failing
+        /// probes gets a fresh allowance.
+        ///
+        /// # Errors
+        /// * `ServerNotFound` - If the server does not exist
+        /// * `NotServerOwner` - If the caller does not own the server
+        /// * `ServerAlreadyActive` - If the server is already active
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::activate_server())]
+        pub fn activate_server(origin: OriginFor<T>, id: ServerId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::mutate_owned_server(id, &who, |server| {
+                ensure!(!server.active, Error::<T>::ServerAlreadyActive);
+                server.active = true;
+                Ok(())
+            })?;
+            ServerHealth::<T>::mutate_extant(id, |health| health.consecutive_failures = 0);
+
+            Self::record_activity(&who, ActivitySubject::Server(id));
+            Self::deposit_event(Event::ServerActivated { id });
+
+            Ok(())
+        }
+
+        /// Renew the lease of a server for `RegistrationPeriod` blocks from the current block,
+        /// burning `RenewalFee` from the caller.
+        ///
+        /// Servers whose lease runs out are removed in the idle time of later blocks, so owners
+        /// renew the servers they keep operating. Renewing early does not add up: the new lease
+        /// always ends `RegistrationPeriod` blocks after the renewal.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (must be signed)
+        /// * `id` - The server to renew
+        ///
+        /// # Errors
+        /// * `ServerNotFound` - If the server does not exist
+        /// * `NotServerOwner` - If the caller does not own the server
+        /// * `RegistrationExpired` - If the lease has already run out
+        /// * `InsufficientBalance` - If the caller cannot pay `RenewalFee`
+        #[pallet::call_index(34)]
+        #[pallet::weight(T::WeightInfo::renew_server())]
+        pub fn renew_server(origin: OriginFor<T>, id: ServerId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let expires_at = Self::mutate_owned_server(id, &who, |server| {
+                ensure!(server.expires_at >= now, Error::<T>::RegistrationExpired);
+                server.expires_at = now.saturating_add(T::RegistrationPeriod::get());
+                Ok(server.expires_at)
+            })?;
+            let fee = T::RenewalFee::get();
+            // The imbalance is dropped at the end of the statement, burning the fee
+            let _ = T::Currency::withdraw(
+                &who,
+                fee,
+                WithdrawReasons::FEE,
+                ExistenceRequirement::KeepAlive,
+            )?;
+
+            Self::record_activity(&who, ActivitySubject::Server(id));
+            Self::deposit_event(Event::ServerRenewed {
+                id,
+                expires_at,
+                fee,
+            });
+
+            Ok(())
+        }
+
+        /// Remove a server together with its tools, prompt templates and resources, releasing its
+        /// deposit.
+        ///
+        /// The call is charged for a server offering the maximum number of each; the
+        /// difference is refunded.
+        ///
+        /// # Errors
+        /// * `ServerNotFound` - If the server does not exist
+        /// * `NotServerOwner` - If the caller does not own the server
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::deregister_server(
+            T::MaxToolsPerServer::get(),
+            T::MaxPromptsPerServer::get(),
+            T::MaxResourcesPerServer::get(),
+        ))]
+        pub fn deregister_server(origin: OriginFor<T>, id: ServerId) -> DispatchResultWithPostInfo {
+            let owner = ensure_signed(origin)?;
+
+            let server = Servers::<T>::get(id).ok_or(Error::<T>::ServerNotFound)?;
+            ensure!(server.owner == owner, Error::<T>::NotServerOwner);
+
+            Self::release_deposit(id, &server)?;
+            Self::record_activity(&owner, ActivitySubject::Server(id));
+            let (tools, prompts, resources) = Self::remove_server(id, owner);
+
+            Ok(Some(T::WeightInfo::deregister_server(tools, prompts, resources)).into())
+        }
+
+        /// Register a tool offered by a server owned by the caller.
+        ///
+        /// The tool starts active.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (must be signed)
+        /// * `server_id` - The server offering the tool
+        /// * `registration` - Name, description and JSON Schemas of the tool
+        ///
+        /// # Errors
+        /// * `ServerNotFound` - If the server does not exist
+        /// * `NotServerOwner` - If the caller does not own the server
+        /// * `EmptyName` / `InvalidText` / `InvalidSchema` - If the registration is invalid
+        /// * `DuplicateToolName` - If the server already offers a tool with this name
+        /// * `TooManyTools` - If the server already offers `MaxToolsPerServer` tools
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::register_tool())]
+        pub fn register_tool(
+            origin: OriginFor<T>,
+            server_id: ServerId,
+            registration: ToolRegistration<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_server_owner(server_id, &who)?;
+            Self::validate_tool(&registration)?;
+
+            let tool_id = Self::insert_tool(server_id, registration)?;
+
+            Self::record_activity(&who, ActivitySubject::Tool(tool_id));
+            Ok(())
+        }
+
+        /// Replace the registration of a tool.
+        ///
+        /// The tool keeps its server and status.
+        ///
+        /// # Errors
+        /// * `ToolNotFound` - If the tool does not exist
+        /// * `NotServerOwner` - If the caller does not own the tool's server
+        /// * `EmptyName` / `InvalidText` / `InvalidSchema` - If the registration is invalid
+        /// * `DuplicateToolName` - If another tool of the server has this name
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::update_tool())]
+        pub fn update_tool(
+            origin: OriginFor<T>,
+            tool_id: ToolId,
+            registration: ToolRegistration<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::validate_tool(&registration)?;
+
+            Self::replace_tool(tool_id, &who, registration)?;
+
+            Self::record_activity(&who, ActivitySubject::Tool(tool_id));
+            Ok(())
+        }
+
+        /// Replace the registrations of several tools, e.g. after a new release of a server.
+        ///
+        /// Updates are applied in order and all or none of them: the call fails as a whole if any
+        /// update is rejected. Names are checked against the tools as renamed by earlier updates,
+        /// so two tools cannot swap names in one batch. A failing batch is only charged for the
+        /// updates up to the rejected one.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (must be signed)
+        /// * `updates` - Each tool to update with its new registration
+        ///
+        /// # Errors
+        /// * Any error of `update_tool`
+        #[pallet::call_index(33)]
+        #[pallet::weight(T::WeightInfo::batch_update_tools(updates.len() as u32))]
+        pub fn batch_update_tools(
+            origin: OriginFor<T>,
+            updates: BoundedVec<(ToolId, ToolRegistration<T>), T::MaxToolsPerServer>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            updates
+                .iter()
+                .try_for_each(|(_, registration)| Self::validate_tool(registration))
+                .map_err(|error| error.with_weight(T::WeightInfo::batch_update_tools(0)))?;
+
+            for (index, (tool_id, registration)) in updates.into_iter().enumerate() {
+                Self::replace_tool(tool_id, &who, registration).map_err(|error| {
+                    error.with_weight(T::WeightInfo::batch_update_tools(index as u32 + 1))
+                })?;
+                Self::record_activity(&who, ActivitySubject::Tool(tool_id));
+            }
+
+            Ok(().into())
+        }
+
+        /// Enable or disable a tool.
+        ///
+        /// # Errors
+        /// * `ToolNotFound` - If the tool does not exist
+        /// * `NotServerOwner` - If the caller does not own the tool's server
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::set_tool_active())]
+        pub fn set_tool_active(
+            origin: OriginFor<T>,
+            tool_id: ToolId,
+            active: bool,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let server_id = Self::mutate_owned_tool(tool_id, &who, |tool| {
+                tool.active = active;
+                Ok(())
+            })?;
+
+            Self::record_activity(&who, ActivitySubject::Tool(tool_id));
+            Self::deposit_event(Event::ToolStatusChanged {
+                tool_id,
+                server_id,
+                active,
+            });
+
+            Ok(())
+        }
+
+        /// Remove a tool, including one that is still sealed.
+        ///
+        /// # Errors
+        /// * `ToolNotFound` - If the tool does not exist
+        /// * `NotServerOwner` - If the caller does not own the tool's server
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::remove_tool())]
+        pub fn remove_tool(origin: OriginFor<T>, tool_id: ToolId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let (server_id, name) = match Tools::<T>::get(tool_id) {
+                Some(tool) => (tool.server_id, tool.name),
+                None => {
+                    let sealed = SealedTools::<T>::get(tool_id).ok_or(Error::<T>::ToolNotFound)?;
+                    (sealed.server_id, sealed.name)
+                }
+            };
+            Self::ensure_server_owner(server_id, &who)?;
+
+            Tools::<T>::remove(tool_id);
+            SealedTools::<T>::remove(tool_id);
+            ToolIdByName::<T>::remove(server_id, &name);
+            AccessRules::<T>::remove(AccessTarget::Tool(tool_id));
+            ToolPricing::<T>::remove(tool_id);
+            ToolReputation::<T>::remove(tool_id);
+            ToolsByServer::<T>::mutate(server_id, |tools| tools.retain(|t| *t != tool_id));
+
+            Self::record_activity(&who, ActivitySubject::Tool(tool_id));
+            Self::deposit_event(Event::ToolRemoved { tool_id, server_id });
+
+            Ok(())
+        }
+
+        /// Set what a tool charges for a run.
+        ///
+        /// Requests made afterwards are charged the new price; open requests keep the fee they
+        /// escrowed, and existing subscriptions run until they end.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (must be signed)
+        /// * `tool_id` - The tool to price
+        /// * `pricing` - The new pricing model
+        ///
+        /// # Errors
+        /// * `ToolNotFound` - If the tool does not exist
+        /// * `NotServerOwner` - If the caller does not own the tool's server
+        /// * `InvalidPricing` - If a price is below `MinExecutionFee` or a subscription period is
+        ///   zero
+        #[pallet::call_index(29)]
+        #[pallet::weight(T::WeightInfo::set_tool_pricing())]
+        pub fn set_tool_pricing(
+            origin: OriginFor<T>,
+            tool_id: ToolId,
+            pricing: PricingModel<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let server_id = Tools::<T>::get(tool_id)
+                .ok_or(Error::<T>::ToolNotFound)?
+                .server_id;
+            Self::ensure_server_owner(server_id, &who)?;
+            let valid = match &pricing {
+                PricingModel::Free => true,
+                PricingModel::PerCall(price) => *price >= T::MinExecutionFee::get(),
+                PricingModel::Subscription {
+                    period_blocks,
+                    price,
+                } => !period_blocks.is_zero() && *price >= T::MinExecutionFee::get(),
+            };
+            ensure!(valid, Error::<T>::InvalidPricing);
+
+            ToolPricing::<T>::insert(tool_id, &pricing);
+
+            Self::record_activity(&who, ActivitySubject::Tool(tool_id));
+            Self::deposit_event(Event::ToolPricingSet { tool_id, pricing });
+
+            Ok(())
+        }
+
+        /// Register a tool ahead of its launch, publishing only its name and a commitment to its
+        /// registration.
+        ///
+        /// The tool takes its identifier, name and a slot of the server right away, but is not
+        /// offered to clients until it is revealed with [`Pallet::reveal_tool`].
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (must be signed)
+        /// * `server_id` - The server that will offer the tool
+        /// * `name` - The name to reserve
+        /// * `commitment` - [`Pallet::tool_commitment`] of the registration and a secret salt
+        /// * `reveal_at` - First block at which the registration may be revealed
+        ///
+        /// # Errors
+        /// * `ServerNotFound` - If the server does not exist
+        /// * `NotServerOwner` - If the caller does not own the server
+        /// * `EmptyName` / `InvalidText` - If the name is invalid
+        /// * `InvalidRevealBlock` - If `reveal_at` is not in the future
+        /// * `DuplicateToolName` - If the server already offers a tool with this name
+        /// * `TooManyTools` - If the server already offers `MaxToolsPerServer` tools
+        #[pallet::call_index(27)]
+        #[pallet::weight(T::WeightInfo::register_sealed_tool())]
+        pub fn register_sealed_tool(
+            origin: OriginFor<T>,
+            server_id: ServerId,
+            name: NameOf<T>,
+            commitment: H256,
+            reveal_at: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_server_owner(server_id, &who)?;
+            Self::validate_text(&name, &[])?;
+            ensure!(
+                reveal_at > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::InvalidRevealBlock
+            );
+            ensure!(
+                !ToolIdByName::<T>::contains_key(server_id, &name),
+                Error::<T>::DuplicateToolName
+            );
+
+            let tool_id = NextToolId::<T>::get();
+            ToolsByServer::<T>::try_mutate(server_id, |tools| tools.try_push(tool_id))
+                .map_err(|_| Error::<T>::TooManyTools)?;
+            NextToolId::<T>::put(tool_id.checked_add(1).ok_or(Error::<T>::IdsExhausted)?);
+            ToolIdByName::<T>::insert(server_id, &name, tool_id);
+            SealedTools::<T>::insert(
+                tool_id,
+                SealedTool {
+                    server_id,
+                    name: name.clone(),
+                    commitment,
+                    reveal_at,
+                },
+            );
+
+            Self::record_activity(&who, ActivitySubject::Tool(tool_id));
+            Self::deposit_event(Event::ToolSealed {
+                tool_id,
+                server_id,
+                name,
+                reveal_at,
+            });
+
+            Ok(())
+        }
+
+        /// Reveal the registration of a sealed tool, which is offered from then on.
+        ///
+        /// The tool starts active.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (must be signed)
+        /// * `tool_id` - The sealed tool
+        /// * `registration` - The registration committed to, carrying the reserved name
+        /// * `salt` - The salt committed to
+        ///
+        /// # Errors
+        /// * `ToolNotFound` - If the tool does not exist
+        /// * `ToolNotSealed` - If the tool was already revealed or registered unsealed
+        /// * `NotServerOwner` - If the caller does not own the tool's server
+        /// * `RevealTooEarly` - If the tool's reveal block has not been reached
+        /// * `CommitmentMismatch` - If the registration or salt do not match the commitment
+        /// * `InvalidText` / `InvalidSchema` - If the registration is invalid
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::WeightInfo::reveal_tool())]
+        pub fn reveal_tool(
+            origin: OriginFor<T>,
+            tool_id: ToolId,
+            registration: ToolRegistration<T>,
+            salt: [u8; 32],
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let sealed = SealedTools::<T>::get(tool_id).ok_or_else(|| {
+                if Tools::<T>::contains_key(tool_id) {
+                    Error::<T>::ToolNotSealed
+                } else {
+                    Error::<T>::ToolNotFound
+                }
+            })?;
+            let server_id = sealed.server_id;
+            Self::ensure_server_owner(server_id, &who)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= sealed.reveal_at,
+                Error::<T>::RevealTooEarly
+            );
+            ensure!(
+                registration.name == sealed.name
+                    && Self::tool_commitment(&registration, &salt) == sealed.commitment,
+                Error::<T>::CommitmentMismatch
+            );
+            Self::validate_tool(&registration)?;
+
+            SealedTools::<T>::remove(tool_id);
+            Tools::<T>::insert(tool_id, ToolInfo::new(server_id, registration));
+
+            Self::record_activity(&who, ActivitySubject::Tool(tool_id));
+            Self::deposit_event(Event::ToolRevealed { tool_id, server_id });
+
+            Ok(())
+        }
+
+        /// Add a prompt template offered by a server owned by the caller.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (must be signed)
+        /// * `server_id` - The server offering the prompt
+        /// * `registration` - Name, description, template text, parameter schema and metadata
+        ///
+        /// # Errors
+        /// * `ServerNotFound` - If the server does not exist
+        /// * `NotServerOwner` - If the caller does not own the server
+        /// * `EmptyName` / `InvalidText` / `InvalidTemplate` / `InvalidSchema` / `InvalidCid` -
+        ///   If the registration is invalid
+        /// * `DuplicatePromptName` - If the server already offers a prompt with this name
+        /// * `TooManyPrompts` - If the server already offers `MaxPromptsPerServer` prompts
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::add_prompt().saturating_add(T::OnCidPublished::weight()))]
+        pub fn add_prompt(
+            origin: OriginFor<T>,
+            server_id: ServerId,
+            registration: PromptRegistration<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_server_owner(server_id, &who)?;
+            Self::validate_prompt(&registration)?;
+
+            let prompt_id = Self::insert_prompt(server_id, registration)?;
+
+            Self::record_activity(&who, ActivitySubject::Prompt(prompt_id));
+            Ok(())
+        }
+
+        /// Replace a prompt template.
+        ///
+        /// # Errors
+        /// * `PromptNotFound` - If the prompt does not exist
+        /// * `NotServerOwner` - If the caller does not own the prompt's server
+        /// * `EmptyName` / `InvalidText` / `InvalidTemplate` / `InvalidSchema` / `InvalidCid` -
+        ///   If the registration is invalid
+        /// * `DuplicatePromptName` - If another prompt of the server has this name
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::update_prompt().saturating_add(T::OnCidPublished::weight()))]
+        pub fn update_prompt(
+            origin: OriginFor<T>,
+            prompt_id: PromptId,
+            registration: PromptRegistration<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::validate_prompt(&registration)?;
+
+            let metadata_cid = registration.metadata_cid.clone();
+            let server_id = Prompts::<T>::try_mutate(prompt_id, |prompt| {
+                let prompt = prompt.as_mut().ok_or(Error::<T>::PromptNotFound)?;
+                let server_id = prompt.server_id;
+                Self::ensure_server_owner(server_id, &who)?;
+                if prompt.name != registration.name {
+                    ensure!(
+                        !PromptIdByName::<T>::contains_key(server_id, &registration.name),
+                        Error::<T>::DuplicatePromptName
+                    );
+                    PromptIdByName::<T>::remove(server_id, &prompt.name);
+                    PromptIdByName::<T>::insert(server_id, &registration.name, prompt_id);
+                }
+                *prompt = PromptTemplate::new(server_id, registration);
+                Ok::<_, DispatchError>(server_id)
+            })?;
+            Self::publish_cids(&metadata_cid);
+
+            Self::record_activity(&who, ActivitySubject::Prompt(prompt_id));
+            Self::deposit_event(Event::PromptUpdated {
+                prompt_id,
+                server_id,
+            });
+
+            Ok(())
+        }
+
+        /// Remove a prompt template.
+        ///
+        /// # Errors
+        /// * `PromptNotFound` - If the prompt does not exist
+        /// * `NotServerOwner` - If the caller does not own the prompt's server
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::remove_prompt())]
+        pub fn remove_prompt(origin: OriginFor<T>, prompt_id: PromptId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let prompt = Prompts::<T>::get(prompt_id).ok_or(Error::<T>::PromptNotFound)?;
+            let server_id = prompt.server_id;
+            Self::ensure_server_owner(server_id, &who)?;
+
+            Prompts::<T>::remove(prompt_id);
+            PromptIdByName::<T>::remove(server_id, &prompt.name);
+            PromptsByServer::<T>::mutate(server_id, |prompts| prompts.retain(|p| *p != prompt_id));
+
+            Self::record_activity(&who, ActivitySubject::Prompt(prompt_id));
+            Self::deposit_event(Event::PromptRemoved {
+                prompt_id,
+                server_id,
+            });
+
+            Ok(())
+        }
+
+        /// Add a resource offered by a server owned by the caller.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (must be signed)
+        /// * `server_id` - The server offering the resource
+        /// * `registration` - URI, name, description, MIME type, content CID and metadata
+        ///
+        /// # Errors
+        /// * `ServerNotFound` - If the server does not exist
+        /// * `NotServerOwner` - If the caller does not own the server
+        /// * `EmptyName` / `InvalidText` / `InvalidResource` / `InvalidCid` - If the
+        ///   registration is invalid
+        /// * `DuplicateResourceUri` - If the server already offers a resource with this URI
+        /// * `TooManyResources` - If the server already offers `MaxResourcesPerServer` resources
+        #[pallet::call_index(12)]
+        #[pallet::weight(
+            T::WeightInfo::add_resource().saturating_add(T::OnCidPublished::weight().saturating_mul(2))
+        )]
+        pub fn add_resource(
+            origin: OriginFor<T>,
+            server_id: ServerId,
+            registration: ResourceRegistration<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_server_owner(server_id, &who)?;
+            Self::validate_resource(&registration)?;
+
+            let resource_id = Self::insert_resource(server_id, registration)?;
+
+            Self::record_activity(&who, ActivitySubject::Resource(resource_id));
+            Ok(())
+        }
+
+        /// Replace a resource, e.g. to point it at new content.
+        ///
+        /// A `ResourceChanged` event is emitted for every subscriber of the resource. The call is
+        /// charged for `MaxSubscribersPerResource` subscribers; the difference is refunded.
+        ///
+        /// # Errors
+        /// * `ResourceNotFound` - If the resource does not exist
+        /// * `NotServerOwner` - If the caller does not own the resource's server
+        /// * `EmptyName` / `InvalidText` / `InvalidResource` / `InvalidCid` - If the
+        ///   registration is invalid
+        /// * `DuplicateResourceUri` - If another resource of the server has this URI
+        #[pallet::call_index(13)]
+        #[pallet::weight(
+            T::WeightInfo::update_resource(T::MaxSubscribersPerResource::get())
+                .saturating_add(T::OnCidPublished::weight().saturating_mul(2))
+        )]
+        pub fn update_resource(
+            origin: OriginFor<T>,
+            resource_id: ResourceId,
+            registration: ResourceRegistration<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::validate_resource(&registration)?;
+
+            let cids = Self::resource_cids(&registration);
+            let server_id = Resources::<T>::try_mutate(resource_id, |resource| {
+                let resource = resource.as_mut().ok_or(Error::<T>::ResourceNotFound)?;
+                let server_id = resource.server_id;
+                Self::ensure_server_owner(server_id, &who)?;
+                if resource.uri != registration.uri {
+                    ensure!(
+                        !ResourceIdByUri::<T>::contains_key(server_id, &registration.uri),
+                        Error::<T>::DuplicateResourceUri
+                    );
+                    ResourceIdByUri::<T>::remove(server_id, &resource.uri);
+                    ResourceIdByUri::<T>::insert(server_id, &registration.uri, resource_id);
+                }
+                *resource = ResourceInfo::new(server_id, registration);
+                Ok::<_, DispatchError>(server_id)
+            })?;
+            Self::publish_cids(&cids);
+
+            Self::record_activity(&who, ActivitySubject::Resource(resource_id));
+            let subscribers = ResourceSubscribers::<T>::get(resource_id);
+            for subscriber in &subscribers {
+                Self::deposit_event(Event::ResourceChanged {
+                    resource_id,
+                    subscriber: subscriber.clone(),
+                });
+            }
+            let subscribers_notified = subscribers.len() as u32;
+            Self::deposit_event(Event::ResourceUpdated {
+                resource_id,
+                server_id,
+                subscribers_notified,
+            });
+
+            Ok(Some(
+                T::WeightInfo::update_resource(subscribers_notified)
+                    .saturating_add(T::OnCidPublished::weight().saturating_mul(2)),
+            )
+            .into())
+        }
+
+        /// Remove a resource.
+        ///
+        /// # Errors
+        /// * `ResourceNotFound` - If the resource does not exist
+        /// * `NotServerOwner` - If the caller does not own the resource's server
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::remove_resource())]
+        pub fn remove_resource(origin: OriginFor<T>, resource_id: ResourceId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let resource = Resources::<T>::get(resource_id).ok_or(Error::<T>::ResourceNotFound)?;
+            let server_id = resource.server_id;
+            Self::ensure_server_owner(server_id, &who)?;
+
+            Resources::<T>::remove(resource_id);
+            ResourceIdByUri::<T>::remove(server_id, &resource.uri);
+            AccessRules::<T>::remove(AccessTarget::Resource(resource_id));
+            ResourceSubscribers::<T>::remove(resource_id);
+            ResourcesByServer::<T>::mutate(server_id, |resources| {
+                resources.retain(|r| *r != resource_id)
+            });
+
+            Self::record_activity(&who, ActivitySubject::Resource(resource_id));
+            Self::deposit_event(Event::ResourceRemoved {
+                resource_id,
+                server_id,
+            });
+
+            Ok(())
+        }
+
+        /// Subscribe the caller to changes of a resource.
+        ///
+        /// Every later `update_resource` emits a `ResourceChanged` event for the caller, which
+        /// off-chain MCP bridges turn into `notifications/resources/updated` messages.
+        ///
+        /// # Errors
+        /// * `ResourceNotFound` / `ServerNotFound` - If the resource or its server does not exist
+        /// * `SubscriptionsNotSupported` - If the server does not announce resource subscriptions
+        /// * `AccessDenied` - If the resource's access rule excludes the caller
+        /// * `AlreadySubscribed` - If the caller is already subscribed
+        /// * `TooManySubscribers` - If the resource has `MaxSubscribersPerResource` subscribers
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::subscribe_resource())]
+        pub fn subscribe_resource(origin: OriginFor<T>, resource_id: ResourceId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let resource = Resources::<T>::get(resource_id).ok_or(Error::<T>::ResourceNotFound)?;
+            let server = Servers::<T>::get(resource.server_id).ok_or(Error::<T>::ServerNotFound)?;
+            ensure!(
+                server
+                    .capabilities
+                    .resources
+                    .is_some_and(|resources| resources.subscribe),
+                Error::<T>::SubscriptionsNotSupported
+            );
+            ensure!(
+                server.owner == who || Self::can_access(&who, AccessTarget::Resource(resource_id)),
+                Error::<T>::AccessDenied
+            );
+            ResourceSubscribers::<T>::try_mutate(resource_id, |subscribers| {
+                ensure!(!subscribers.contains(&who), Error::<T>::AlreadySubscribed);
+                subscribers
+                    .try_push(who.clone())
+                    .map_err(|_| Error::<T>::TooManySubscribers)
+            })?;
+            ResourceSubscriptionsByAccount::<T>::insert(&who, resource_id, ());
+
+            Self::record_activity(&who, ActivitySubject::Resource(resource_id));
+            Self::deposit_event(Event::ResourceSubscribed {
+                resource_id,
+                subscriber: who,
+            });
+
+            Ok(())
+        }
+
+        /// Unsubscribe the caller from changes of a resource.
+        ///
+        /// # Errors
+        /// * `NotSubscribed` - If the caller is not subscribed to the resource
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::unsubscribe_resource())]
+        pub fn unsubscribe_resource(
+            origin: OriginFor<T>,
+            resource_id: ResourceId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ResourceSubscribers::<T>::try_mutate_exists(resource_id, |subscribers| {
+                let list = subscribers.as_mut().ok_or(Error::<T>::NotSubscribed)?;
+                let index = list
+                    .iter()
+                    .position(|s| *s == who)
+                    .ok_or(Error::<T>::NotSubscribed)?;
+                list.remove(index);
+                if list.is_empty() {
+                    *subscribers = None;
+                }
+                Ok::<_, Error<T>>(())
+            })?;
+            ResourceSubscriptionsByAccount::<T>::remove(&who, resource_id);
+
+            Self::record_activity(&who, ActivitySubject::Resource(resource_id));
+            Self::deposit_event(Event::ResourceUnsubscribed {
+                resource_id,
+                subscriber: who,
+            });
+
+            Ok(())
+        }
+
+        /// Remove a misbehaving server and burn its owner's deposit.
+        ///
+        /// The server's tools, prompt templates and resources are removed as on
+        /// deregistration. The call is charged for a server offering the maximum number of each;
+        /// the difference is refunded.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the origin is not `SlashOrigin`
+        /// * `ServerNotFound` - If the server does not exist
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::slash_server(
+            T::MaxToolsPerServer::get(),
+            T::MaxPromptsPerServer::get(),
+            T::MaxResourcesPerServer::get(),
+        ))]
+        pub fn slash_server(origin: OriginFor<T>, id: ServerId) -> DispatchResultWithPostInfo {
+            T::SlashOrigin::ensure_origin(origin)?;
+
+            let server = Servers::<T>::get(id).ok_or(Error::<T>::ServerNotFound)?;
+            let amount = Self::burn_deposit(id, &server);
+
+            Self::deposit_event(Event::ServerSlashed {
+                id,
+                owner: server.owner.clone(),
+                amount,
+            });
+            let (tools, prompts, resources) = Self::remove_server(id, server.owner);
+
+            Ok(Some(T::WeightInfo::slash_server(tools, prompts, resources)).into())
+        }
+
+        /// Mark a server as verified at `level`, replacing any previous level.
+        ///
+        /// The verification lasts until it is revoked or the owner replaces the registration.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the origin is not `VerifyOrigin`
+        /// * `ServerNotFound` - If the server does not exist
+        #[pallet::call_index(30)]
+        #[pallet::weight(T::WeightInfo::verify_server())]
+        pub fn verify_server(
+            origin: OriginFor<T>,
+            server_id: ServerId,
+            level: VerificationLevel,
+        ) -> DispatchResult {
+            T::VerifyOrigin::ensure_origin(origin)?;
+            ensure!(
+                Servers::<T>::contains_key(server_id),
+                Error::<T>::ServerNotFound
+            );
+
+            ServerVerification::<T>::insert(server_id, level);
+
+            Self::deposit_event(Event::ServerVerified {
+                id: server_id,
+                level,
+            });
+
+            Ok(())
+        }
+
+        /// Remove the verification of a server.
+        ///
+        /// # Errors
+        /// * `BadOrigin` - If the origin is not `VerifyOrigin`
+        /// * `NotVerified` - If the server is not verified
+        #[pallet::call_index(31)]
+        #[pallet::weight(T::WeightInfo::revoke_verification())]
+        pub fn revoke_verification(origin: OriginFor<T>, server_id: ServerId) -> DispatchResult {
+            T::VerifyOrigin::ensure_origin(origin)?;
+            ServerVerification::<T>::take(server_id).ok_or(Error::<T>::NotVerified)?;
+
+            Self::deposit_event(Event::VerificationRevoked { id: server_id });
+
+            Ok(())
+        }
+
+        /// Ask a server to run one of its tools, escrowing the fee until it answers.
+        ///
+        /// A priced tool charges the fee given by its pricing model, which `max_fee` caps; a tool
+        /// without one is paid the whole `max_fee`. The fee is reserved from the caller and paid
+        /// to the server owner when the output is submitted. If no output arrives within
+        /// `ExecutionTimeout` blocks the caller may cancel and is refunded.
+        ///
+        /// A run that costs nothing, of a free tool or under a subscription, reserves a bond of
+        /// `MinExecutionFee` instead, which is returned to the caller when the request is settled.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (must be signed)
+        /// * `tool_id` - The tool to run
+        /// * `input_cid` - CID of the tool's input on IPFS
+        /// * `max_fee` - The most the caller pays for the run
+        ///
+        /// # Errors
+        /// * `ToolNotFound` / `ServerNotFound` - If the tool or its server does not exist
+        /// * `ToolInactive` / `ServerInactive` - If the tool or its server is out of service
+        /// * `AccessDenied` - If the tool's access rule excludes the caller
+        /// * `InvalidCid` - If the input CID is malformed
+        /// * `FeeTooLow` - If the tool has no pricing model and `max_fee` is below
+        ///   `MinExecutionFee`
+        /// * `PriceAboveMaxFee` - If the tool's price is above `max_fee`
+        /// * `TooManyPendingExecutions` - If the server has `MaxPendingExecutions` open requests
+        /// * `TooManyRequests` - If the caller has `MaxRequestsPerAccount` open requests
+        /// * `ProviderExposureLimit` - If the fees escrowed for the server owner would exceed
+        ///   `ExposureMultiplier` times their server deposits
+        /// * `InsufficientBalance` - If the caller cannot reserve the fee or bond
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::request_tool_execution())]
+        pub fn request_tool_execution(
+            origin: OriginFor<T>,
+            tool_id: ToolId,
+            input_cid: CidOf<T>,
+            #[pallet::compact] max_fee: BalanceOf<T>,
+        ) -> DispatchResult {
+            let requester = ensure_signed(origin)?;
+            Self::do_request_tool_execution(
+                requester,
+                tool_id,
+                input_cid,
+                max_fee,
+                PaymentAsset::Native,
+            )
+        }
+
+        /// Post the output of a requested run and claim its fee.
+        ///
+        /// The request is settled and removed; the output CID is only kept in the event. The run
+        /// is counted in the requester's usage of the tool, and starts or renews their
+        /// subscription if it was bought with the request.
+        ///
+        /// # Errors
+        /// * `ExecutionNotFound` - If the request does not exist or was already settled
+        /// * `ServerNotFound` - If the request's server was removed
+        /// * `NotServerOwner` - If the caller does not own the request's server
+        /// * `ExecutionTimedOut` - If the request's deadline has passed
+        /// * `InvalidCid` - If the output CID is malformed
+        #[pallet::call_index(17)]
+        #[pallet::weight(
+            T::WeightInfo::submit_execution_result().saturating_add(T::OnCallSettled::weight())
+        )]
+        pub fn submit_execution_result(
+            origin: OriginFor<T>,
+            request_id: RequestId,
+            output_cid: CidOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let request =
+                ExecutionRequests::<T>::get(request_id).ok_or(Error::<T>::ExecutionNotFound)?;
+            Self::ensure_server_owner(request.server_id, &who)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() <= request.deadline,
+                Error::<T>::ExecutionTimedOut
+            );
+            Self::validate_cids([&output_cid])?;
+
+            let subscription = Self::settle(request_id, &request);
+            let credits = CreditEscrow::<T>::take(request_id);
+            Self::release(&request.requester, &who, request.fee, credits)?;
+            // Only a fee paid to someone else earns a rating
+            if !request.fee.is_zero() && request.requester != who {
+                RatingCredits::<T>::mutate(request.tool_id, &request.requester, |credits| {
+                    *credits = credits.saturating_add(1)
+                });
+            }
+            let now = frame_system::Pallet::<T>::block_number();
+            let subscribed_until =
+                Usage::<T>::mutate(request.tool_id, &request.requester, |usage| {
+                    usage.calls = usage.calls.saturating_add(1);
+                    usage.paid = usage.paid.saturating_add(request.fee);
+                    // A subscription bought while another one was pending extends it
+                    let until = subscription.map(|period| {
+                        usage
+                            .subscribed_until
+                            .map_or(now, |until| until.max(now))
+                            .saturating_add(period)
+                    });
+                    if until.is_some() {
+                        usage.subscribed_until = until;
+                    }
+                    until
+                });
+            if let Some(until) = subscribed_until {
+                ToolSubscriptionsByAccount::<T>::insert(&request.requester, request.tool_id, ());
+                Self::deposit_event(Event::Subscribed {
+                    tool_id: request.tool_id,
+                    subscriber: request.requester.clone(),
+                    until,
+                });
+            }
+            Self::record_activity(&who, ActivitySubject::Execution(request_id));
+            T::OnCallSettled::on_call_settled(&SettledCall {
+                request_id,
+                tool_id: request.tool_id,
+                server_id: request.server_id,
+                requester: request.requester,
+                fee: request.fee,
+                paid_to: Some(who),
+            });
+
+            Self::deposit_event(Event::ExecutionCompleted {
+                request_id,
+                output_cid,
+                fee: request.fee,
+                asset: Self::asset_of(credits),
+            });
+
+            Ok(())
+        }
+
+        /// Drop a request that was not answered before its deadline, refunding the fee to the
+        /// requester.
+        ///
+        /// Anyone may clear an expired request, so an unanswered request does not hold a slot of
+        /// the server's queue after its requester lost interest in it.
+        ///
+        /// # Errors
+        /// * `ExecutionNotFound` - If the request does not exist or was already settled
+        /// * `ExecutionNotExpired` - If the request's deadline has not passed yet
+        #[pallet::call_index(18)]
+        #[pallet::weight(
+            T::WeightInfo::cancel_execution().saturating_add(T::OnCallSettled::weight())
+        )]
+        pub fn cancel_execution(origin: OriginFor<T>, request_id: RequestId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let request =
+                ExecutionRequests::<T>::get(request_id).ok_or(Error::<T>::ExecutionNotFound)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() > request.deadline,
+                Error::<T>::ExecutionNotExpired
+            );
+
+            let credits = Self::refund(request_id, &request)?;
+            Self::record_activity(&who, ActivitySubject::Execution(request_id));
+            T::OnCallSettled::on_call_settled(&SettledCall {
+                request_id,
+                tool_id: request.tool_id,
+                server_id: request.server_id,
+                requester: request.requester,
+                fee: request.fee,
+                paid_to: None,
+            });
+
+            Self::deposit_event(Event::ExecutionExpired {
+                request_id,
+                refunded: request.fee,
+                asset: Self::asset_of(credits),
+            });
+
+            Ok(())
+        }
+
+        /// Ask a server to sample a language model, as MCP clients do for servers announcing
+        /// the sampling capability.
+        ///
+        /// The request waits for the server owner to post the completion, which an off-chain
+        /// bridge typically obtains from a model backend. It can be answered within
+        /// `ExecutionTimeout` blocks.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (must be signed)
+        /// * `server_id` - The server to ask
+        /// * `prompt_cid` - CID of the messages to complete on IPFS
+        /// * `model_preferences` - The model the caller would like to be sampled
+        ///
+        /// # Errors
+        /// * `ServerNotFound` - If the server does not exist
+        /// * `ServerInactive` - If the server is out of service
+        /// * `SamplingNotSupported` - If the server does not announce sampling
+        /// * `InvalidCid` - If the prompt CID is malformed
+        /// * `EmptyName` / `InvalidText` - If a model hint is empty or not valid UTF-8
+        /// * `TooManyPendingSamplingRequests` - If the server has `MaxPendingSamplingRequests`
+        ///   open requests
+        #[pallet::call_index(36)]
+        #[pallet::weight(T::WeightInfo::create_sampling_request())]
+        pub fn create_sampling_request(
+            origin: OriginFor<T>,
+            server_id: ServerId,
+            prompt_cid: CidOf<T>,
+            model_preferences: ModelPreferences<T>,
+        ) -> DispatchResult {
+            let requester = ensure_signed(origin)?;
+
+            let server = Servers::<T>::get(server_id).ok_or(Error::<T>::ServerNotFound)?;
+            ensure!(server.active, Error::<T>::ServerInactive);
+            ensure!(
+                server.capabilities.sampling,
+                Error::<T>::SamplingNotSupported
+            );
+            Self::validate_cids([&prompt_cid])?;
+            for hint in &model_preferences.hints {
+                Self::validate_text(hint, &[])?;
+            }
+
+            let request_id = NextSamplingRequestId::<T>::get();
+            PendingSamplingRequests::<T>::try_mutate(server_id, |pending| {
+                pending.try_push(request_id)
+            })
+            .map_err(|_| Error::<T>::TooManyPendingSamplingRequests)?;
+            NextSamplingRequestId::<T>::put(
+                request_id.checked_add(1).ok_or(Error::<T>::IdsExhausted)?,
+            );
+            let deadline = frame_system::Pallet::<T>::block_number()
+                .saturating_add(T::ExecutionTimeout::get());
+            SamplingRequests::<T>::insert(
+                request_id,
+                SamplingRequest {
+                    requester: requester.clone(),
+                    server_id,
+                    prompt_cid: prompt_cid.clone(),
+                    model_preferences: model_preferences.clone(),
+                    deadline,
+                },
+            );
+
+            Self::record_activity(&requester, ActivitySubject::Sampling(request_id));
+            Self::deposit_event(Event::SamplingRequested {
+                request_id,
+                server_id,
+                requester,
+                prompt_cid,
+                model_preferences,
+            });
+
+            Ok(())
+        }
+
+        /// Post the completion of a sampling request.
+        ///
+        /// The request is removed and the result appended to the server's sampling history.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (must be signed by the server owner)
+        /// * `request_id` - The request to answer
+        /// * `completion_cid` - CID of the completion on IPFS, an MCP `CreateMessageResult`
+        /// * `usage_stats` - Tokens used for the completion
+        ///
+        /// # Errors
+        /// * `SamplingRequestNotFound` - If the request does not exist or was already settled
+        /// * `ServerNotFound` - If the request's server was removed
+        /// * `NotServerOwner` - If the caller does not own the request's server
+        /// * `SamplingTimedOut` - If the request's deadline has passed
+        /// * `InvalidCid` - If the completion CID is malformed
+        #[pallet::call_index(37)]
+        #[pallet::weight(T::WeightInfo::submit_sampling_result())]
+        pub fn submit_sampling_result(
+            origin: OriginFor<T>,
+            request_id: SamplingRequestId,
+            completion_cid: CidOf<T>,
+            usage_stats: UsageStats,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let request = SamplingRequests::<T>::get(request_id)
+                .ok_or(Error::<T>::SamplingRequestNotFound)?;
+            Self::ensure_server_owner(request.server_id, &who)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(now <= request.deadline, Error::<T>::SamplingTimedOut);
+            Self::validate_cids([&completion_cid])?;
+
+            Self::settle_sampling(request_id, request.server_id);
+            let result = SamplingResult {
+                request_id,
+                requester: request.requester,
+                completion_cid: completion_cid.clone(),
+                usage: usage_stats,
+                completed_at: now,
+            };
+            SamplingHistory::<T>::mutate(request.server_id, |history| {
+                // Drops the oldest result of a full history; nothing is kept if the bound is zero
+                let _ = history.force_insert_keep_right(history.len(), result);
+            });
+
+            Self::record_activity(&who, ActivitySubject::Sampling(request_id));
+            Self::deposit_event(Event::SamplingCompleted {
+                request_id,
+                server_id: request.server_id,
+                completion_cid,
+                usage: usage_stats,
+            });
+
+            Ok(())
+        }
+
+        /// Withdraw a sampling request, or drop anyone's request once its deadline has passed.
+        ///
+        /// # Errors
+        /// * `SamplingRequestNotFound` - If the request does not exist or was already settled
+        /// * `NotRequester` - If the caller did not make the request and its deadline has not
+        ///   passed
+        #[pallet::call_index(38)]
+        #[pallet::weight(T::WeightInfo::cancel_sampling_request())]
+        pub fn cancel_sampling_request(
+            origin: OriginFor<T>,
+            request_id: SamplingRequestId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let request = SamplingRequests::<T>::get(request_id)
+                .ok_or(Error::<T>::SamplingRequestNotFound)?;
+            ensure!(
+                request.requester == who
+                    || frame_system::Pallet::<T>::block_number() > request.deadline,
+                Error::<T>::NotRequester
+            );
+
+            Self::settle_sampling(request_id, request.server_id);
+            Self::record_activity(&who, ActivitySubject::Sampling(request_id));
+            Self::deposit_event(Event::SamplingCancelled { request_id });
+
+            Ok(())
+        }
+
+        /// Rate a tool after its server completed a paid run requested by the caller.
+        ///
+        /// Every completed run that paid a fee allows one rating; runs that cost nothing and runs
+        /// requested by the server owner do not. The score is added to the reputation of the tool
+        /// and of its server.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (must be signed)
+        /// * `tool_id` - The tool to rate
+        /// * `score` - From 1 (worst) to 5 (best)
+        /// * `comment_cid` - CID of a written comment on IPFS
+        ///
+        /// # Errors
+        /// * `ToolNotFound` - If the tool does not exist
+        /// * `InvalidScore` - If the score is not between 1 and 5
+        /// * `InvalidCid` - If the comment CID is malformed
+        /// * `CannotRateOwnTool` - If the caller owns the server offering the tool
+        /// * `NoSettledExecution` - If the caller has no completed run of the tool left to rate
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::rate_tool())]
+        pub fn rate_tool(
+            origin: OriginFor<T>,
+            tool_id: ToolId,
+            score: u8,
+            comment_cid: Option<CidOf<T>>,
+        ) -> DispatchResult {
+            let rater = ensure_signed(origin)?;
+
+            let server_id = Tools::<T>::get(tool_id)
+                .ok_or(Error::<T>::ToolNotFound)?
+                .server_id;
+            let owner = Servers::<T>::get(server_id).map(|server| server.owner);
+            ensure!(
+                owner.as_ref() != Some(&rater),
+                Error::<T>::CannotRateOwnTool
+            );
+            ensure!(RATING_RANGE.contains(&score), Error::<T>::InvalidScore);
+            Self::validate_cids(&comment_cid)?;
+            RatingCredits::<T>::try_mutate_exists(tool_id, &rater, |credits| {
+                let left = credits
+                    .unwrap_or_default()
+                    .checked_sub(1)
+                    .ok_or(Error::<T>::NoSettledExecution)?;
+                *credits = (left > 0).then_some(left);
+                Ok::<_, Error<T>>(())
+            })?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let half_life = T::ReputationHalfLife::get();
+            ToolReputation::<T>::mutate(tool_id, |reputation| {
+                reputation
+                    .get_or_insert_default()
+                    .record(score, now, half_life)
+            });
+            ServerReputation::<T>::mutate(server_id, |reputation| {
+                reputation
+                    .get_or_insert_default()
+                    .record(score, now, half_life)
+            });
+
+            Self::record_activity(&rater, ActivitySubject::Tool(tool_id));
+            Self::deposit_event(Event::ToolRated {
+                tool_id,
+                server_id,
+                rater,
+                score,
+                comment_cid,
+            });
+
+            Ok(())
+        }
+
+        /// Replace the access rule of a tool or resource offered by a server owned by the caller.
+        ///
+        /// Setting `Public` lifts every restriction.
+        ///
+        /// # Errors
+        /// * `ToolNotFound` / `ResourceNotFound` - If the target does not exist
+        /// * `NotServerOwner` - If the caller does not own the target's server
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::set_access_policy())]
+        pub fn set_access_policy(
+            origin: OriginFor<T>,
+            target: AccessTarget,
+            rule: AccessRule<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_target_owner(target, &who)?;
+
+            if rule == AccessRule::Public {
+                AccessRules::<T>::remove(target);
+            } else {
+                AccessRules::<T>::insert(target, &rule);
+            }
+
+            Self::record_activity(&who, target.into());
+            Self::deposit_event(Event::AccessPolicySet { target, rule });
+
+            Ok(())
+        }
+
+        /// Add an account to the allowlist of a tool or resource.
+        ///
+        /// # Errors
+        /// * `ToolNotFound` / `ResourceNotFound` - If the target does not exist
+        /// * `NotServerOwner` - If the caller does not own the target's server
+        /// * `NotAnAllowlist` - If the target is not restricted to an allowlist
+        /// * `AlreadyAllowlisted` - If the account is already on the allowlist
+        /// * `TooManyAllowlisted` - If the allowlist holds `MaxAllowlistLength` accounts
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::grant_access())]
+        pub fn grant_access(
+            origin: OriginFor<T>,
+            target: AccessTarget,
+            account: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_target_owner(target, &who)?;
+
+            Self::mutate_allowlist(target, |accounts| {
+                ensure!(!accounts.contains(&account), Error::<T>::AlreadyAllowlisted);
+                accounts
+                    .try_push(account.clone())
+                    .map_err(|_| Error::<T>::TooManyAllowlisted.into())
+            })?;
+
+            Self::record_activity(&who, target.into());
+            Self::deposit_event(Event::AccessGranted {
+                target,
+                who: account,
+            });
+
+            Ok(())
+        }
+
+        /// Remove an account from the allowlist of a tool or resource.
+        ///
+        /// # Errors
+        /// * `ToolNotFound` / `ResourceNotFound` - If the target does not exist
+        /// * `NotServerOwner` - If the caller does not own the target's server
+        /// * `NotAnAllowlist` - If the target is not restricted to an allowlist
+        /// * `NotAllowlisted` - If the account is not on the allowlist
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::revoke_access())]
+        pub fn revoke_access(
+            origin: OriginFor<T>,
+            target: AccessTarget,
+            account: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_target_owner(target, &who)?;
+
+            Self::mutate_allowlist(target, |accounts| {
+                let position = accounts
+                    .iter()
+                    .position(|a| *a == account)
+                    .ok_or(Error::<T>::NotAllowlisted)?;
+                accounts.remove(position);
+                Ok(())
+            })?;
+
+            Self::record_activity(&who, target.into());
+            Self::deposit_event(Event::AccessRevoked {
+                target,
+                who: account,
+            });
+
+            Ok(())
+        }
+
+        /// Submit probe results for a set of servers.
+        ///
+        /// Results for servers that were removed since the probe are ignored. A server that
+        /// reaches `MaxConsecutiveFailures` failed probes in a row is deactivated; failures are
+        /// counted at most once per `ProbeInterval`, however many reporters probe the server. A reporter may
+        /// submit at most one batch per `ProbeInterval` blocks.
+        ///
+        /// # Errors
+        /// * `NotReporter` - If the caller is not a health reporter
+        /// * `ReportTooEarly` - If the caller already reported within the probe interval
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::report_server_health(reports.len() as u32))]
+        pub fn report_server_health(
+            origin: OriginFor<T>,
+            reports: BoundedVec<HealthReport, T::MaxHealthReports>,
+        ) -> DispatchResult {
+            let reporter = ensure_signed(origin)?;
+            ensure!(
+                T::HealthReporters::contains(&reporter),
+                Error::<T>::NotReporter
+            );
+
+            let now = frame_system::Pallet::<T>::block_number();
+            if let Some(last) = LastHealthReport::<T>::get(&reporter) {
+                ensure!(
+                    now >= last.saturating_add(T::ProbeInterval::get()),
+                    Error::<T>::ReportTooEarly
+                );
+            }
+            LastHealthReport::<T>::insert(&reporter, now);
+
+            let mut applied = 0u32;
+            for report in reports {
+                let Some(mut server) = Servers::<T>::get(report.server_id) else {
+                    continue;
+                };
+                let mut health = ServerHealth::<T>::get(report.server_id).unwrap_or_default();
+                health.record(
+                    report.healthy,
+                    report.latency_ms,
+                    now,
+                    T::ProbeInterval::get(),
+                );
+                if server.active && health.consecutive_failures >= T::MaxConsecutiveFailures::get()
+                {
+                    server.active = false;
+                    Servers::<T>::insert(report.server_id, server);
+                    Self::deposit_event(Event::ServerDeactivated {
+                        id: report.server_id,
+                    });
+                }
+                ServerHealth::<T>::insert(report.server_id, health);
+                applied += 1;
+            }
+
+            Self::deposit_event(Event::ServerHealthReported { reporter, applied });
+
+            Ok(())
+        }
+    }
+
+    /// Helper functions for server registrations.
+    impl<T: Config> Pallet<T> {
+        /// Register a server owned by `owner`, holding its deposit in `asset`.
+        fn do_register_server(
+            owner: T::AccountId,
+            registration: ServerRegistration<T>,
+            asset: PaymentAsset,
+        ) -> DispatchResult {
+            Self::validate_registration(&registration)?;
+
+            let id = Self::insert_server(&owner, registration, asset)?;
+
+            Self::record_activity(&owner, ActivitySubject::Server(id));
+            Ok(())
+        }
+
+        /// Register a server owned by `owner` with its catalog, holding its deposit in `asset`.
+        fn do_register_server_with_catalog(
+            owner: T::AccountId,
+            registration: ServerRegistration<T>,
+            tools: BoundedVec<ToolRegistration<T>, T::MaxToolsPerServer>,
+            prompts: BoundedVec<PromptRegistration<T>, T::MaxPromptsPerServer>,
+            resources: BoundedVec<ResourceRegistration<T>, T::MaxResourcesPerServer>,
+            asset: PaymentAsset,
+        ) -> DispatchResultWithPostInfo {
+            let (tool_count, prompt_count, resource_count) = (
+                tools.len() as u32,
+                prompts.len() as u32,
+                resources.len() as u32,
+            );
+            Self::validate_registration(&registration)
+                .and_then(|_| tools.iter().try_for_each(Self::validate_tool))
+                .and_then(|_| prompts.iter().try_for_each(Self::validate_prompt))
+                .and_then(|_| resources.iter().try_for_each(Self::validate_resource))
+                .map_err(|error| error.with_weight(Self::catalog_weight(0, 0, 0, 0)))?;
+
+            let cids = registration.metadata_cid.iter().count()
+                + prompts
+                    .iter()
+                    .filter(|prompt| prompt.metadata_cid.is_some())
+                    .count()
+                + resources
+                    .iter()
+                    .map(|resource| 1 + resource.metadata_cid.iter().count())
+                    .sum::<usize>();
+
+            let id = Self::insert_server(&owner, registration, asset)?;
+            for tool in tools {
+                Self::insert_tool(id, tool)?;
+            }
+            for prompt in prompts {
+                Self::insert_prompt(id, prompt)?;
+            }
+            for resource in resources {
+                Self::insert_resource(id, resource)?;
+            }
+
+            Self::record_activity(&owner, ActivitySubject::Server(id));
+            Ok(Some(Self::catalog_weight(
+                tool_count,
+                prompt_count,
+                resource_count,
+                cids as u32,
+            ))
+            .into())
+        }
+
+        /// Store a validated server registration owned by `owner`, holding its deposit in
+        /// `asset`. Returns the identifier assigned to the server.
+        fn insert_server(
+            owner: &T::AccountId,
+            registration: ServerRegistration<T>,
+            asset: PaymentAsset,
+        ) -> Result<ServerId, DispatchError> {
+            let id = NextServerId::<T>::get();
+            ServersByOwner::<T>::try_mutate(owner, |servers| servers.try_push(id))
+                .map_err(|_| Error::<T>::TooManyServers)?;
+            NextServerId::<T>::put(id.checked_add(1).ok_or(Error::<T>::IdsExhausted)?);
+            let deposit = T::ServerDeposit::get();
+            if let Some(credits) = Self::hold(owner, deposit, asset)? {
+                CreditDeposits::<T>::insert(id, credits);
+            }
+
+            let name = registration.name.clone();
+            let metadata_cid = registration.metadata_cid.clone();
+            let now = frame_system::Pallet::<T>::block_number();
+            Servers::<T>::insert(
+                id,
+                ServerInfo::new(owner.clone(), deposit, registration, now),
+            );
+
+            T::OnServerRegistered::on_server_registered(id, owner);
+            Self::publish_cids(&metadata_cid);
+            Self::deposit_event(Event::ServerRegistered {
+                id,
+                owner: owner.clone(),
+                name,
+            });
+            Ok(id)
+        }
+
+        /// Weight of `register_server_with_catalog` for a catalog of `tools`, `prompts` and
+        /// `resources` publishing `cids` CIDs.
+        fn catalog_weight(tools: u32, prompts: u32, resources: u32, cids: u32) -> Weight {
+            T::WeightInfo::register_server_with_catalog(tools, prompts, resources)
+                .saturating_add(T::OnServerRegistered::weight())
+                .saturating_add(T::OnCidPublished::weight().saturating_mul(cids.into()))
+        }
+
+        /// Add a validated tool registration to a server, returning the tool's identifier.
+        fn insert_tool(
+            server_id: ServerId,
+            registration: ToolRegistration<T>,
+        ) -> Result<ToolId, DispatchError> {
+            ensure!(
+                !ToolIdByName::<T>::contains_key(server_id, &registration.name),
+                Error::<T>::DuplicateToolName
+            );
+
+            let tool_id = NextToolId::<T>::get();
+            ToolsByServer::<T>::try_mutate(server_id, |tools| tools.try_push(tool_id))
+                .map_err(|_| Error::<T>::TooManyTools)?;
+            NextToolId::<T>::put(tool_id.checked_add(1).ok_or(Error::<T>::IdsExhausted)?);
+            ToolIdByName::<T>::insert(server_id, &registration.name, tool_id);
+
+            let name = registration.name.clone();
+            Tools::<T>::insert(tool_id, ToolInfo::new(server_id, registration));
+
+            Self::deposit_event(Event::ToolRegistered {
+                tool_id,
+                server_id,
+                name,
+            });
+            Ok(tool_id)
+        }
+
+        /// Replace the registration of a tool whose server `who` owns with a validated one.
+        fn replace_tool(
+            tool_id: ToolId,
+            who: &T::AccountId,
+            registration: ToolRegistration<T>,
+        ) -> DispatchResult {
+            let server_id = Self::mutate_owned_tool(tool_id, who, |tool| {
+                if tool.name != registration.name {
+                    ensure!(
+                        !ToolIdByName::<T>::contains_key(tool.server_id, &registration.name),
+                        Error::<T>::DuplicateToolName
+                    );
+                    ToolIdByName::<T>::remove(tool.server_id, &tool.name);
+                    ToolIdByName::<T>::insert(tool.server_id, &registration.name, tool_id);
+                }
+                tool.apply(registration);
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::ToolUpdated { tool_id, server_id });
+            Ok(())
+        }
+
+        /// Add a validated prompt registration to a server, returning the prompt's identifier.
+        fn insert_prompt(
+            server_id: ServerId,
+            registration: PromptRegistration<T>,
+        ) -> Result<PromptId, DispatchError> {
+            ensure!(
+                !PromptIdByName::<T>::contains_key(server_id, &registration.name),
+                Error::<T>::DuplicatePromptName
+            );
+
+            let prompt_id = NextPromptId::<T>::get();
+            PromptsByServer::<T>::try_mutate(server_id, |prompts| prompts.try_push(prompt_id))
+                .map_err(|_| Error::<T>::TooManyPrompts)?;
+            NextPromptId::<T>::put(prompt_id.checked_add(1).ok_or(Error::<T>::IdsExhausted)?);
+            PromptIdByName::<T>::insert(server_id, &registration.name, prompt_id);
+
+            let name = registration.name.clone();
+            let metadata_cid = registration.metadata_cid.clone();
+            Prompts::<T>::insert(prompt_id, PromptTemplate::new(server_id, registration));
+            Self::publish_cids(&metadata_cid);
+
+            Self::deposit_event(Event::PromptAdded {
+                prompt_id,
+                server_id,
+                name,
+            });
+            Ok(prompt_id)
+        }
+
+        /// Add a validated resource registration to a server, returning the resource's
+        /// identifier.
+        fn insert_resource(
+            server_id: ServerId,
+            registration: ResourceRegistration<T>,
+        ) -> Result<ResourceId, DispatchError> {
+            ensure!(
+                !ResourceIdByUri::<T>::contains_key(server_id, &registration.uri),
+                Error::<T>::DuplicateResourceUri
+            );
+
+            let resource_id = NextResourceId::<T>::get();
+            ResourcesByServer::<T>::try_mutate(server_id, |resources| {
+                resources.try_push(resource_id)
+            })
+            .map_err(|_| Error::<T>::TooManyResources)?;
+            NextResourceId::<T>::put(resource_id.checked_add(1).ok_or(Error::<T>::IdsExhausted)?);
+            ResourceIdByUri::<T>::insert(server_id, &registration.uri, resource_id);
+
+            let uri = registration.uri.clone();
+            let cids = Self::resource_cids(&registration);
+            Resources::<T>::insert(resource_id, ResourceInfo::new(server_id, registration));
+            Self::publish_cids(&cids);
+
+            Self::deposit_event(Event::ResourceAdded {
+                resource_id,
+                server_id,
+                uri,
+            });
+            Ok(resource_id)
+        }
+
+        /// Remove the servers whose lease ran out before `now`, releasing their deposits.
+        ///
+        /// Servers are checked in identifier order from `ExpiryCursor` on, wrapping around, each at
+        /// most once, for as long as `limit` leaves room to remove a server at every per-server
+        /// limit. Returns the weight used.
+        pub(crate) fn expire_servers(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+            let worst = T::WeightInfo::expire_server(
+                T::MaxToolsPerServer::get(),
+                T::MaxPromptsPerServer::get(),
+                T::MaxResourcesPerServer::get(),
+            );
+            let mut used = T::DbWeight::get().reads(1);
+            let end = NextServerId::<T>::get();
+            let cursor_weight = T::DbWeight::get().reads_writes(1, 1);
+            if end == 0 || limit.any_lt(used.saturating_add(cursor_weight).saturating_add(worst)) {
+                return used;
+            }
+            used.saturating_accrue(cursor_weight);
+
+            let mut cursor = ExpiryCursor::<T>::get() % end;
+            for _ in 0..end {
+                if limit.any_lt(used.saturating_add(worst)) {
+                    break;
+                }
+                let id = cursor;
+                cursor = id.saturating_add(1) % end;
+                let Some(server) = Servers::<T>::get(id).filter(|server| server.expires_at < now)
+                else {
+                    used.saturating_accrue(T::WeightInfo::check_expiry());
+                    continue;
+                };
+
+                if let Err(e) = Self::release_deposit(id, &server) {
+                    log::error!(target: "runtime::mcp", "Deposit of server {id} not released: {e:?}");
+                }
+                Self::deposit_event(Event::ServerExpired {
+                    id,
+                    owner: server.owner.clone(),
+                });
+                let (tools, prompts, resources) = Self::remove_server(id, server.owner);
+                used.saturating_accrue(T::WeightInfo::expire_server(tools, prompts, resources));
+            }
+            ExpiryCursor::<T>::put(cursor);
+
+            used
+        }
+
+        /// Remove a server with its tools, prompt templates and resources, returning how many of
+        /// each were removed. The deposit must already have been released or slashed.
+        fn remove_server(id: ServerId, owner: T::AccountId) -> (u32, u32, u32) {
+            let tools = ToolsByServer::<T>::take(id);
+            for tool_id in &tools {
+                let name = Tools::<T>::take(tool_id)
+                    .map(|tool| tool.name)
+                    .or_else(|| SealedTools::<T>::take(tool_id).map(|sealed| sealed.name));
+                if let Some(name) = name {
+                    ToolIdByName::<T>::remove(id, name);
+                }
+                AccessRules::<T>::remove(AccessTarget::Tool(*tool_id));
+                ToolPricing::<T>::remove(tool_id);
+                ToolReputation::<T>::remove(tool_id);
+            }
+            let prompts = PromptsByServer::<T>::take(id);
+            for prompt_id in &prompts {
+                if let Some(prompt) = Prompts::<T>::take(prompt_id) {
+                    PromptIdByName::<T>::remove(id, prompt.name);
+                }
+            }
+            let resources = ResourcesByServer::<T>::take(id);
+            for resource_id in &resources {
+                if let Some(resource) = Resources::<T>::take(resource_id) {
+                    ResourceIdByUri::<T>::remove(id, resource.uri);
+                }
+                AccessRules::<T>::remove(AccessTarget::Resource(*resource_id));
+                ResourceSubscribers::<T>::remove(resource_id);
+            }
+            Servers::<T>::remove(id);
+            ServerHealth::<T>::remove(id);
+            ServerVerification::<T>::remove(id);
+            // Requests to a removed server can only be refunded, so they no longer count
+            // against its owner
+            ServerEscrow::<T>::remove(id);
+            ServerReputation::<T>::remove(id);
+            SamplingHistory::<T>::remove(id);
+            ServersByOwner::<T>::mutate(&owner, |servers| servers.retain(|s| *s != id));
+
+            let (tools_removed, prompts_removed, resources_removed) = (
+                tools.len() as u32,
+                prompts.len() as u32,
+                resources.len() as u32,
+            );
+            Self::deposit_event(Event::ServerDeregistered {
+                id,
+                owner,
+                tools_removed,
+                prompts_removed,
+                resources_removed,
+            });
+
+            (tools_removed, prompts_removed, resources_removed)
+        }
+
+        /// Append an extrinsic of `who` acting on `subject` to their activity history.
+        fn record_activity(who: &T::AccountId, subject: ActivitySubject) {
+            let record = ActivityRecord {
+                block: frame_system::Pallet::<T>::block_number(),
+                extrinsic_index: frame_system::Pallet::<T>::extrinsic_index().unwrap_or_default(),
+                subject,
+            };
+            ActivityByAccount::<T>::mutate(who, |activity| {
+                // Drops the oldest record of a full history; nothing is kept if the bound is zero
+                let _ = activity.force_insert_keep_right(activity.len(), record);
+            });
+        }
+
+        /// Open an execution request for `requester`, escrowing the fee in `asset`.
+        fn do_request_tool_execution(
+            requester: T::AccountId,
+            tool_id: ToolId,
+            input_cid: CidOf<T>,
+            max_fee: BalanceOf<T>,
+            asset: PaymentAsset,
+        ) -> DispatchResult {
+            let tool = Tools::<T>::get(tool_id).ok_or(Error::<T>::ToolNotFound)?;
+            ensure!(tool.active, Error::<T>::ToolInactive);
+            let server_id = tool.server_id;
+            let server = Servers::<T>::get(server_id).ok_or(Error::<T>::ServerNotFound)?;
+            ensure!(server.active, Error::<T>::ServerInactive);
+            Self::ensure_can_access(&requester, tool_id)?;
+            Self::validate_cids([&input_cid])?;
+            let (fee, subscription) = match Self::charge(tool_id, &requester) {
+                Some((fee, subscription)) => {
+                    ensure!(fee <= max_fee, Error::<T>::PriceAboveMaxFee);
+                    (fee, subscription)
+                }
+                None => {
+                    ensure!(max_fee >= T::MinExecutionFee::get(), Error::<T>::FeeTooLow);
+                    (max_fee, None)
+                }
+            };
+            let (escrowed, cap) = Self::provider_exposure(&server.owner);
+            ensure!(
+                escrowed.saturating_add(fee) <= cap,
+                Error::<T>::ProviderExposureLimit
+            );
+
+            let request_id = NextRequestId::<T>::get();
+            PendingExecutions::<T>::try_mutate(server_id, |pending| pending.try_push(request_id))
+                .map_err(|_| Error::<T>::TooManyPendingExecutions)?;
+            RequestsByAccount::<T>::try_mutate(&requester, |requests| {
+                requests.try_push(request_id)
+            })
+            .map_err(|_| Error::<T>::TooManyRequests)?;
+            NextRequestId::<T>::put(request_id.checked_add(1).ok_or(Error::<T>::IdsExhausted)?);
+            if let Some(credits) = Self::hold(&requester, fee, asset)? {
+                CreditEscrow::<T>::insert(request_id, credits);
+            }
+            if fee.is_zero() {
+                let bond = T::MinExecutionFee::get();
+                T::Currency::reserve(&requester, bond)?;
+                ExecutionBonds::<T>::insert(request_id, bond);
+            }
+            ServerEscrow::<T>::mutate(server_id, |escrow| *escrow = escrow.saturating_add(fee));
+            if let Some(period) = subscription {
+                SubscriptionPurchases::<T>::insert(request_id, period);
+            }
+
+            let deadline = frame_system::Pallet::<T>::block_number()
+                .saturating_add(T::ExecutionTimeout::get());
+            ExecutionRequests::<T>::insert(
+                request_id,
+                ExecutionRequest {
+                    requester: requester.clone(),
+                    tool_id,
+                    server_id,
+                    input_cid: input_cid.clone(),
+                    fee,
+                    deadline,
+                },
+            );
+
+            Self::record_activity(&requester, ActivitySubject::Execution(request_id));
+            Self::deposit_event(Event::ExecutionRequested {
+                request_id,
+                tool_id,
+                server_id,
+                requester,
+                input_cid,
+                fee,
+                asset,
+            });
+
+            Ok(())
+        }
+
+        /// The account holding the credits paid for deposits and fees.
+        pub fn credit_account() -> T::AccountId {
+            T::PalletId::get().into_account_truncating()
+        }
+
+        /// Credits worth `amount` of the native currency at `NativePerCredit`, rounded up.
+        pub fn credits_for(amount: BalanceOf<T>) -> BalanceOf<T> {
+            let rate = T::NativePerCredit::get();
+            amount.saturating_add(rate.saturating_sub(One::one())) / rate
+        }
+
+        /// Hold `amount` from `who` in `asset`, returning the credits moved into the credit
+        /// account if it was paid in credits.
+        pub(crate) fn hold(
+            who: &T::AccountId,
+            amount: BalanceOf<T>,
+            asset: PaymentAsset,
+        ) -> Result<Option<BalanceOf<T>>, DispatchError> {
+            match asset {
+                PaymentAsset::Native => {
+                    T::Currency::reserve(who, amount)?;
+                    Ok(None)
+                }
+                PaymentAsset::Credits => {
+                    let credits = Self::credits_for(amount);
+                    T::Assets::transfer(
+                        T::CreditAsset::get(),
+                        who,
+                        &Self::credit_account(),
+                        credits,
+                        Preservation::Expendable,
+                    )?;
+                    Ok(Some(credits))
+                }
+            }
+        }
+
+        /// Pay an amount `payer` holds to `payee`, who may be the payer. `credits` are the
+        /// credits held for it if it was paid in credits; otherwise it is reserved from `payer`.
+        fn release(
+            payer: &T::AccountId,
+            payee: &T::AccountId,
+            amount: BalanceOf<T>,
+            credits: Option<BalanceOf<T>>,
+        ) -> DispatchResult {
+            match credits {
+                Some(credits) => T::Assets::transfer(
+                    T::CreditAsset::get(),
+                    &Self::credit_account(),
+                    payee,
+                    credits,
+                    Preservation::Expendable,
+                )
+                .map(drop),
+                None if payer == payee => {
+                    T::Currency::unreserve(payer, amount);
+                    Ok(())
+                }
+                None => T::Currency::repatriate_reserved(payer, payee, amount, BalanceStatus::Free)
+                    .map(drop),
+            }
+        }
+
+        /// The asset an amount was paid in, given the credits held for it.
+        fn asset_of(credits: Option<BalanceOf<T>>) -> PaymentAsset {
+            credits.map_or(PaymentAsset::Native, |_| PaymentAsset::Credits)
+        }
+
+        /// Return the deposit of server `id` to its owner.
+        fn release_deposit(id: ServerId, server: &ServerInfo<T>) -> DispatchResult {
+            let credits = CreditDeposits::<T>::take(id);
+            Self::release(&server.owner, &server.owner, server.deposit, credits)
+        }
+
+        /// Burn the deposit of server `id`, returning the native amount it was worth.
+        fn burn_deposit(id: ServerId, server: &ServerInfo<T>) -> BalanceOf<T> {
+            match CreditDeposits::<T>::take(id) {
+                Some(credits) => {
+                    let _ = T::Assets::burn_from(
+                        T::CreditAsset::get(),
+                        &Self::credit_account(),
+                        credits,
+                        Preservation::Expendable,
+                        Precision::BestEffort,
+                        Fortitude::Force,
+                    );
+                    server.deposit
+                }
+                // The imbalance is dropped at the end of the statement, burning the funds
+                None => T::Currency::slash_reserved(&server.owner, server.deposit)
+                    .0
+                    .peek(),
+            }
+        }
+
+        /// Remove a completed or cancelled request from storage and return its bond to the
+        /// requester, giving the subscription period it bought, if any. Requests outlive their
+        /// server, so an expired request can be refunded after the server is gone.
+        fn settle(
+            request_id: RequestId,
+            request: &ExecutionRequest<T>,
+        ) -> Option<BlockNumberFor<T>> {
+            ExecutionRequests::<T>::remove(request_id);
+            PendingExecutions::<T>::mutate(request.server_id, |pending| {
+                pending.retain(|r| *r != request_id)
+            });
+            RequestsByAccount::<T>::mutate_exists(&request.requester, |requests| {
+                if let Some(list) = requests {
+                    list.retain(|r| *r != request_id);
+                    if list.is_empty() {
+                        *requests = None;
+                    }
+                }
+            });
+            ServerEscrow::<T>::mutate_exists(request.server_id, |escrow| {
+                *escrow = escrow
+                    .map(|escrow| escrow.saturating_sub(request.fee))
+                    .filter(|escrow| !escrow.is_zero());
+            });
+            if let Some(bond) = ExecutionBonds::<T>::take(request_id) {
+                T::Currency::unreserve(&request.requester, bond);
+            }
+            SubscriptionPurchases::<T>::take(request_id)
+        }
+
+        /// Remove a request that will not be answered from storage, returning its fee and bond to
+        /// the requester. Returns the credits the fee was paid with, if any.
+        pub(crate) fn refund(
+            request_id: RequestId,
+            request: &ExecutionRequest<T>,
+        ) -> Result<Option<BalanceOf<T>>, DispatchError> {
+            // A subscription is only bought by a completed run
+            Self::settle(request_id, request);
+            let credits = CreditEscrow::<T>::take(request_id);
+            Self::release(&request.requester, &request.requester, request.fee, credits)?;
+            Ok(credits)
+        }
+
+        /// Remove an answered or cancelled sampling request from storage. Like execution
+        /// requests, sampling requests outlive their server.
+        fn settle_sampling(request_id: SamplingRequestId, server_id: ServerId) {
+            SamplingRequests::<T>::remove(request_id);
+            PendingSamplingRequests::<T>::mutate(server_id, |pending| {
+                pending.retain(|r| *r != request_id)
+            });
+        }
+
+        /// The fee `who` is charged for a run of a priced tool at the current block, with the
+        /// subscription period the run buys. `None` if the tool has no pricing model.
+        fn charge(
+            tool_id: ToolId,
+            who: &T::AccountId,
+        ) -> Option<(BalanceOf<T>, Option<BlockNumberFor<T>>)> {
+            let charge = match ToolPricing::<T>::get(tool_id)? {
+                PricingModel::Free => (Zero::zero(), None),
+                PricingModel::PerCall(price) => (price, None),
+                PricingModel::Subscription {
+                    period_blocks,
+                    price,
+                } => {
+                    let now = frame_system::Pallet::<T>::block_number();
+                    let subscribed = Usage::<T>::get(tool_id, who)
+                        .subscribed_until
+                        .is_some_and(|until| until >= now);
+                    if subscribed {
+                        (Zero::zero(), None)
+                    } else {
+                        (price, Some(period_blocks))
+                    }
+                }
+            };
+            Some(charge)
+        }
+
+        /// Whether `who` passes the access rule of a tool or resource.
+        ///
+        /// The owner of the offering server is not special-cased here; see
+        /// [`Self::ensure_can_access`].
+        pub fn can_access(who: &T::AccountId, target: AccessTarget) -> bool {
+            match AccessRules::<T>::get(target) {
+                AccessRule::Public => true,
+                AccessRule::Allowlist(accounts) => accounts.contains(who),
+                AccessRule::TokenGated { min_balance } => {
+                    T::Currency::total_balance(who) >= min_balance
+                }
+            }
+        }
+
+        /// The fees escrowed in unsettled requests to the servers of `provider`, and the most
+        /// that may be escrowed: `ExposureMultiplier` times the deposits of those servers.
+        pub fn provider_exposure(provider: &T::AccountId) -> (BalanceOf<T>, BalanceOf<T>) {
+            let (escrowed, deposits) = ServersByOwner::<T>::get(provider).iter().fold(
+                (BalanceOf::<T>::zero(), BalanceOf::<T>::zero()),
+                |(escrowed, deposits), id| {
+                    let deposit = Servers::<T>::get(id).map_or_else(Zero::zero, |s| s.deposit);
+                    (
+                        escrowed.saturating_add(ServerEscrow::<T>::get(id)),
+                        deposits.saturating_add(deposit),
+                    )
+                },
+            );
+            (
+                escrowed,
+                deposits.saturating_mul(T::ExposureMultiplier::get().into()),
+            )
+        }
+
+        /// Ensure `who` may use a tool: either it owns the tool's server or passes its rule.
+        pub fn ensure_can_access(who: &T::AccountId, tool_id: ToolId) -> DispatchResult {
+            let tool = Tools::<T>::get(tool_id).ok_or(Error::<T>::ToolNotFound)?;
+            let owner = Servers::<T>::get(tool.server_id).map(|server| server.owner);
+            ensure!(
+                owner.as_ref() == Some(who) || Self::can_access(who, AccessTarget::Tool(tool_id)),
+                Error::<T>::AccessDenied
+            );
+            Ok(())
+        }
+
+        /// Ensure the tool or resource exists and its server is owned by `who`.
+        fn ensure_target_owner(target: AccessTarget, who: &T::AccountId) -> DispatchResult {
+            let server_id = match target {
+                AccessTarget::Tool(id) => {
+                    Tools::<T>::get(id)
+                        .ok_or(Error::<T>::ToolNotFound)?
+                        .server_id
+                }
+                AccessTarget::Resource(id) => {
+                    Resources::<T>::get(id)
+                        .ok_or(Error::<T>::ResourceNotFound)?
+                        .server_id
+                }
+            };
+            Self::ensure_server_owner(server_id, who)
+        }
+
+        /// Apply `f` to the allowlist of a tool or resource restricted to one.
+        fn mutate_allowlist(
+            target: AccessTarget,
+            f: impl FnOnce(&mut BoundedVec<T::AccountId, T::MaxAllowlistLength>) -> DispatchResult,
+        ) -> DispatchResult {
+            AccessRules::<T>::try_mutate(target, |rule| match rule {
+                AccessRule::Allowlist(accounts) => f(accounts),
+                _ => Err(Error::<T>::NotAnAllowlist.into()),
+            })
+        }
+
+        /// Check the owner-provided fields of a registration.
+        pub fn validate_registration(registration: &ServerRegistration<T>) -> DispatchResult {
+            Self::validate_text(&registration.name, &registration.description)?;
+
+            let transport = &registration.transport;
+            if let Some(url) = transport.url() {
+                let valid = transport
+                    .schemes()
+                    .iter()
+                    .any(|scheme| url.len() > scheme.len() && url.starts_with(scheme.as_bytes()));
+                ensure!(
+                    valid && core::str::from_utf8(url).is_ok(),
+                    Error::<T>::InvalidUrl
+                );
+                // Health reporters probe public servers from validator nodes
+                ensure!(
+                    registration.private_network || crate::url::is_public_host(url),
+                    Error::<T>::PrivateHost
+                );
+            }
+            let policy_cid = registration
+                .access_policy
+                .as_ref()
+                .map(|policy| &policy.cid);
+            Self::validate_cids(registration.metadata_cid.iter().chain(policy_cid))
+        }
+
+        /// Check the owner-provided fields of a tool registration.
+        pub fn validate_tool(registration: &ToolRegistration<T>) -> DispatchResult {
+            Self::validate_text(&registration.name, &registration.description)?;
+            for schema in
+                core::iter::once(&registration.input_schema).chain(&registration.output_schema)
+            {
+                Self::validate_schema(schema)?;
+            }
+            Ok(())
+        }
+
+        /// The commitment a sealed tool is registered with: the BLAKE2-256 hash of the
+        /// SCALE-encoded registration followed by the salt. The salt keeps a short registration
+        /// from being guessed before it is revealed.
+        pub fn tool_commitment(registration: &ToolRegistration<T>, salt: &[u8; 32]) -> H256 {
+            (registration, salt).using_encoded(|bytes| sp_io::hashing::blake2_256(bytes).into())
+        }
+
+        /// JSON Schemas must be non-empty UTF-8; their content is checked off-chain.
+        fn validate_schema(schema: &[u8]) -> DispatchResult {
+            ensure!(
+                !schema.is_empty() && core::str::from_utf8(schema).is_ok(),
+                Error::<T>::InvalidSchema
+            );
+            Ok(())
+        }
+
+        /// Check the owner-provided fields of a prompt template.
+        pub fn validate_prompt(registration: &PromptRegistration<T>) -> DispatchResult {
+            Self::validate_text(&registration.name, &registration.description)?;
+            ensure!(
+                !registration.template.is_empty() && template::is_valid(&registration.template),
+                Error::<T>::InvalidTemplate
+            );
+            if let Some(schema) = &registration.parameter_schema {
+                Self::validate_schema(schema)?;
+            }
+            Self::validate_cids(&registration.metadata_cid)
+        }
+
+        /// Check the owner-provided fields of a resource.
+        pub fn validate_resource(registration: &ResourceRegistration<T>) -> DispatchResult {
+            Self::validate_text(&registration.name, &registration.description)?;
+            let is_text = |bytes: &[u8]| !bytes.is_empty() && core::str::from_utf8(bytes).is_ok();
+            ensure!(
+                is_text(&registration.uri)
+                    && registration
+                        .mime_type
+                        .as_deref()
+                        .is_none_or(|mime| is_text(mime)),
+                Error::<T>::InvalidResource
+            );
+            Self::validate_cids(
+                core::iter::once(&registration.content_cid).chain(&registration.metadata_cid),
+            )
+        }
+
+        /// The content CID of a resource followed by its metadata CID, if any.
+        fn resource_cids(registration: &ResourceRegistration<T>) -> Vec<CidOf<T>> {
+            core::iter::once(&registration.content_cid)
+                .chain(&registration.metadata_cid)
+                .cloned()
+                .collect()
+        }
+
+        /// Pass every given CID to the `OnCidPublished` hook.
+        fn publish_cids<'a>(cids: impl IntoIterator<Item = &'a CidOf<T>>) {
+            for cid in cids {
+                T::OnCidPublished::on_cid_published(cid);
+            }
+        }
+
+        /// Ensure every given CID is well-formed.
+        fn validate_cids<'a>(cids: impl IntoIterator<Item = &'a CidOf<T>>) -> DispatchResult {
+            for cid in cids {
+                ensure!(crate::cid::is_valid(cid), Error::<T>::InvalidCid);
+            }
+            Ok(())
+        }
+
+        /// Names must be non-empty and, like descriptions, valid UTF-8.
+        fn validate_text(name: &[u8], description: &[u8]) -> DispatchResult {
+            ensure!(!name.is_empty(), Error::<T>::EmptyName);
+            ensure!(
+                core::str::from_utf8(name).is_ok() && core::str::from_utf8(description).is_ok(),
+                Error::<T>::InvalidText
+            );
+            Ok(())
+        }
+
+        /// Ensure the server exists and is owned by `who`.
+        fn ensure_server_owner(id: ServerId, who: &T::AccountId) -> DispatchResult {
+            let server = Servers::<T>::get(id).ok_or(Error::<T>::ServerNotFound)?;
+            ensure!(&server.owner == who, Error::<T>::NotServerOwner);
+            Ok(())
+        }
+
+        /// Apply `f` to a tool whose server is owned by `who`, returning the tool's server.
+        fn mutate_owned_tool(
+            tool_id: ToolId,
+            who: &T::AccountId,
+            f: impl FnOnce(&mut ToolInfo<T>) -> DispatchResult,
+        ) -> Result<ServerId, DispatchError> {
+            Tools::<T>::try_mutate(tool_id, |tool| {
+                let tool = tool.as_mut().ok_or(Error::<T>::ToolNotFound)?;
+                Self::ensure_server_owner(tool.server_id, who)?;
+                f(tool)?;
+                Ok(tool.server_id)
+            })
+        }
+
+        /// Probe the next batch of servers and submit the results, if this node holds a
+        /// reporter key.
+        ///
+        /// Rounds continue where the previous one stopped, so every server is probed in turn
+        /// even when more than `MaxHealthReports` are registered.
+        fn probe_and_report() -> Result<(), &'static str> {
+            let public = <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic::all()
+                .into_iter()
+                .map(|key| {
+                    let generic: <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::GenericPublic =
+                        key.into();
+                    let public: T::Public = generic.into();
+                    public
+                })
+                .find(|public| T::HealthReporters::contains(&public.clone().into_account()))
+                .ok_or("no local reporter key")?;
+
+            let cursor = StorageValueRef::persistent(PROBE_CURSOR_KEY);
+            let start = match cursor.get::<ServerId>() {
+                Ok(start) => start.unwrap_or_default(),
+                Err(StorageRetrievalError::Undecodable) => 0,
+            };
+            let end = NextServerId::<T>::get();
+            let reports: Vec<HealthReport> = (start.min(end)..end)
+                .chain(0..start.min(end))
+                .filter_map(|id| Servers::<T>::get(id).map(|server| (id, server)))
+                .filter(|(_, server)| server.active && !server.private_network)
+                .filter_map(|(id, server)| {
+                    // Servers registered before hosts were checked may still point inwards
+                    let url = server
+                        .transport
+                        .url()
+                        .filter(|url| crate::url::is_public_host(url))?;
+                    let latency_ms = Self::probe(url);
+                    Some(HealthReport {
+                        server_id: id,
+                        healthy: latency_ms.is_some(),
+                        latency_ms: latency_ms.unwrap_or_default(),
+                    })
+                })
+                .take(T::MaxHealthReports::get() as usize)
+                .collect();
+            let Some(last) = reports.last() else {
+                return Ok(());
+            };
+            let next = last.server_id.saturating_add(1);
+            if let Err(MutateStorageError::ConcurrentModification(_)) =
+                cursor.mutate::<_, (), _>(|_| Ok(next))
+            {
+                return Err("probe cursor modified concurrently");
+            }
+            let reports: BoundedVec<_, T::MaxHealthReports> = reports
+                .try_into()
+                .map_err(|_| "more reports than MaxHealthReports")?;
+
+            let (_, result) = Signer::<T, T::AuthorityId>::any_account()
+                .with_filter(vec![public])
+                .send_signed_transaction(|_| Call::report_server_health {
+                    reports: reports.clone(),
+                })
+                .ok_or("no signing account")?;
+            result.map_err(|_| "failed to submit transaction")
+        }
+
+        /// Probe one endpoint, returning the round-trip time in milliseconds on success.
+        ///
+        /// The offchain HTTP client cannot open WebSockets, so `ws(s)://` endpoints are requested
+        /// over `http(s)://` instead. Any response below 500 counts as a success: MCP endpoints
+        /// answer a bare `GET` with an error or a stream, but they do answer.
+        fn probe(url: &[u8]) -> Option<u32> {
+            let url = core::str::from_utf8(url).ok()?;
+            let target = if let Some(rest) = url.strip_prefix("ws://") {
+                format!("http://{rest}")
+            } else if let Some(rest) = url.strip_prefix("wss://") {
+                format!("https://{rest}")
+            } else {
+                String::from(url)
+            };
+
+            let started = sp_io::offchain::timestamp();
+            let deadline = started.add(Duration::from_millis(T::ProbeTimeout::get()));
+            let pending = http::Request::get(&target).deadline(deadline).send().ok()?;
+            let response = pending.try_wait(deadline).ok()?.ok()?;
+            if response.code >= 500 {
+                return None;
+            }
+
+            let elapsed = sp_io::offchain::timestamp().diff(&started).millis();
+            Some(elapsed.min(u32::MAX as u64) as u32)
+        }
+
+        /// Apply `f` to a server owned by `who`, returning its result.
+        fn mutate_owned_server<R>(
+            id: ServerId,
+            who: &T::AccountId,
+            f: impl FnOnce(&mut ServerInfo<T>) -> Result<R, DispatchError>,
+        ) -> Result<R, DispatchError> {
+            Servers::<T>::try_mutate(id, |server| {
+                let server = server.as_mut().ok_or(Error::<T>::ServerNotFound)?;
+                ensure!(&server.owner == who, Error::<T>::NotServerOwner);
+                f(server)
+            })
+        }
+    }
+
+    /// Consistency checks of the pallet's storage, run by try-runtime after every block and in
+    /// the tests.
+    #[cfg(any(feature = "try-runtime", test))]
+    impl<T: Config> Pallet<T> {
+        /// Check that the indexes agree with the records they index.
+        pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+            let next_server_id = NextServerId::<T>::get();
+            ensure!(
+                ExpiryCursor::<T>::get() <= next_server_id,
+                "Expiry cursor is past the last server"
+            );
+            let mut servers = 0usize;
+            for (id, server) in Servers::<T>::iter() {
+                servers += 1;
+                ensure!(id < next_server_id, "Server identifier was never assigned");
+                ensure!(
+                    ServersByOwner::<T>::get(&server.owner).contains(&id),
+                    "Server is not listed under its owner"
+                );
+                let escrowed = ExecutionRequests::<T>::iter_values()
+                    .filter(|request| request.server_id == id)
+                    .fold(BalanceOf::<T>::zero(), |total, request| {
+                        total.saturating_add(request.fee)
+                    });
+                ensure!(
+                    ServerEscrow::<T>::get(id) == escrowed,
+                    "Escrow does not match the fees of the server's requests"
+                );
+            }
+            let listed = ServersByOwner::<T>::iter().try_fold(0usize, |listed, (owner, ids)| {
+                for id in &ids {
+                    let server = Servers::<T>::get(id).ok_or("Owner lists a missing server")?;
+                    ensure!(
+                        server.owner == owner,
+                        "Owner lists another account's server"
+                    );
+                }
+                Ok::<_, sp_runtime::TryRuntimeError>(listed + ids.len())
+            })?;
+            ensure!(listed == servers, "Servers are listed more than once");
+
+            let next_tool_id = NextToolId::<T>::get();
+            for (tool_id, tool) in Tools::<T>::iter() {
+                ensure!(tool_id < next_tool_id, "Tool identifier was never assigned");
+                ensure!(
+                    !SealedTools::<T>::contains_key(tool_id),
+                    "Tool is both sealed and revealed"
+                );
+                Self::check_offering(
+                    tool.server_id,
+                    ToolsByServer::<T>::get(tool.server_id).contains(&tool_id),
+                )?;
+                ensure!(
+                    ToolIdByName::<T>::get(tool.server_id, &tool.name) == Some(tool_id),
+                    "Tool name does not resolve to the tool"
+                );
+            }
+            for (tool_id, sealed) in SealedTools::<T>::iter() {
+                Self::check_offering(
+                    sealed.server_id,
+                    ToolsByServer::<T>::get(sealed.server_id).contains(&tool_id),
+                )?;
+                ensure!(
+                    ToolIdByName::<T>::get(sealed.server_id, &sealed.name) == Some(tool_id),
+                    "Sealed tool name does not resolve to the tool"
+                );
+            }
+            for id in ServerVerification::<T>::iter_keys() {
+                ensure!(Servers::<T>::contains_key(id), "Missing server is verified");
+            }
+            for tool_id in ToolPricing::<T>::iter_keys() {
+                ensure!(Tools::<T>::contains_key(tool_id), "Missing tool is priced");
+            }
+            for request_id in SubscriptionPurchases::<T>::iter_keys() {
+                ensure!(
+                    ExecutionRequests::<T>::contains_key(request_id),
+                    "Settled request buys a subscription"
+                );
+            }
+            for request_id in CreditEscrow::<T>::iter_keys() {
+                ensure!(
+                    ExecutionRequests::<T>::contains_key(request_id),
+                    "Credits are held for a settled request"
+                );
+            }
+            for request_id in ExecutionBonds::<T>::iter_keys() {
+                ensure!(
+                    ExecutionRequests::<T>::contains_key(request_id),
+                    "A bond is held for a settled request"
+                );
+            }
+            for id in CreditDeposits::<T>::iter_keys() {
+                ensure!(
+                    Servers::<T>::contains_key(id),
+                    "Credits are held for a missing server"
+                );
+            }
+            for (request_id, request) in SamplingRequests::<T>::iter() {
+                ensure!(
+                    request_id < NextSamplingRequestId::<T>::get(),
+                    "Sampling request identifier was never assigned"
+                );
+                ensure!(
+                    PendingSamplingRequests::<T>::get(request.server_id).contains(&request_id),
+                    "Sampling request is not pending for its server"
+                );
+            }
+            for ids in PendingSamplingRequests::<T>::iter_values() {
+                for request_id in ids {
+                    ensure!(
+                        SamplingRequests::<T>::contains_key(request_id),
+                        "Server lists a settled sampling request"
+                    );
+                }
+            }
+            for id in SamplingHistory::<T>::iter_keys() {
+                ensure!(
+                    Servers::<T>::contains_key(id),
+                    "Missing server has a sampling history"
+                );
+            }
+            for (server_id, tools) in ToolsByServer::<T>::iter() {
+                ensure!(
+                    Servers::<T>::contains_key(server_id),
+                    "Missing server lists tools"
+                );
+                for tool_id in tools {
+                    ensure!(
+                        Tools::<T>::contains_key(tool_id)
+                            || SealedTools::<T>::contains_key(tool_id),
+                        "Server lists a missing tool"
+                    );
+                }
+            }
+
+            for (prompt_id, prompt) in Prompts::<T>::iter() {
+                ensure!(
+                    prompt_id < NextPromptId::<T>::get(),
+                    "Prompt identifier was never assigned"
+                );
+                Self::check_offering(
+                    prompt.server_id,
+                    PromptsByServer::<T>::get(prompt.server_id).contains(&prompt_id),
+                )?;
+                ensure!(
+                    PromptIdByName::<T>::get(prompt.server_id, &prompt.name) == Some(prompt_id),
+                    "Prompt name does not resolve to the prompt"
+                );
+            }
+            for (resource_id, resource) in Resources::<T>::iter() {
+                ensure!(
+                    resource_id < NextResourceId::<T>::get(),
+                    "Resource identifier was never assigned"
+                );
+                Self::check_offering(
+                    resource.server_id,
+                    ResourcesByServer::<T>::get(resource.server_id).contains(&resource_id),
+                )?;
+                ensure!(
+                    ResourceIdByUri::<T>::get(resource.server_id, &resource.uri)
+                        == Some(resource_id),
+                    "Resource URI does not resolve to the resource"
+                );
+            }
+            for (resource_id, subscribers) in ResourceSubscribers::<T>::iter() {
+                ensure!(
+                    Resources::<T>::contains_key(resource_id),
+                    "Missing resource has subscribers"
+                );
+                for who in subscribers {
+                    ensure!(
+                        ResourceSubscriptionsByAccount::<T>::contains_key(&who, resource_id),
+                        "Resource subscriber is not indexed"
+                    );
+                }
+            }
+            for (who, resource_id) in ResourceSubscriptionsByAccount::<T>::iter_keys() {
+                ensure!(
+                    !Resources::<T>::contains_key(resource_id)
+                        || ResourceSubscribers::<T>::get(resource_id).contains(&who),
+                    "Indexed resource subscription does not exist"
+                );
+            }
+            for (who, tool_id) in ToolSubscriptionsByAccount::<T>::iter_keys() {
+                ensure!(
+                    Usage::<T>::get(tool_id, &who).subscribed_until.is_some(),
+                    "Indexed tool subscription does not exist"
+                );
+            }
+            for (request_id, request) in ExecutionRequests::<T>::iter() {
+                ensure!(
+                    request_id < NextRequestId::<T>::get(),
+                    "Execution request identifier was never assigned"
+                );
+                ensure!(
+                    RequestsByAccount::<T>::get(&request.requester).contains(&request_id),
+                    "Request is not indexed by its requester"
+                );
+            }
+            for (who, requests) in RequestsByAccount::<T>::iter() {
+                ensure!(!requests.is_empty(), "Empty request index is stored");
+                for request_id in requests {
+                    ensure!(
+                        ExecutionRequests::<T>::get(request_id)
+                            .is_some_and(|request| request.requester == who),
+                        "Indexed request was not made by the account"
+                    );
+                }
+            }
+
+            Ok(())
+        }
+
+        /// An offering's server must exist and list it.
+        fn check_offering(
+            server_id: ServerId,
+            listed: bool,
+        ) -> Result<(), sp_runtime::TryRuntimeError> {
+            ensure!(
+                Servers::<T>::contains_key(server_id),
+                "Offering of a missing server"
+            );
+            ensure!(listed, "Offering is not listed by its server");
+            Ok(())
+        }
+    }
+
+    /// Read-only queries backing the `McpApi` runtime API.
+    impl<T: Config> Pallet<T> {
+        /// Servers with an identifier of at least `start`, in identifier order, at most `limit`.
+        ///
+        /// With `min_reputation` (in hundredths of a point, see [`ReputationScore::average`]),
+        /// only servers whose reputation reaches it are listed; unrated servers are left out.
+        /// Likewise, with `min_verification` only servers verified at least at that level are.
+        pub fn list_servers(
+            start: ServerId,
+            limit: u32,
+            min_reputation: Option<u32>,
+            min_verification: Option<VerificationLevel>,
+        ) -> Vec<(ServerId, ServerInfo<T>)> {
+            (start..NextServerId::<T>::get())
+                .filter(|id| {
+                    min_reputation.is_none_or(|min| {
+                        Self::server_reputation(*id).is_some_and(|average| average >= min)
+                    })
+                })
+                .filter(|id| {
+                    min_verification.is_none_or(|min| {
+                        ServerVerification::<T>::get(id).is_some_and(|level| level >= min)
+                    })
+                })
+                .filter_map(|id| Servers::<T>::get(id).map(|server| (id, server)))
+                .take(limit as usize)
+                .collect()
+        }
+
+        /// Average rating of a server in hundredths of a point, or `None` if it has no ratings
+        /// that still count.
+        pub fn server_reputation(server_id: ServerId) -> Option<u32> {
+            ServerReputation::<T>::get(server_id).and_then(Self::current_average)
+        }
+
+        /// Average rating of a tool in hundredths of a point, or `None` if it has no ratings
+        /// that still count.
+        pub fn tool_reputation(tool_id: ToolId) -> Option<u32> {
+            ToolReputation::<T>::get(tool_id).and_then(Self::current_average)
+        }
+
+        /// Average of a reputation decayed to the current block.
+        fn current_average(mut reputation: ReputationScore<BlockNumberFor<T>>) -> Option<u32> {
+            reputation.decay(
+                frame_system::Pallet::<T>::block_number(),
+                T::ReputationHalfLife::get(),
+            );
+            reputation.average()
+        }
+
+        /// Servers owned by `owner`, in registration order.
+        pub fn servers_by_owner(owner: &T::AccountId) -> Vec<(ServerId, ServerInfo<T>)> {
+            ServersByOwner::<T>::get(owner)
+                .into_iter()
+                .filter_map(|id| Servers::<T>::get(id).map(|server| (id, server)))
+                .collect()
+        }
+
+        /// The servers and tools `who` owns, their subscriptions, the unsettled execution
+        /// requests they made or have to answer, and their latest activity.
+        pub fn account_overview(who: &T::AccountId) -> AccountOverview<T> {
+            let servers = Self::servers_by_owner(who);
+            let tools = servers
+                .iter()
+                .flat_map(|(id, _)| Self::tools_by_server(*id))
+                .collect();
+            let now = frame_system::Pallet::<T>::block_number();
+            let tool_subscriptions = ToolSubscriptionsByAccount::<T>::iter_key_prefix(who)
+                .filter_map(|tool_id| {
+                    Usage::<T>::get(tool_id, who)
+                        .subscribed_until
+                        .filter(|until| *until >= now)
+                        .map(|until| (tool_id, until))
+                })
+                .collect();
+            let resource_subscriptions = ResourceSubscriptionsByAccount::<T>::iter_key_prefix(who)
+                .filter(|id| Resources::<T>::contains_key(id))
+                .collect();
+            let requested = RequestsByAccount::<T>::get(who)
+                .into_iter()
+                .filter_map(|id| ExecutionRequests::<T>::get(id).map(|request| (id, request)))
+                .collect();
+            let assigned = servers
+                .iter()
+                .flat_map(|(id, _)| PendingExecutions::<T>::get(id))
+                .filter_map(|id| ExecutionRequests::<T>::get(id).map(|request| (id, request)))
+                .collect();
+
+            AccountOverview {
+                servers,
+                tools,
+                tool_subscriptions,
+                resource_subscriptions,
+                requested,
+                assigned,
+                activity: ActivityByAccount::<T>::get(who).into_inner(),
+            }
+        }
+
+        /// The identifiers the next registrations and requests of each kind will be assigned.
+        pub fn next_ids() -> NextIds {
+            NextIds {
+                server: NextServerId::<T>::get(),
+                tool: NextToolId::<T>::get(),
+                prompt: NextPromptId::<T>::get(),
+                resource: NextResourceId::<T>::get(),
+                request: NextRequestId::<T>::get(),
+                sampling_request: NextSamplingRequestId::<T>::get(),
+            }
+        }
+
+        /// Unanswered sampling requests addressed to a server, oldest first.
+        pub fn pending_sampling_requests(
+            server_id: ServerId,
+        ) -> Vec<(SamplingRequestId, SamplingRequest<T>)> {
+            PendingSamplingRequests::<T>::get(server_id)
+                .into_iter()
+                .filter_map(|id| SamplingRequests::<T>::get(id).map(|request| (id, request)))
+                .collect()
+        }
+
+        /// Tools offered by a server, in registration order.
+        pub fn tools_by_server(server_id: ServerId) -> Vec<(ToolId, ToolInfo<T>)> {
+            ToolsByServer::<T>::get(server_id)
+                .into_iter()
+                .filter_map(|id| Tools::<T>::get(id).map(|tool| (id, tool)))
+                .collect()
+        }
+
+        /// Prompt templates offered by a server, in registration order.
+        pub fn prompts_by_server(server_id: ServerId) -> Vec<(PromptId, PromptTemplate<T>)> {
+            PromptsByServer::<T>::get(server_id)
+                .into_iter()
+                .filter_map(|id| Prompts::<T>::get(id).map(|prompt| (id, prompt)))
+                .collect()
+        }
+
+        /// Resources offered by a server, in registration order.
+        pub fn resources_by_server(server_id: ServerId) -> Vec<(ResourceId, ResourceInfo<T>)> {
+            ResourcesByServer::<T>::get(server_id)
+                .into_iter()
+                .filter_map(|id| Resources::<T>::get(id).map(|resource| (id, resource)))
+                .collect()
+        }
+
+        /// Active servers announcing at least the `required` capabilities, in identifier order.
+        ///
+        /// With `min_protocol_version`, only servers implementing that revision or a newer one
+        /// are returned.
+        pub fn negotiate_capabilities(
+            required: &ServerCapabilities,
+            min_protocol_version: Option<ProtocolVersion>,
+        ) -> Vec<ServerId> {
+            (0..NextServerId::<T>::get())
+                .filter(|id| {
+                    Servers::<T>::get(id).is_some_and(|server| {
+                        server.active
+                            && server.capabilities.satisfies(required)
+                            && min_protocol_version.is_none_or(|min| server.protocol_version >= min)
+                    })
+                })
+                .collect()
+        }
+
+        /// Render a prompt template with the parameters in `params_json`, a JSON object checked
+        /// against the prompt's parameter schema. See [`crate::template`].
+        pub fn render_prompt(
+            prompt_id: PromptId,
+            params_json: &[u8],
+        ) -> Result<Vec<u8>, RenderError> {
+            let prompt = Prompts::<T>::get(prompt_id).ok_or(RenderError::PromptNotFound)?;
+            template::render(
+                &prompt.template,
+                prompt.parameter_schema.as_ref().map(|schema| &schema[..]),
+                params_json,
+            )
+        }
+
+        /// The fee `who` would pay for a run of a tool at the current block: the price from its
+        /// pricing model, or `MinExecutionFee` if it accepts offers. `None` if the tool does not
+        /// exist.
+        pub fn quote(tool_id: ToolId, who: &T::AccountId) -> Option<BalanceOf<T>> {
+            Tools::<T>::contains_key(tool_id).then(|| {
+                Self::charge(tool_id, who).map_or_else(T::MinExecutionFee::get, |(fee, _)| fee)
+            })
+        }
+
+        /// Capabilities announced by a server.
+        pub fn server_capabilities(server_id: ServerId) -> Option<ServerCapabilities> {
+            Servers::<T>::get(server_id).map(|server| server.capabilities)
+        }
+
+        /// Verification level of a server, or `None` if it is not verified.
+        pub fn server_verification(server_id: ServerId) -> Option<VerificationLevel> {
+            ServerVerification::<T>::get(server_id)
+        }
+
+        /// Endpoint health of a server, or `None` if it was never probed.
+        pub fn server_health(server_id: ServerId) -> Option<HealthStatus<BlockNumberFor<T>>> {
+            ServerHealth::<T>::get(server_id)
+        }
+    }
+}
@@ -0,0 +1,267 @@
+//! Storage migrations of the MCP pallet.
+//!
+//! The layout of stored values is tracked by the pallet's storage version
+//! ([`crate::STORAGE_VERSION`]). A change that alters how existing values decode bumps the version
+//! and comes with a migration in a module named after the new version, wrapped in a
+//! [`VersionedMigration`] so that it runs exactly once, when the on-chain version is the one it
+//! migrates from. Runtimes add the migrations to their `Executive` in version order.
+//!
+//! Appending a [`ProtocolVersion`](crate::ProtocolVersion) revision needs no migration: every
+//! revision has a fixed encoding index and new ones take the next free index. Removing or
+//! renumbering a revision does, since stored servers would no longer decode.
+//!
+//! A new version follows the layout of [`v2`]: bump [`crate::STORAGE_VERSION`], add a `v4` module
+//! with a `MigrateV3ToV4` alias of [`VersionedMigration`] over an [`UncheckedOnRuntimeUpgrade`]
+//! that translates the affected storage, and append it to the runtime's migrations. Under the
+//! `try-runtime` feature, its `pre_upgrade` records what `post_upgrade` checks was preserved, and
+//! the pallet's `try_state` checks run after the upgrade.
+
+use frame_support::{
+    migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+};
+#[cfg(feature = "try-runtime")]
+use {alloc::vec::Vec, codec::Decode, sp_runtime::TryRuntimeError};
+
+/// Version 1: fixed encoding indexes for protocol revisions.
+pub mod v1 {
+    use super::*;
+    use crate::{Config, Pallet, Servers};
+
+    /// Migrate from storage version 0 to 1.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        VerifyServers<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+
+    /// Checks that every stored server decodes with the current types.
+    ///
+    /// The indexes given to the revisions match the ones derived before, so stored servers are
+    /// left untouched; any server that does not decode is logged for governance to deal with.
+    pub struct VerifyServers<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for VerifyServers<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut servers: u64 = 0;
+            for id in Servers::<T>::iter_keys() {
+                servers = servers.saturating_add(1);
+                if Servers::<T>::try_get(id).is_err() {
+                    log::error!(target: "runtime::mcp", "Server {id} does not decode");
+                }
+            }
+            T::DbWeight::get().reads(servers.saturating_mul(2))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok((Servers::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let servers = u64::decode(&mut &state[..]).map_err(|_| "Invalid pre-upgrade state")?;
+            ensure!(
+                Servers::<T>::iter_keys().count() as u64 == servers,
+                "Servers were added or removed"
+            );
+            ensure!(
+                Servers::<T>::iter_keys().all(|id| Servers::<T>::try_get(id).is_ok()),
+                "A server does not decode"
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Version 2: servers are leased until an expiry block.
+pub mod v2 {
+    use super::*;
+    use crate::{
+        AccessPolicy, BalanceOf, CidOf, Config, DescriptionOf, NameOf, Pallet, ProtocolVersion,
+        ServerCapabilities, ServerInfo, Servers, TransportOf,
+    };
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_runtime::Saturating;
+
+    /// Migrate from storage version 1 to 2.
+    pub type MigrateV1ToV2<T> = VersionedMigration<
+        1,
+        2,
+        AddServerExpiry<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+
+    /// A registered server as stored in version 1, without a lease.
+    #[derive(Decode)]
+    pub struct OldServerInfo<T: Config> {
+        owner: T::AccountId,
+        deposit: BalanceOf<T>,
+        name: NameOf<T>,
+        description: DescriptionOf<T>,
+        protocol_version: ProtocolVersion,
+        capabilities: ServerCapabilities,
+        transport: TransportOf<T>,
+        metadata_cid: Option<CidOf<T>>,
+        private_network: bool,
+        access_policy: Option<AccessPolicy<T>>,
+        active: bool,
+        registered_at: BlockNumberFor<T>,
+    }
+
+    impl<T: Config> OldServerInfo<T> {
+        fn leased_until(self, expires_at: BlockNumberFor<T>) -> ServerInfo<T> {
+            ServerInfo {
+                owner: self.owner,
+                deposit: self.deposit,
+                name: self.name,
+                description: self.description,
+                protocol_version: self.protocol_version,
+                capabilities: self.capabilities,
+                transport: self.transport,
+                metadata_cid: self.metadata_cid,
+                private_network: self.private_network,
+                access_policy: self.access_policy,
+                active: self.active,
+                registered_at: self.registered_at,
+                expires_at,
+            }
+        }
+    }
+
+    /// Leases every registered server for `RegistrationPeriod` blocks from the upgrade, so that
+    /// owners have a full period to start renewing.
+    pub struct AddServerExpiry<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for AddServerExpiry<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let expires_at = frame_system::Pallet::<T>::block_number()
+                .saturating_add(T::RegistrationPeriod::get());
+            let mut servers: u64 = 0;
+            Servers::<T>::translate::<OldServerInfo<T>, _>(|_, old| {
+                servers = servers.saturating_add(1);
+                Some(old.leased_until(expires_at))
+            });
+            T::DbWeight::get().reads_writes(servers, servers)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok((Servers::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let servers = u64::decode(&mut &state[..]).map_err(|_| "Invalid pre-upgrade state")?;
+            ensure!(
+                Servers::<T>::iter_values().count() as u64 == servers,
+                "A server was lost in the upgrade"
+            );
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                Servers::<T>::iter_values().all(|server| server.expires_at > now),
+                "A server is not leased beyond the upgrade"
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Version 3: requests and subscriptions are indexed by account.
+pub mod v3 {
+    use super::*;
+    use crate::{
+        Config, ExecutionRequests, Pallet, RequestsByAccount, ResourceSubscribers,
+        ResourceSubscriptionsByAccount, ToolSubscriptionsByAccount, Usage,
+    };
+
+    /// Migrate from storage version 2 to 3.
+    pub type MigrateV2ToV3<T> = VersionedMigration<
+        2,
+        3,
+        IndexByAccount<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+
+    /// Fills the per-account indexes of unsettled requests, tool subscriptions and resource
+    /// subscriptions from the storage they index.
+    ///
+    /// Accounts could make any number of requests before `MaxRequestsPerAccount`; requests beyond
+    /// it cannot be indexed, so they are dropped and their fee and bond refunded to the requester.
+    pub struct IndexByAccount<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for IndexByAccount<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let (mut reads, mut writes): (u64, u64) = (0, 0);
+            for (request_id, request) in ExecutionRequests::<T>::iter() {
+                reads = reads.saturating_add(2);
+                let indexed = RequestsByAccount::<T>::mutate(&request.requester, |requests| {
+                    requests.try_push(request_id).is_ok()
+                });
+                if indexed {
+                    writes = writes.saturating_add(1);
+                    continue;
+                }
+                reads = reads.saturating_add(5);
+                writes = writes.saturating_add(7);
+                match Pallet::<T>::refund(request_id, &request) {
+                    Ok(_) => log::warn!(
+                        target: "runtime::mcp",
+                        "Request {request_id} exceeds its requester's limit and was refunded"
+                    ),
+                    Err(error) => log::error!(
+                        target: "runtime::mcp",
+                        "Request {request_id} was dropped but not refunded: {error:?}"
+                    ),
+                }
+            }
+            for (tool_id, who, usage) in Usage::<T>::iter() {
+                reads = reads.saturating_add(1);
+                if usage.subscribed_until.is_some() {
+                    ToolSubscriptionsByAccount::<T>::insert(who, tool_id, ());
+                    writes = writes.saturating_add(1);
+                }
+            }
+            for (resource_id, subscribers) in ResourceSubscribers::<T>::iter() {
+                reads = reads.saturating_add(1);
+                for who in subscribers {
+                    ResourceSubscriptionsByAccount::<T>::insert(who, resource_id, ());
+                    writes = writes.saturating_add(1);
+                }
+            }
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok((ExecutionRequests::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let requests = u64::decode(&mut &state[..]).map_err(|_| "Invalid pre-upgrade state")?;
+            ensure!(
+                ExecutionRequests::<T>::iter_keys().count() as u64 <= requests,
+                "A request was added in the upgrade"
+            );
+            ensure!(
+                ExecutionRequests::<T>::iter().all(|(request_id, request)| {
+                    RequestsByAccount::<T>::get(&request.requester).contains(&request_id)
+                }),
+                "A request is not indexed"
+            );
+            ensure!(
+                ResourceSubscribers::<T>::iter().all(|(resource_id, subscribers)| {
+                    subscribers.iter().all(|who| {
+                        ResourceSubscriptionsByAccount::<T>::contains_key(who, resource_id)
+                    })
+                }),
+                "A resource subscription is not indexed"
+            );
+            Ok(())
+        }
+    }
+}
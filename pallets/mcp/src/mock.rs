@@ -0,0 +1,292 @@
+use crate as pallet_mcp;
+use crate::{
+    traits::{OnCallSettled, OnCidPublished, OnServerRegistered, SettledCall},
+    ServerId,
+};
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{AsEnsureOriginWithArg, ConstU16, ConstU64, SortedMembers},
+    weights::Weight,
+    PalletId,
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use sp_core::H256;
+use sp_runtime::{
+    testing::{TestSignature, TestXt, UintAuthorityId},
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+    pub enum Test
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        Assets: pallet_assets,
+        Mcp: pallet_mcp,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Nonce = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Block = Block;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<u64>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ConstU16<42>;
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+impl frame_system::offchain::SigningTypes for Test {
+    type Public = UintAuthorityId;
+    type Signature = TestSignature;
+}
+
+impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    type RuntimeCall = RuntimeCall;
+    type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_signed_transaction<
+        C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>,
+    >(
+        call: RuntimeCall,
+        _public: Self::Public,
+        _account: u64,
+        nonce: u64,
+    ) -> Option<Extrinsic> {
+        Some(Extrinsic::new_signed(call, nonce, (), ()))
+    }
+}
+
+/// Test crypto signing health reports with `UintAuthorityId` keys, whose account is the key.
+pub struct TestAuthorityId;
+
+impl frame_system::offchain::AppCrypto<UintAuthorityId, TestSignature> for TestAuthorityId {
+    type RuntimeAppPublic = UintAuthorityId;
+    type GenericSignature = TestSignature;
+    type GenericPublic = UintAuthorityId;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+}
+
+#[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
+impl pallet_assets::Config for Test {
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u64>>;
+    type ForceOrigin = EnsureRoot<u64>;
+    type Freezer = ();
+}
+
+parameter_types! {
+    pub const ServerDeposit: u64 = 100;
+    pub const RegistrationPeriod: u64 = 1_000;
+    pub const RenewalFee: u64 = 5;
+    pub const CreditAsset: u32 = CREDIT_ASSET;
+    pub const NativePerCredit: u64 = 2;
+    pub const McpPalletId: PalletId = PalletId(*b"py/mcpcr");
+    pub const MaxNameLength: u32 = 32;
+    pub const MaxDescriptionLength: u32 = 64;
+    pub const MaxUrlLength: u32 = 64;
+    pub const MaxCidLength: u32 = 64;
+    pub const MaxServersPerOwner: u32 = 2;
+    pub const MaxToolsPerServer: u32 = 2;
+    pub const MaxSchemaLength: u32 = 64;
+    pub const MaxPromptsPerServer: u32 = 2;
+    pub const MaxTemplateLength: u32 = 64;
+    pub const MaxResourcesPerServer: u32 = 2;
+    pub const MaxSubscribersPerResource: u32 = 2;
+    pub const ExecutionTimeout: u64 = 10;
+    pub const MinExecutionFee: u64 = 10;
+    pub const MaxPendingExecutions: u32 = 2;
+    pub const MaxRequestsPerAccount: u32 = 2;
+    pub const MaxModelHints: u32 = 2;
+    pub const MaxPendingSamplingRequests: u32 = 2;
+    pub const MaxSamplingHistory: u32 = 2;
+    pub const ExposureMultiplier: u32 = 11;
+    pub const ReputationHalfLife: u64 = 100;
+    pub const MaxAllowlistLength: u32 = 2;
+    pub static Reporters: Vec<u64> = vec![REPORTER];
+    pub static RegisteredServers: Vec<(ServerId, u64)> = vec![];
+    pub static SettledCalls: Vec<SettledCall<u64, u64>> = vec![];
+    pub static PublishedCids: Vec<Vec<u8>> = vec![];
+    pub const ProbeInterval: u64 = 10;
+    pub const ProbeTimeout: u64 = 2_000;
+    pub const MaxHealthReports: u32 = 2;
+    pub const MaxConsecutiveFailures: u32 = 3;
+    pub const MaxActivityPerAccount: u32 = 3;
+}
+
+impl pallet_mcp::Config for Test {
+    type WeightInfo = ();
+    type RuntimeCall = RuntimeCall;
+    type AuthorityId = TestAuthorityId;
+    type HealthReporters = HealthReporters;
+    type Currency = Balances;
+    type Assets = Assets;
+    type SlashOrigin = EnsureRoot<u64>;
+    type VerifyOrigin = EnsureRoot<u64>;
+    type OnServerRegistered = RecordHooks;
+    type OnCallSettled = RecordHooks;
+    type OnCidPublished = RecordHooks;
+    type ServerDeposit = ServerDeposit;
+    type RegistrationPeriod = RegistrationPeriod;
+    type RenewalFee = RenewalFee;
+    type CreditAsset = CreditAsset;
+    type NativePerCredit = NativePerCredit;
+    type PalletId = McpPalletId;
+    type MaxNameLength = MaxNameLength;
+    type MaxDescriptionLength = MaxDescriptionLength;
+    type MaxUrlLength = MaxUrlLength;
+    type MaxCidLength = MaxCidLength;
+    type MaxServersPerOwner = MaxServersPerOwner;
+    type MaxToolsPerServer = MaxToolsPerServer;
+    type MaxSchemaLength = MaxSchemaLength;
+    type MaxPromptsPerServer = MaxPromptsPerServer;
+    type MaxTemplateLength = MaxTemplateLength;
+    type MaxResourcesPerServer = MaxResourcesPerServer;
+    type MaxSubscribersPerResource = MaxSubscribersPerResource;
+    type ExecutionTimeout = ExecutionTimeout;
+    type MinExecutionFee = MinExecutionFee;
+    type MaxPendingExecutions = MaxPendingExecutions;
+    type MaxRequestsPerAccount = MaxRequestsPerAccount;
+    type MaxModelHints = MaxModelHints;
+    type MaxPendingSamplingRequests = MaxPendingSamplingRequests;
+    type MaxSamplingHistory = MaxSamplingHistory;
+    type ExposureMultiplier = ExposureMultiplier;
+    type ReputationHalfLife = ReputationHalfLife;
+    type MaxAllowlistLength = MaxAllowlistLength;
+    type ProbeInterval = ProbeInterval;
+    type ProbeTimeout = ProbeTimeout;
+    type MaxHealthReports = MaxHealthReports;
+    type MaxConsecutiveFailures = MaxConsecutiveFailures;
+    type MaxActivityPerAccount = MaxActivityPerAccount;
+}
+
+/// Records every hook call in `RegisteredServers`, `SettledCalls` and `PublishedCids`.
+pub struct RecordHooks;
+
+impl OnServerRegistered<u64> for RecordHooks {
+    fn on_server_registered(id: ServerId, owner: &u64) {
+        RegisteredServers::mutate(|servers| servers.push((id, *owner)));
+    }
+
+    fn weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+impl OnCallSettled<u64, u64> for RecordHooks {
+    fn on_call_settled(call: &SettledCall<u64, u64>) {
+        SettledCalls::mutate(|calls| calls.push(call.clone()));
+    }
+
+    fn weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+impl OnCidPublished for RecordHooks {
+    fn on_cid_published(cid: &[u8]) {
+        PublishedCids::mutate(|cids| cids.push(cid.to_vec()));
+    }
+
+    fn weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+/// The account allowed to report server health.
+pub const REPORTER: u64 = 9;
+
+/// Health reporters kept in `Reporters`, so benchmarks can add their own.
+pub struct HealthReporters;
+
+impl SortedMembers<u64> for HealthReporters {
+    fn sorted_members() -> Vec<u64> {
+        Reporters::get()
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn add(who: &u64) {
+        Reporters::mutate(|reporters| {
+            reporters.push(*who);
+            reporters.sort();
+        });
+    }
+}
+
+/// Balance of every funded test account, enough for a few server deposits.
+pub const INITIAL_BALANCE: u64 = 1_000;
+
+/// Accounts funded at genesis.
+pub const FUNDED: [u64; 3] = [1, 2, 3];
+
+/// Identifier of the compute-credit asset.
+pub const CREDIT_ASSET: u32 = 0;
+
+/// Credits of every funded test account.
+pub const INITIAL_CREDITS: u64 = 500;
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: FUNDED.iter().map(|who| (*who, INITIAL_BALANCE)).collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+    pallet_assets::GenesisConfig::<Test> {
+        assets: vec![(CREDIT_ASSET, 1, true, 1)],
+        accounts: FUNDED
+            .iter()
+            .map(|who| (CREDIT_ASSET, *who, INITIAL_CREDITS))
+            .collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+    storage.into()
+}
+
+/// Run `test` in fresh externalities, then check that it left the pallet's storage consistent.
+pub fn build_and_execute(test: impl FnOnce()) {
+    new_test_ext().execute_with(|| {
+        test();
+        Mcp::do_try_state().expect("storage is consistent");
+    });
+}
@@ -0,0 +1,205 @@
+//! Rendering of prompt templates.
+//!
+//! Templates are UTF-8 text in which `{{name}}` placeholders are replaced by the parameter of
+//! that name; whitespace around the name is ignored. A backslash before `{{` writes the braces
+//! literally, so `\{{name}}` renders as `{{name}}`. Parameter names consist of ASCII letters,
+//! digits, `_`, `-` and `.`.
+//!
+//! Parameters are given as a JSON object whose values are strings, numbers or booleans. When the
+//! prompt has a parameter schema, they are checked against the parts of JSON Schema that describe
+//! a flat object: `required`, and per property `type` (`string`, `number`, `integer` or
+//! `boolean`), `minLength` and `maxLength`. If the schema has `properties`, parameters it does not
+//! list are rejected. Other keywords are ignored. Both are parsed by `mod-net-canonical-json`, so
+//! they must be JSON documents that can be canonicalized: duplicate keys are rejected.
+//!
+//! Rendering is deterministic and runs in the runtime, so light clients can render a prompt
+//! through the runtime API without trusting an off-chain service.
+
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use codec::{Decode, Encode};
+use frame_support::pallet_prelude::*;
+use mod_net_canonical_json::{parse, Value};
+
+/// Why a prompt could not be rendered.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum RenderError {
+    /// The prompt does not exist.
+    PromptNotFound,
+    /// The template has an unterminated placeholder or an invalid parameter name.
+    InvalidTemplate,
+    /// The stored parameter schema is not a JSON object.
+    InvalidSchema,
+    /// The parameters are not a JSON object of strings, numbers and booleans.
+    InvalidParams,
+    /// A parameter required by the schema or used by the template is missing.
+    MissingParam(Vec<u8>),
+    /// A parameter is not listed by the schema.
+    UnknownParam(Vec<u8>),
+    /// A parameter does not have the type or length the schema requires.
+    InvalidParam(Vec<u8>),
+}
+
+/// Whether `template` can be rendered: it is UTF-8 and every placeholder is well-formed.
+pub fn is_valid(template: &[u8]) -> bool {
+    core::str::from_utf8(template).is_ok_and(|template| segments(template).is_ok())
+}
+
+/// Render `template` with the parameters in `params_json`, checked against `schema` if the
+/// prompt has one.
+pub fn render(
+    template: &[u8],
+    schema: Option<&[u8]>,
+    params_json: &[u8],
+) -> Result<Vec<u8>, RenderError> {
+    let template = core::str::from_utf8(template).map_err(|_| RenderError::InvalidTemplate)?;
+    let segments = segments(template)?;
+    let params = params(params_json)?;
+    if let Some(schema) = schema {
+        validate(&params, schema)?;
+    }
+
+    let mut output = String::with_capacity(template.len());
+    for segment in segments {
+        match segment {
+            Segment::Text(text) => output.push_str(text),
+            Segment::Placeholder(name) => {
+                let value = params
+                    .get(name)
+                    .ok_or_else(|| RenderError::MissingParam(name.as_bytes().to_vec()))?;
+                output.push_str(&value.text());
+            }
+        }
+    }
+    Ok(output.into_bytes())
+}
+
+/// A piece of a template.
+enum Segment<'a> {
+    /// Text copied to the output.
+    Text(&'a str),
+    /// The name of a parameter to substitute.
+    Placeholder(&'a str),
+}
+
+fn segments(mut template: &str) -> Result<Vec<Segment<'_>>, RenderError> {
+    let mut segments = Vec::new();
+    while let Some(start) = template.find("{{") {
+        if template[..start].ends_with('\\') {
+            segments.push(Segment::Text(&template[..start - 1]));
+            segments.push(Segment::Text("{{"));
+            template = &template[start + 2..];
+            continue;
+        }
+        segments.push(Segment::Text(&template[..start]));
+        let rest = &template[start + 2..];
+        let end = rest.find("}}").ok_or(RenderError::InvalidTemplate)?;
+        let name = rest[..end].trim();
+        let valid_name = !name.is_empty()
+            && name
+                .bytes()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, b'_' | b'-' | b'.'));
+        if !valid_name {
+            return Err(RenderError::InvalidTemplate);
+        }
+        segments.push(Segment::Placeholder(name));
+        template = &rest[end + 2..];
+    }
+    segments.push(Segment::Text(template));
+    Ok(segments)
+}
+
+/// A parameter value.
+enum Param {
+    String(String),
+    /// A number as written in the parameters.
+    Number(String),
+    Bool(bool),
+}
+
+impl Param {
+    fn text(&self) -> String {
+        match self {
+            Param::String(text) | Param::Number(text) => text.clone(),
+            Param::Bool(value) => value.to_string(),
+        }
+    }
+}
+
+fn params(params_json: &[u8]) -> Result<BTreeMap<String, Param>, RenderError> {
+    let Ok(Value::Object(entries)) = parse(params_json) else {
+        return Err(RenderError::InvalidParams);
+    };
+    entries
+        .into_iter()
+        .map(|(name, value)| {
+            let param = match value {
+                Value::String(text) => Param::String(text),
+                Value::Number(number) => Param::Number(number.as_str().into()),
+                Value::Bool(value) => Param::Bool(value),
+                _ => return Err(RenderError::InvalidParams),
+            };
+            Ok((name, param))
+        })
+        .collect()
+}
+
+/// Check `params` against the constraints of a JSON Schema describing a flat object.
+fn validate(params: &BTreeMap<String, Param>, schema: &[u8]) -> Result<(), RenderError> {
+    let schema = parse(schema).map_err(|_| RenderError::InvalidSchema)?;
+    if !matches!(schema, Value::Object(_)) {
+        return Err(RenderError::InvalidSchema);
+    }
+
+    if let Some(Value::Array(required)) = schema.get("required") {
+        for name in required {
+            if let Value::String(name) = name {
+                if !params.contains_key(name) {
+                    return Err(RenderError::MissingParam(name.as_bytes().to_vec()));
+                }
+            }
+        }
+    }
+
+    let properties = schema.get("properties");
+    for (name, param) in params {
+        let invalid = || RenderError::InvalidParam(name.as_bytes().to_vec());
+        let Some(property) = properties.map(|properties| properties.get(name)) else {
+            // Without `properties` the schema says nothing about individual parameters
+            continue;
+        };
+        let property =
+            property.ok_or_else(|| RenderError::UnknownParam(name.as_bytes().to_vec()))?;
+
+        let type_matches = match property.get("type") {
+            Some(Value::String(kind)) => match (kind.as_str(), param) {
+                ("string", Param::String(_)) | ("boolean", Param::Bool(_)) => true,
+                ("number", Param::Number(_)) => true,
+                ("integer", Param::Number(number)) => !number.contains(['.', 'e', 'E']),
+                ("string" | "number" | "integer" | "boolean", _) => false,
+                _ => true,
+            },
+            _ => true,
+        };
+        if !type_matches {
+            return Err(invalid());
+        }
+
+        if let Param::String(text) = param {
+            let length = text.chars().count() as u64;
+            let bound = |keyword| match property.get(keyword) {
+                Some(Value::Number(bound)) => bound.as_u64(),
+                _ => None,
+            };
+            if bound("minLength").is_some_and(|min| length < min)
+                || bound("maxLength").is_some_and(|max| length > max)
+            {
+                return Err(invalid());
+            }
+        }
+    }
+    Ok(())
+}
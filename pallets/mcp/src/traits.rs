@@ -0,0 +1,97 @@
+//! Hooks through which other pallets react to MCP activity.
+//!
+//! The MCP pallet calls these after the change is written, so implementations see the new state.
+//! They cannot veto or fail the change. Coupling stays one-directional: a points program or an
+//! analytics pallet implements a hook and the runtime plugs it into the MCP pallet's config, which
+//! never depends on it. Several implementations are combined as a tuple, e.g.
+//! `type OnCallSettled = (Points, Analytics);`.
+//!
+//! Hooks run inside the triggering call, which is charged `weight()` on top of its own weight, so
+//! implementations must stay within the weight they declare.
+
+use crate::{RequestId, ServerId, ToolId};
+use frame_support::{pallet_prelude::*, weights::Weight};
+
+/// Called after a server was registered.
+pub trait OnServerRegistered<AccountId> {
+    /// React to the registration of server `id` by `owner`.
+    fn on_server_registered(id: ServerId, owner: &AccountId);
+
+    /// Upper bound of the weight of `on_server_registered`.
+    fn weight() -> Weight;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(8)]
+impl<AccountId> OnServerRegistered<AccountId> for Tuple {
+    fn on_server_registered(id: ServerId, owner: &AccountId) {
+        for_tuples!( #( Tuple::on_server_registered(id, owner); )* );
+    }
+
+    fn weight() -> Weight {
+        let mut weight = Weight::zero();
+        for_tuples!( #( weight = weight.saturating_add(Tuple::weight()); )* );
+        weight
+    }
+}
+
+/// A paid tool run that was completed or refunded.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct SettledCall<AccountId, Balance> {
+    /// The settled request.
+    pub request_id: RequestId,
+    /// The tool that was requested.
+    pub tool_id: ToolId,
+    /// The server offering the tool.
+    pub server_id: ServerId,
+    /// The account that paid for the run.
+    pub requester: AccountId,
+    /// The escrowed fee.
+    pub fee: Balance,
+    /// The server owner the fee was paid to, or `None` if it was refunded to the requester.
+    pub paid_to: Option<AccountId>,
+}
+
+/// Called after a paid tool run was settled, either completed or refunded after its deadline.
+pub trait OnCallSettled<AccountId, Balance> {
+    /// React to a settled run.
+    fn on_call_settled(call: &SettledCall<AccountId, Balance>);
+
+    /// Upper bound of the weight of `on_call_settled`.
+    fn weight() -> Weight;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(8)]
+impl<AccountId, Balance> OnCallSettled<AccountId, Balance> for Tuple {
+    fn on_call_settled(call: &SettledCall<AccountId, Balance>) {
+        for_tuples!( #( Tuple::on_call_settled(call); )* );
+    }
+
+    fn weight() -> Weight {
+        let mut weight = Weight::zero();
+        for_tuples!( #( weight = weight.saturating_add(Tuple::weight()); )* );
+        weight
+    }
+}
+
+/// Called for every content or metadata CID a server, prompt or resource was registered or
+/// updated with, e.g. so that a pinning program can ask for the content to be kept available.
+pub trait OnCidPublished {
+    /// React to `cid` being referenced by a registration.
+    fn on_cid_published(cid: &[u8]);
+
+    /// Upper bound of the weight of `on_cid_published`.
+    fn weight() -> Weight;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(8)]
+impl OnCidPublished for Tuple {
+    fn on_cid_published(cid: &[u8]) {
+        for_tuples!( #( Tuple::on_cid_published(cid); )* );
+    }
+
+    fn weight() -> Weight {
+        let mut weight = Weight::zero();
+        for_tuples!( #( weight = weight.saturating_add(Tuple::weight()); )* );
+        weight
+    }
+}
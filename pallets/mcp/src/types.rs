@@ -0,0 +1,1087 @@
+//! Types describing registered MCP servers and what they offer.
+
+use crate::Config;
+use alloc::vec::Vec;
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{
+    pallet_prelude::*,
+    traits::{fungibles, Currency},
+    CloneNoBound, DefaultNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_core::H256;
+use sp_runtime::{
+    traits::{AtLeast32BitUnsigned, Saturating, UniqueSaturatedInto},
+    Percent,
+};
+
+/// Identifier of a registered server.
+pub type ServerId = u64;
+
+/// Identifier of a registered tool.
+pub type ToolId = u64;
+
+/// Identifier of a registered prompt template.
+pub type PromptId = u64;
+
+/// Identifier of a registered resource.
+pub type ResourceId = u64;
+
+/// Identifier of a tool execution request.
+pub type RequestId = u64;
+
+/// Identifier of a sampling request.
+pub type SamplingRequestId = u64;
+
+/// Revision of the Model Context Protocol specification implemented by a server.
+///
+/// Revisions are ordered by release date. Each variant has a fixed encoding index so that stored
+/// servers keep decoding as revisions are added: a new revision is appended with the next index,
+/// and existing indexes are never reused (see [`crate::migrations`]).
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum ProtocolVersion {
+    /// Revision `2024-11-05`.
+    #[codec(index = 0)]
+    V2024_11_05,
+    /// Revision `2025-03-26`.
+    #[codec(index = 1)]
+    V2025_03_26,
+    /// Revision `2025-06-18`.
+    #[codec(index = 2)]
+    V2025_06_18,
+}
+
+impl ProtocolVersion {
+    /// The revision as written in the MCP `initialize` handshake, e.g. `2025-06-18`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::V2024_11_05 => "2024-11-05",
+            Self::V2025_03_26 => "2025-03-26",
+            Self::V2025_06_18 => "2025-06-18",
+        }
+    }
+
+    /// The revision written as in the MCP `initialize` handshake, or `None` if it is unknown.
+    pub fn parse(revision: &str) -> Option<Self> {
+        match revision {
+            "2024-11-05" => Some(Self::V2024_11_05),
+            "2025-03-26" => Some(Self::V2025_03_26),
+            "2025-06-18" => Some(Self::V2025_06_18),
+            _ => None,
+        }
+    }
+}
+
+/// How thoroughly `VerifyOrigin` checked a server, from least to most.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum VerificationLevel {
+    /// The operator's identity is known.
+    #[codec(index = 0)]
+    Identity,
+    /// The server's behaviour was reviewed against its registration.
+    #[codec(index = 1)]
+    Reviewed,
+    /// The server's code and deployment were audited.
+    #[codec(index = 2)]
+    Audited,
+}
+
+impl VerificationLevel {
+    /// The level as named in RPC responses, e.g. `audited`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Identity => "identity",
+            Self::Reviewed => "reviewed",
+            Self::Audited => "audited",
+        }
+    }
+
+    /// The level named as in RPC responses, or `None` if it is unknown.
+    pub fn parse(level: &str) -> Option<Self> {
+        match level {
+            "identity" => Some(Self::Identity),
+            "reviewed" => Some(Self::Reviewed),
+            "audited" => Some(Self::Audited),
+            _ => None,
+        }
+    }
+}
+
+/// Resource features supported by a server.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct ResourcesCapability {
+    /// Clients may subscribe to changes of individual resources.
+    pub subscribe: bool,
+    /// The server notifies clients when its list of resources changes.
+    pub list_changed: bool,
+}
+
+/// Capabilities a server announces, mirroring the MCP `ServerCapabilities` object.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct ServerCapabilities {
+    /// The server offers tools.
+    pub tools: bool,
+    /// The server offers prompt templates.
+    pub prompts: bool,
+    /// The server offers resources, with the given features.
+    pub resources: Option<ResourcesCapability>,
+    /// The server emits log messages.
+    pub logging: bool,
+    /// The server may request LLM sampling from clients.
+    pub sampling: bool,
+}
+
+impl ServerCapabilities {
+    /// Whether these capabilities include every capability in `required`.
+    pub fn satisfies(&self, required: &ServerCapabilities) -> bool {
+        let resources = match (required.resources, self.resources) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(required), Some(offered)) => {
+                (offered.subscribe || !required.subscribe)
+                    && (offered.list_changed || !required.list_changed)
+            }
+        };
+        (self.tools || !required.tools)
+            && (self.prompts || !required.prompts)
+            && resources
+            && (self.logging || !required.logging)
+            && (self.sampling || !required.sampling)
+    }
+}
+
+/// How clients reach a server.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    CloneNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    RuntimeDebugNoBound,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(UrlLimit))]
+#[codec(mel_bound())]
+pub enum TransportConfig<UrlLimit: Get<u32>> {
+    /// The server runs locally as a subprocess, launched as described by its metadata.
+    Stdio,
+    /// Streamable HTTP endpoint (`http://` or `https://`).
+    Http {
+        /// Endpoint URL.
+        url: BoundedVec<u8, UrlLimit>,
+    },
+    /// Legacy HTTP+SSE endpoint (`http://` or `https://`).
+    Sse {
+        /// Endpoint URL.
+        url: BoundedVec<u8, UrlLimit>,
+    },
+    /// WebSocket endpoint (`ws://` or `wss://`).
+    WebSocket {
+        /// Endpoint URL.
+        url: BoundedVec<u8, UrlLimit>,
+    },
+}
+
+impl<UrlLimit: Get<u32>> TransportConfig<UrlLimit> {
+    /// The endpoint URL, if the server is reached over the network.
+    pub fn url(&self) -> Option<&[u8]> {
+        match self {
+            Self::Stdio => None,
+            Self::Http { url } | Self::Sse { url } | Self::WebSocket { url } => Some(url),
+        }
+    }
+
+    /// URL schemes accepted for this transport.
+    pub fn schemes(&self) -> &'static [&'static str] {
+        match self {
+            Self::Stdio => &[],
+            Self::Http { .. } | Self::Sse { .. } => &["http://", "https://"],
+            Self::WebSocket { .. } => &["ws://", "wss://"],
+        }
+    }
+}
+
+/// Balance of the currency server deposits are reserved in.
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Identifier of the fungible asset compute credits are issued as.
+pub type AssetIdOf<T> =
+    <<T as Config>::Assets as fungibles::Inspect<<T as frame_system::Config>::AccountId>>::AssetId;
+
+/// Bounded name of a server, tool, prompt or resource.
+pub type NameOf<T> = BoundedVec<u8, <T as Config>::MaxNameLength>;
+
+/// Bounded description of a server, tool, prompt or resource.
+pub type DescriptionOf<T> = BoundedVec<u8, <T as Config>::MaxDescriptionLength>;
+
+/// Bounded IPFS CID.
+pub type CidOf<T> = BoundedVec<u8, <T as Config>::MaxCidLength>;
+
+/// Bounded JSON Schema of a tool's input or output.
+pub type SchemaOf<T> = BoundedVec<u8, <T as Config>::MaxSchemaLength>;
+
+/// Bounded prompt template text.
+pub type TemplateOf<T> = BoundedVec<u8, <T as Config>::MaxTemplateLength>;
+
+/// Bounded resource URI.
+pub type UriOf<T> = BoundedVec<u8, <T as Config>::MaxUrlLength>;
+
+/// Transport with the configured URL bound.
+pub type TransportOf<T> = TransportConfig<<T as Config>::MaxUrlLength>;
+
+/// Network access policy of a server that only some networks can reach.
+///
+/// The policy document, listing the allowed IP ranges or ASNs, lives on IPFS. Its hash commits
+/// the owner to one document, so a gateway can tell a swapped or altered copy.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    PartialEq,
+    Eq,
+    RuntimeDebugNoBound,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct AccessPolicy<T: Config> {
+    /// CID of the policy document on IPFS.
+    pub cid: CidOf<T>,
+    /// BLAKE2-256 hash of the policy document.
+    pub hash: H256,
+}
+
+/// The owner-provided part of a server's registration.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    PartialEq,
+    Eq,
+    RuntimeDebugNoBound,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct ServerRegistration<T: Config> {
+    /// Human-readable name, UTF-8.
+    pub name: NameOf<T>,
+    /// Short description, UTF-8.
+    pub description: DescriptionOf<T>,
+    /// Protocol revision the server implements.
+    pub protocol_version: ProtocolVersion,
+    /// Capabilities the server announces.
+    pub capabilities: ServerCapabilities,
+    /// How clients reach the server.
+    pub transport: TransportOf<T>,
+    /// CID of extended metadata (package, launch command, documentation) on IPFS.
+    pub metadata_cid: Option<CidOf<T>>,
+    /// The server is only reachable from private networks; gateways outside them should not
+    /// try to connect.
+    pub private_network: bool,
+    /// Networks allowed to reach the server.
+    pub access_policy: Option<AccessPolicy<T>>,
+}
+
+/// A registered MCP server.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct ServerInfo<T: Config> {
+    /// The account that registered the server and may change or remove it.
+    pub owner: T::AccountId,
+    /// Amount reserved from the owner while the server is registered.
+    pub deposit: BalanceOf<T>,
+    /// Human-readable name, UTF-8.
+    pub name: NameOf<T>,
+    /// Short description, UTF-8.
+    pub description: DescriptionOf<T>,
+    /// Protocol revision the server implements.
+    pub protocol_version: ProtocolVersion,
+    /// Capabilities the server announces.
+    pub capabilities: ServerCapabilities,
+    /// How clients reach the server.
+    pub transport: TransportOf<T>,
+    /// CID of extended metadata on IPFS.
+    pub metadata_cid: Option<CidOf<T>>,
+    /// The server is only reachable from private networks.
+    pub private_network: bool,
+    /// Networks allowed to reach the server.
+    pub access_policy: Option<AccessPolicy<T>>,
+    /// Whether the server accepts clients; inactive servers stay registered.
+    pub active: bool,
+    /// Block at which the server was registered.
+    pub registered_at: BlockNumberFor<T>,
+    /// Last block of the server's lease; afterwards the server is removed unless renewed.
+    pub expires_at: BlockNumberFor<T>,
+}
+
+impl<T: Config> ServerInfo<T> {
+    /// Build a new, active server record leased for `RegistrationPeriod` blocks.
+    pub fn new(
+        owner: T::AccountId,
+        deposit: BalanceOf<T>,
+        registration: ServerRegistration<T>,
+        now: BlockNumberFor<T>,
+    ) -> Self {
+        let ServerRegistration {
+            name,
+            description,
+            protocol_version,
+            capabilities,
+            transport,
+            metadata_cid,
+            private_network,
+            access_policy,
+        } = registration;
+        Self {
+            owner,
+            deposit,
+            name,
+            description,
+            protocol_version,
+            capabilities,
+            transport,
+            metadata_cid,
+            private_network,
+            access_policy,
+            active: true,
+            registered_at: now,
+            expires_at: now.saturating_add(T::RegistrationPeriod::get()),
+        }
+    }
+
+    /// Replace the owner-provided fields, keeping ownership, deposit, status, registration block
+    /// and lease.
+    pub fn apply(&mut self, registration: ServerRegistration<T>) {
+        self.name = registration.name;
+        self.description = registration.description;
+        self.protocol_version = registration.protocol_version;
+        self.capabilities = registration.capabilities;
+        self.transport = registration.transport;
+        self.metadata_cid = registration.metadata_cid;
+        self.private_network = registration.private_network;
+        self.access_policy = registration.access_policy;
+    }
+}
+
+/// The owner-provided part of a tool's registration, mirroring the MCP `Tool` object.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    PartialEq,
+    Eq,
+    RuntimeDebugNoBound,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct ToolRegistration<T: Config> {
+    /// Name the tool is called by, unique within its server, UTF-8.
+    pub name: NameOf<T>,
+    /// Description of what the tool does, UTF-8.
+    pub description: DescriptionOf<T>,
+    /// JSON Schema of the tool's arguments.
+    pub input_schema: SchemaOf<T>,
+    /// JSON Schema of the tool's structured result, if it returns one.
+    pub output_schema: Option<SchemaOf<T>>,
+}
+
+/// A tool offered by a registered server.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct ToolInfo<T: Config> {
+    /// The server offering the tool.
+    pub server_id: ServerId,
+    /// Name the tool is called by, unique within its server, UTF-8.
+    pub name: NameOf<T>,
+    /// Description of what the tool does, UTF-8.
+    pub description: DescriptionOf<T>,
+    /// JSON Schema of the tool's arguments.
+    pub input_schema: SchemaOf<T>,
+    /// JSON Schema of the tool's structured result, if it returns one.
+    pub output_schema: Option<SchemaOf<T>>,
+    /// Whether the tool may be called; inactive tools stay registered.
+    pub active: bool,
+}
+
+impl<T: Config> ToolInfo<T> {
+    /// Build a new, active tool record.
+    pub fn new(server_id: ServerId, registration: ToolRegistration<T>) -> Self {
+        let ToolRegistration {
+            name,
+            description,
+            input_schema,
+            output_schema,
+        } = registration;
+        Self {
+            server_id,
+            name,
+            description,
+            input_schema,
+            output_schema,
+            active: true,
+        }
+    }
+
+    /// Replace the owner-provided fields, keeping the server and status.
+    pub fn apply(&mut self, registration: ToolRegistration<T>) {
+        self.name = registration.name;
+        self.description = registration.description;
+        self.input_schema = registration.input_schema;
+        self.output_schema = registration.output_schema;
+    }
+}
+
+/// A tool registered ahead of its launch: its name and identifier are taken, but its
+/// registration stays hidden behind a commitment until the owner reveals it.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct SealedTool<T: Config> {
+    /// The server that will offer the tool.
+    pub server_id: ServerId,
+    /// The reserved name, which the revealed registration must carry.
+    pub name: NameOf<T>,
+    /// BLAKE2-256 hash of the SCALE-encoded registration and a salt chosen by the owner, see
+    /// [`crate::Pallet::tool_commitment`].
+    pub commitment: H256,
+    /// First block at which the registration may be revealed.
+    pub reveal_at: BlockNumberFor<T>,
+}
+
+/// The owner-provided part of a prompt template, mirroring the MCP `Prompt` object.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    PartialEq,
+    Eq,
+    RuntimeDebugNoBound,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct PromptRegistration<T: Config> {
+    /// Name the prompt is requested by, unique within its server, UTF-8.
+    pub name: NameOf<T>,
+    /// Description of the prompt, UTF-8.
+    pub description: DescriptionOf<T>,
+    /// Template text with `{{parameter}}` placeholders, UTF-8.
+    pub template: TemplateOf<T>,
+    /// JSON Schema of the template parameters.
+    pub parameter_schema: Option<SchemaOf<T>>,
+    /// CID of extended metadata (examples, changelog) on IPFS.
+    pub metadata_cid: Option<CidOf<T>>,
+}
+
+/// A prompt template offered by a registered server.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct PromptTemplate<T: Config> {
+    /// The server offering the prompt.
+    pub server_id: ServerId,
+    /// Name the prompt is requested by, unique within its server, UTF-8.
+    pub name: NameOf<T>,
+    /// Description of the prompt, UTF-8.
+    pub description: DescriptionOf<T>,
+    /// Template text with `{{parameter}}` placeholders, UTF-8.
+    pub template: TemplateOf<T>,
+    /// JSON Schema of the template parameters.
+    pub parameter_schema: Option<SchemaOf<T>>,
+    /// CID of extended metadata on IPFS.
+    pub metadata_cid: Option<CidOf<T>>,
+}
+
+impl<T: Config> PromptTemplate<T> {
+    /// Build a prompt record from its registration.
+    pub fn new(server_id: ServerId, registration: PromptRegistration<T>) -> Self {
+        let PromptRegistration {
+            name,
+            description,
+            template,
+            parameter_schema,
+            metadata_cid,
+        } = registration;
+        Self {
+            server_id,
+            name,
+            description,
+            template,
+            parameter_schema,
+            metadata_cid,
+        }
+    }
+}
+
+/// The owner-provided part of a resource, mirroring the MCP `Resource` object.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    PartialEq,
+    Eq,
+    RuntimeDebugNoBound,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct ResourceRegistration<T: Config> {
+    /// URI the resource is read by, unique within its server, UTF-8.
+    pub uri: UriOf<T>,
+    /// Human-readable name, UTF-8.
+    pub name: NameOf<T>,
+    /// Description of the resource, UTF-8.
+    pub description: DescriptionOf<T>,
+    /// MIME type of the content, e.g. `text/markdown`.
+    pub mime_type: Option<NameOf<T>>,
+    /// CID of the content on IPFS.
+    pub content_cid: CidOf<T>,
+    /// CID of extended metadata on IPFS.
+    pub metadata_cid: Option<CidOf<T>>,
+}
+
+/// A resource offered by a registered server.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct ResourceInfo<T: Config> {
+    /// The server offering the resource.
+    pub server_id: ServerId,
+    /// URI the resource is read by, unique within its server, UTF-8.
+    pub uri: UriOf<T>,
+    /// Human-readable name, UTF-8.
+    pub name: NameOf<T>,
+    /// Description of the resource, UTF-8.
+    pub description: DescriptionOf<T>,
+    /// MIME type of the content, e.g. `text/markdown`.
+    pub mime_type: Option<NameOf<T>>,
+    /// CID of the content on IPFS.
+    pub content_cid: CidOf<T>,
+    /// CID of extended metadata on IPFS.
+    pub metadata_cid: Option<CidOf<T>>,
+}
+
+impl<T: Config> ResourceInfo<T> {
+    /// Build a resource record from its registration.
+    pub fn new(server_id: ServerId, registration: ResourceRegistration<T>) -> Self {
+        let ResourceRegistration {
+            uri,
+            name,
+            description,
+            mime_type,
+            content_cid,
+            metadata_cid,
+        } = registration;
+        Self {
+            server_id,
+            uri,
+            name,
+            description,
+            mime_type,
+            content_cid,
+            metadata_cid,
+        }
+    }
+}
+
+/// A paid request to run a tool, escrowed until the server owner answers or it expires.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct ExecutionRequest<T: Config> {
+    /// The account that requested the execution and pays the fee.
+    pub requester: T::AccountId,
+    /// The tool to run.
+    pub tool_id: ToolId,
+    /// The server offering the tool; its owner is paid on completion.
+    pub server_id: ServerId,
+    /// CID of the tool's input on IPFS.
+    pub input_cid: CidOf<T>,
+    /// Amount reserved from the requester and paid to the server owner on completion.
+    pub fee: BalanceOf<T>,
+    /// Last block in which the result may be submitted; afterwards the requester may cancel.
+    pub deadline: BlockNumberFor<T>,
+}
+
+/// The model a sampling client should pick, mirroring the MCP `ModelPreferences` object.
+///
+/// Priorities weigh the criteria against each other; a criterion without one is left to the
+/// client.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    CloneNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    DefaultNoBound,
+    RuntimeDebugNoBound,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct ModelPreferences<T: Config> {
+    /// Names or name fragments of suitable models, most preferred first, UTF-8.
+    pub hints: BoundedVec<NameOf<T>, T::MaxModelHints>,
+    /// How much to favour a cheaper model.
+    pub cost_priority: Option<Percent>,
+    /// How much to favour a faster model.
+    pub speed_priority: Option<Percent>,
+    /// How much to favour a more capable model.
+    pub intelligence_priority: Option<Percent>,
+}
+
+/// A request for a server to sample a language model, waiting to be answered.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct SamplingRequest<T: Config> {
+    /// The account that made the request.
+    pub requester: T::AccountId,
+    /// The server asked to answer it.
+    pub server_id: ServerId,
+    /// CID of the messages to complete on IPFS.
+    pub prompt_cid: CidOf<T>,
+    /// The model the requester would like to be sampled.
+    pub model_preferences: ModelPreferences<T>,
+    /// Last block in which the result may be submitted; afterwards anyone may cancel.
+    pub deadline: BlockNumberFor<T>,
+}
+
+/// Tokens used to answer a sampling request, as reported by the server.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct UsageStats {
+    /// Tokens of the prompt.
+    pub input_tokens: u32,
+    /// Tokens of the completion.
+    pub output_tokens: u32,
+}
+
+/// An answered sampling request, as kept in its server's sampling history.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct SamplingResult<T: Config> {
+    /// The answered request.
+    pub request_id: SamplingRequestId,
+    /// The account that made the request.
+    pub requester: T::AccountId,
+    /// CID of the completion on IPFS.
+    pub completion_cid: CidOf<T>,
+    /// Tokens used for the completion.
+    pub usage: UsageStats,
+    /// Block in which the result was submitted.
+    pub completed_at: BlockNumberFor<T>,
+}
+
+/// A tool or resource whose use can be restricted.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum AccessTarget {
+    /// A registered tool.
+    Tool(ToolId),
+    /// A registered resource.
+    Resource(ResourceId),
+}
+
+/// What an account acted on in a recorded extrinsic.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ActivitySubject {
+    /// A server.
+    Server(ServerId),
+    /// A tool.
+    Tool(ToolId),
+    /// A prompt template.
+    Prompt(PromptId),
+    /// A resource.
+    Resource(ResourceId),
+    /// An execution request.
+    Execution(RequestId),
+    /// A sampling request.
+    Sampling(SamplingRequestId),
+}
+
+impl From<AccessTarget> for ActivitySubject {
+    fn from(target: AccessTarget) -> Self {
+        match target {
+            AccessTarget::Tool(id) => Self::Tool(id),
+            AccessTarget::Resource(id) => Self::Resource(id),
+        }
+    }
+}
+
+/// An extrinsic of an account that changed the registry, as kept in its activity history.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ActivityRecord<BlockNumber> {
+    /// Block in which the extrinsic was included.
+    pub block: BlockNumber,
+    /// Index of the extrinsic within its block.
+    pub extrinsic_index: u32,
+    /// What the extrinsic acted on.
+    pub subject: ActivitySubject,
+}
+
+/// Who may use a tool or resource. The owner of the offering server always may.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    CloneNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    DefaultNoBound,
+    RuntimeDebugNoBound,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub enum AccessRule<T: Config> {
+    /// Any account.
+    #[default]
+    Public,
+    /// Only the listed accounts.
+    Allowlist(BoundedVec<T::AccountId, T::MaxAllowlistLength>),
+    /// Accounts holding at least `min_balance`, reserved funds included.
+    TokenGated {
+        /// Smallest total balance that grants access.
+        min_balance: BalanceOf<T>,
+    },
+}
+
+/// Asset a server deposit or an execution fee is paid in.
+///
+/// Amounts are always denominated in the native currency; paying in credits moves the equivalent
+/// amount of the compute-credit asset, at `NativePerCredit`, into the pallet's account until the
+/// deposit or fee is released.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum PaymentAsset {
+    /// The native currency, reserved from the payer.
+    #[default]
+    Native,
+    /// The compute-credit asset, held in the pallet's account.
+    Credits,
+}
+
+/// What a tool charges for a run.
+///
+/// Tools without a pricing model accept any fee of at least `MinExecutionFee` offered by the
+/// requester.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    CloneNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    RuntimeDebugNoBound,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub enum PricingModel<T: Config> {
+    /// Runs cost nothing.
+    Free,
+    /// Every run costs the same fee.
+    PerCall(BalanceOf<T>),
+    /// A paid run covers all runs of the caller for `period_blocks` blocks after it completes.
+    Subscription {
+        /// Blocks a subscription lasts.
+        period_blocks: BlockNumberFor<T>,
+        /// Fee of the run that starts or renews a subscription.
+        price: BalanceOf<T>,
+    },
+}
+
+/// An account's use of a tool.
+#[derive(
+    Encode,
+    Decode,
+    CloneNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    DefaultNoBound,
+    RuntimeDebugNoBound,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct ToolUsage<T: Config> {
+    /// Completed runs.
+    pub calls: u32,
+    /// Fees paid for the completed runs.
+    pub paid: BalanceOf<T>,
+    /// Last block covered by the account's subscription, if it ever subscribed.
+    pub subscribed_until: Option<BlockNumberFor<T>>,
+}
+
+/// Liveness of a server's endpoint as observed by the health reporters.
+#[derive(
+    Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct HealthStatus<BlockNumber> {
+    /// Whether the last probe reached the server.
+    pub healthy: bool,
+    /// Round-trip time of the last successful probe, in milliseconds.
+    pub latency_ms: u32,
+    /// Failed probes since the last successful one.
+    pub consecutive_failures: u32,
+    /// Block at which the last report was recorded.
+    pub last_report: BlockNumber,
+}
+
+impl<BlockNumber: Saturating + PartialOrd + Copy> HealthStatus<BlockNumber> {
+    /// Record the outcome of one probe.
+    ///
+    /// Failures count at most once per `interval` blocks: several reporters probing the same
+    /// server within one interval must not deactivate it sooner, so a failure reported less than
+    /// `interval` blocks after the last counted one is ignored.
+    pub fn record(
+        &mut self,
+        healthy: bool,
+        latency_ms: u32,
+        now: BlockNumber,
+        interval: BlockNumber,
+    ) {
+        if healthy {
+            self.latency_ms = latency_ms;
+            self.consecutive_failures = 0;
+        } else if self.consecutive_failures == 0 || now >= self.last_report.saturating_add(interval)
+        {
+            self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+        } else {
+            return;
+        }
+        self.healthy = healthy;
+        self.last_report = now;
+    }
+}
+
+/// The outcome of probing a single server.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub struct HealthReport {
+    /// The probed server.
+    pub server_id: ServerId,
+    /// Whether the server answered in time.
+    pub healthy: bool,
+    /// Round-trip time in milliseconds; ignored for failed probes.
+    pub latency_ms: u32,
+}
+
+/// Weight of a fresh rating. Weights halve every `ReputationHalfLife` blocks, so a rating stops
+/// counting after about 20 half-lives.
+pub const RATING_WEIGHT: u64 = 1 << 20;
+
+/// Lowest and highest score of a rating.
+pub const RATING_RANGE: core::ops::RangeInclusive<u8> = 1..=5;
+
+/// Aggregated ratings of a tool or server, with older ratings counting less.
+#[derive(
+    Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct ReputationScore<BlockNumber> {
+    /// Decayed sum of each rating's score times its weight.
+    pub weighted_sum: u64,
+    /// Decayed sum of the rating weights.
+    pub total_weight: u64,
+    /// Number of ratings received.
+    pub ratings: u32,
+    /// Block up to which the decay was applied.
+    pub decayed_at: BlockNumber,
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> ReputationScore<BlockNumber> {
+    /// Decay the earlier ratings to `now` and add a rating of `score`.
+    pub fn record(&mut self, score: u8, now: BlockNumber, half_life: BlockNumber) {
+        self.decay(now, half_life);
+        self.weighted_sum = self
+            .weighted_sum
+            .saturating_add(RATING_WEIGHT.saturating_mul(score.into()));
+        self.total_weight = self.total_weight.saturating_add(RATING_WEIGHT);
+        self.ratings = self.ratings.saturating_add(1);
+    }
+
+    /// Halve the weights once for every whole half-life since they were last decayed.
+    pub fn decay(&mut self, now: BlockNumber, half_life: BlockNumber) {
+        if self.total_weight == 0 || half_life.is_zero() {
+            self.decayed_at = now;
+            return;
+        }
+        let halvings = now.saturating_sub(self.decayed_at) / half_life;
+        // Blocks short of a whole half-life count towards the next halving
+        self.decayed_at = self
+            .decayed_at
+            .saturating_add(halvings.saturating_mul(half_life));
+        let shift: u32 = halvings.unique_saturated_into();
+        self.weighted_sum = self.weighted_sum.checked_shr(shift).unwrap_or_default();
+        self.total_weight = self.total_weight.checked_shr(shift).unwrap_or_default();
+    }
+
+    /// Weighted average score in hundredths of a point (100 to 500), or `None` without ratings
+    /// that still count.
+    pub fn average(&self) -> Option<u32> {
+        (self.total_weight > 0).then(|| {
+            (self.weighted_sum.saturating_mul(100) / self.total_weight).unique_saturated_into()
+        })
+    }
+}
+
+/// The identifiers the next registrations and requests of each kind will be assigned, as
+/// returned by [`Pallet::next_ids`].
+///
+/// Identifiers are assigned in increasing order and never reused, so a client can tell the
+/// identifier of an item before submitting it, as long as no other extrinsic of the same kind is
+/// included before its own.
+///
+/// [`Pallet::next_ids`]: crate::Pallet::next_ids
+#[derive(Encode, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct NextIds {
+    /// The next server.
+    pub server: ServerId,
+    /// The next tool.
+    pub tool: ToolId,
+    /// The next prompt template.
+    pub prompt: PromptId,
+    /// The next resource.
+    pub resource: ResourceId,
+    /// The next execution request.
+    pub request: RequestId,
+    /// The next sampling request.
+    pub sampling_request: SamplingRequestId,
+}
+
+/// Everything an account has in the registry, as returned by [`Pallet::account_overview`].
+///
+/// [`Pallet::account_overview`]: crate::Pallet::account_overview
+#[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct AccountOverview<T: Config> {
+    /// Servers owned by the account, in registration order.
+    pub servers: Vec<(ServerId, ServerInfo<T>)>,
+    /// Tools offered by the account's servers, server by server.
+    pub tools: Vec<(ToolId, ToolInfo<T>)>,
+    /// Tools the account is subscribed to, with the last block of each subscription.
+    pub tool_subscriptions: Vec<(ToolId, BlockNumberFor<T>)>,
+    /// Resources whose changes the account follows.
+    pub resource_subscriptions: Vec<ResourceId>,
+    /// Unsettled execution requests made by the account.
+    pub requested: Vec<(RequestId, ExecutionRequest<T>)>,
+    /// Unanswered execution requests to the account's servers.
+    pub assigned: Vec<(RequestId, ExecutionRequest<T>)>,
+    /// The account's latest extrinsics, oldest first.
+    pub activity: Vec<ActivityRecord<BlockNumberFor<T>>>,
+}
@@ -0,0 +1,78 @@
+//! Checks of the hosts server endpoints point to.
+//!
+//! Health reporters send requests from validator nodes to the endpoints servers register, so an
+//! endpoint must not reach into the network those nodes run in. These checks only look at the URL:
+//! a public name that resolves to a private address is not caught.
+
+use core::net::{Ipv4Addr, Ipv6Addr};
+use core::str::FromStr;
+
+/// Whether the host of `url` may be public: neither `localhost` nor a loopback, private,
+/// link-local or unspecified address.
+///
+/// Hosts whose last label is numeric must be a dotted-quad IPv4 address, since resolvers also
+/// accept forms like `127.1` or `2130706433` for loopback.
+pub fn is_public_host(url: &[u8]) -> bool {
+    let Some(host) = host(url) else {
+        return false;
+    };
+    if let Some(literal) = host.strip_prefix('[').and_then(|h| h.strip_suffix(']')) {
+        return Ipv6Addr::from_str(literal).is_ok_and(is_public_v6);
+    }
+
+    let host = host.strip_suffix('.').unwrap_or(host);
+    let last_label = host.rsplit('.').next().unwrap_or_default();
+    if last_label.eq_ignore_ascii_case("localhost") {
+        return false;
+    }
+    let numeric = last_label.bytes().all(|c| c.is_ascii_digit())
+        || last_label.starts_with("0x")
+        || last_label.starts_with("0X");
+    if numeric {
+        return Ipv4Addr::from_str(host).is_ok_and(is_public_v4);
+    }
+    !host.is_empty()
+}
+
+/// The host of `url`, without scheme, credentials or port.
+fn host(url: &[u8]) -> Option<&str> {
+    let url = core::str::from_utf8(url).ok()?;
+    let (_, rest) = url.split_once("://")?;
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host_port = authority
+        .rsplit_once('@')
+        .map_or(authority, |(_, host)| host);
+    if host_port.starts_with('[') {
+        let end = host_port.find(']')?;
+        return Some(&host_port[..=end]);
+    }
+    Some(
+        host_port
+            .split_once(':')
+            .map_or(host_port, |(host, _)| host),
+    )
+}
+
+fn is_public_v4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+    !(ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        // Shared address space (100.64.0.0/10) is private to the operator's network
+        || (a == 100 && (64..128).contains(&b))
+        || a == 0)
+}
+
+fn is_public_v6(ip: Ipv6Addr) -> bool {
+    if let Some(mapped) = ip.to_ipv4_mapped() {
+        return is_public_v4(mapped);
+    }
+    let first = ip.segments()[0];
+    !(ip.is_loopback()
+        || ip.is_unspecified()
+        // Unique local (fc00::/7) and link-local (fe80::/10) addresses
+        || (first & 0xfe00) == 0xfc00
+        || (first & 0xffc0) == 0xfe80)
+}
@@ -0,0 +1,2153 @@
+//! Placeholder weights for `pallet_mcp`
+//!
+//! THESE WEIGHTS WERE NOT PRODUCED BY THE BENCHMARK CLI. The storage accesses and proof sizes
+//! follow the benchmarked code paths, but the execution times are estimates, not measurements.
+//! Replace this file with the output of the command below, run on reference hardware, before
+//! relying on these weights on a production chain.
+
+// Command to regenerate, with a node built with `--features runtime-benchmarks`:
+// ./target/release/mod-net-node
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_mcp
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./pallets/mcp/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_mcp`.
+pub trait WeightInfo {
+	fn register_server() -> Weight;
+	fn register_server_with_catalog(t: u32, p: u32, r: u32, ) -> Weight;
+	fn pay_with_credits() -> Weight;
+	fn update_server() -> Weight;
+	fn upgrade_server_protocol() -> Weight;
+	fn deactivate_server() -> Weight;
+	fn activate_server() -> Weight;
+	fn renew_server() -> Weight;
+	fn deregister_server(t: u32, p: u32, r: u32, ) -> Weight;
+	fn check_expiry() -> Weight;
+	fn expire_server(t: u32, p: u32, r: u32, ) -> Weight;
+	fn register_tool() -> Weight;
+	fn update_tool() -> Weight;
+	fn batch_update_tools(n: u32, ) -> Weight;
+	fn set_tool_active() -> Weight;
+	fn remove_tool() -> Weight;
+	fn set_tool_pricing() -> Weight;
+	fn register_sealed_tool() -> Weight;
+	fn reveal_tool() -> Weight;
+	fn add_prompt() -> Weight;
+	fn update_prompt() -> Weight;
+	fn remove_prompt() -> Weight;
+	fn add_resource() -> Weight;
+	fn update_resource(s: u32, ) -> Weight;
+	fn remove_resource() -> Weight;
+	fn subscribe_resource() -> Weight;
+	fn unsubscribe_resource() -> Weight;
+	fn slash_server(t: u32, p: u32, r: u32, ) -> Weight;
+	fn verify_server() -> Weight;
+	fn revoke_verification() -> Weight;
+	fn request_tool_execution() -> Weight;
+	fn submit_execution_result() -> Weight;
+	fn cancel_execution() -> Weight;
+	fn create_sampling_request() -> Weight;
+	fn submit_sampling_result() -> Weight;
+	fn cancel_sampling_request() -> Weight;
+	fn set_access_policy() -> Weight;
+	fn grant_access() -> Weight;
+	fn revoke_access() -> Weight;
+	fn report_server_health(n: u32, ) -> Weight;
+	fn rate_tool() -> Weight;
+}
+
+/// Weights for `pallet_mcp` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Mcp::ServersByOwner` (r:1 w:1)
+	/// Proof: `Mcp::ServersByOwner` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::NextServerId` (r:1 w:1)
+	/// Proof: `Mcp::NextServerId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:0 w:1)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn register_server() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3642`
+		// Minimum execution time: 36_000_000 picoseconds.
+		Weight::from_parts(38_000_000, 3642)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: `Mcp::ServersByOwner` (r:1 w:1)
+	/// Proof: `Mcp::ServersByOwner` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::NextServerId` (r:1 w:1)
+	/// Proof: `Mcp::NextServerId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:0 w:1)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolIdByName` (r:64 w:64)
+	/// Proof: `Mcp::ToolIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolsByServer` (r:1 w:1)
+	/// Proof: `Mcp::ToolsByServer` (`max_values`: None, `max_size`: Some(530), added: 3005, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::NextToolId` (r:1 w:1)
+	/// Proof: `Mcp::NextToolId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Tools` (r:0 w:64)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PromptIdByName` (r:32 w:32)
+	/// Proof: `Mcp::PromptIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PromptsByServer` (r:1 w:1)
+	/// Proof: `Mcp::PromptsByServer` (`max_values`: None, `max_size`: Some(273), added: 2748, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::NextPromptId` (r:1 w:1)
+	/// Proof: `Mcp::NextPromptId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Prompts` (r:0 w:32)
+	/// Proof: `Mcp::Prompts` (`max_values`: None, `max_size`: Some(6562), added: 9037, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceIdByUri` (r:64 w:64)
+	/// Proof: `Mcp::ResourceIdByUri` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourcesByServer` (r:1 w:1)
+	/// Proof: `Mcp::ResourcesByServer` (`max_values`: None, `max_size`: Some(530), added: 3005, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::NextResourceId` (r:1 w:1)
+	/// Proof: `Mcp::NextResourceId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Resources` (r:0 w:64)
+	/// Proof: `Mcp::Resources` (`max_values`: None, `max_size`: Some(802), added: 3277, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	/// The range of component `t` is `[0, 64]`.
+	/// The range of component `p` is `[0, 32]`.
+	/// The range of component `r` is `[0, 64]`.
+	fn register_server_with_catalog(t: u32, p: u32, r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42 + t * (0 ±0) + p * (0 ±0) + r * (0 ±0)`
+		//  Estimated: `3642 + t * (2580 ±0) + p * (2580 ±0) + r * (2773 ±0)`
+		// Minimum execution time: 37_000_000 picoseconds.
+		Weight::from_parts(39_000_000, 3642)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(15_000_000, 0).saturating_mul(t.into()))
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(p.into()))
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(14_000_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2580).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(0, 2580).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 2773).saturating_mul(r.into()))
+	}
+
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 3675, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 3599, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn pay_with_credits() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `210`
+		//  Estimated: `6198`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(33_000_000, 6198)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: `Mcp::Servers` (r:1 w:1)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerVerification` (r:1 w:1)
+	/// Proof: `Mcp::ServerVerification` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn update_server() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `340`
+		//  Estimated: `4352`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 4352)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: `Mcp::Servers` (r:1 w:1)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn upgrade_server_protocol() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `340`
+		//  Estimated: `4352`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 4352)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: `Mcp::Servers` (r:1 w:1)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn deactivate_server() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `340`
+		//  Estimated: `4352`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 4352)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: `Mcp::Servers` (r:1 w:1)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerHealth` (r:1 w:1)
+	/// Proof: `Mcp::ServerHealth` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn activate_server() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `341`
+		//  Estimated: `4352`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 4352)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: `Mcp::Servers` (r:1 w:1)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn renew_server() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `340`
+		//  Estimated: `4352`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 4352)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: `Mcp::Servers` (r:1 w:1)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolsByServer` (r:1 w:1)
+	/// Proof: `Mcp::ToolsByServer` (`max_values`: None, `max_size`: Some(530), added: 3005, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Tools` (r:64 w:64)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::SealedTools` (r:64 w:64)
+	/// Proof: `Mcp::SealedTools` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolIdByName` (r:0 w:64)
+	/// Proof: `Mcp::ToolIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::AccessRules` (r:0 w:128)
+	/// Proof: `Mcp::AccessRules` (`max_values`: None, `max_size`: Some(2075), added: 4550, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PromptsByServer` (r:1 w:1)
+	/// Proof: `Mcp::PromptsByServer` (`max_values`: None, `max_size`: Some(273), added: 2748, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Prompts` (r:32 w:32)
+	/// Proof: `Mcp::Prompts` (`max_values`: None, `max_size`: Some(6562), added: 9037, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PromptIdByName` (r:0 w:32)
+	/// Proof: `Mcp::PromptIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourcesByServer` (r:1 w:1)
+	/// Proof: `Mcp::ResourcesByServer` (`max_values`: None, `max_size`: Some(530), added: 3005, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Resources` (r:64 w:64)
+	/// Proof: `Mcp::Resources` (`max_values`: None, `max_size`: Some(802), added: 3277, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceIdByUri` (r:0 w:64)
+	/// Proof: `Mcp::ResourceIdByUri` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceSubscribers` (r:0 w:64)
+	/// Proof: `Mcp::ResourceSubscribers` (`max_values`: None, `max_size`: Some(2069), added: 4544, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerHealth` (r:0 w:1)
+	/// Proof: `Mcp::ServerHealth` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerVerification` (r:0 w:1)
+	/// Proof: `Mcp::ServerVerification` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolPricing` (r:0 w:64)
+	/// Proof: `Mcp::ToolPricing` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolReputation` (r:0 w:64)
+	/// Proof: `Mcp::ToolReputation` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerReputation` (r:0 w:1)
+	/// Proof: `Mcp::ServerReputation` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerEscrow` (r:0 w:1)
+	/// Proof: `Mcp::ServerEscrow` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServersByOwner` (r:1 w:1)
+	/// Proof: `Mcp::ServersByOwner` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::CreditDeposits` (r:1 w:1)
+	/// Proof: `Mcp::CreditDeposits` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::SamplingHistory` (r:0 w:1)
+	/// Proof: `Mcp::SamplingHistory` (`max_values`: None, `max_size`: Some(3761), added: 6236, mode: `MaxEncodedLen`)
+	/// The range of component `t` is `[0, 64]`.
+	/// The range of component `p` is `[0, 32]`.
+	/// The range of component `r` is `[0, 64]`.
+	fn deregister_server(t: u32, p: u32, r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `603 + t * (8593 ±0) + p * (6610 ±0) + r * (850 ±0)`
+		//  Estimated: `4352 + t * (11020 ±0) + p * (9037 ±0) + r * (3277 ±0)`
+		// Minimum execution time: 47_000_000 picoseconds.
+		Weight::from_parts(49_000_000, 4352)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(t.into()))
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(p.into()))
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(7_000_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 11020).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(0, 9037).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 3277).saturating_mul(r.into()))
+	}
+
+	/// Storage: `Mcp::NextServerId` (r:1 w:0)
+	/// Proof: `Mcp::NextServerId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ExpiryCursor` (r:1 w:1)
+	/// Proof: `Mcp::ExpiryCursor` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	fn check_expiry() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `340`
+		//  Estimated: `4352`
+		// Minimum execution time: 6_000_000 picoseconds.
+		Weight::from_parts(7_000_000, 4352)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: `Mcp::NextServerId` (r:1 w:0)
+	/// Proof: `Mcp::NextServerId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ExpiryCursor` (r:1 w:1)
+	/// Proof: `Mcp::ExpiryCursor` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:1)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolsByServer` (r:1 w:1)
+	/// Proof: `Mcp::ToolsByServer` (`max_values`: None, `max_size`: Some(530), added: 3005, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Tools` (r:64 w:64)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::SealedTools` (r:64 w:64)
+	/// Proof: `Mcp::SealedTools` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolIdByName` (r:0 w:64)
+	/// Proof: `Mcp::ToolIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::AccessRules` (r:0 w:128)
+	/// Proof: `Mcp::AccessRules` (`max_values`: None, `max_size`: Some(2075), added: 4550, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PromptsByServer` (r:1 w:1)
+	/// Proof: `Mcp::PromptsByServer` (`max_values`: None, `max_size`: Some(273), added: 2748, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Prompts` (r:32 w:32)
+	/// Proof: `Mcp::Prompts` (`max_values`: None, `max_size`: Some(6562), added: 9037, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PromptIdByName` (r:0 w:32)
+	/// Proof: `Mcp::PromptIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourcesByServer` (r:1 w:1)
+	/// Proof: `Mcp::ResourcesByServer` (`max_values`: None, `max_size`: Some(530), added: 3005, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Resources` (r:64 w:64)
+	/// Proof: `Mcp::Resources` (`max_values`: None, `max_size`: Some(802), added: 3277, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceIdByUri` (r:0 w:64)
+	/// Proof: `Mcp::ResourceIdByUri` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceSubscribers` (r:0 w:64)
+	/// Proof: `Mcp::ResourceSubscribers` (`max_values`: None, `max_size`: Some(2069), added: 4544, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerHealth` (r:0 w:1)
+	/// Proof: `Mcp::ServerHealth` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerVerification` (r:0 w:1)
+	/// Proof: `Mcp::ServerVerification` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolPricing` (r:0 w:64)
+	/// Proof: `Mcp::ToolPricing` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolReputation` (r:0 w:64)
+	/// Proof: `Mcp::ToolReputation` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerReputation` (r:0 w:1)
+	/// Proof: `Mcp::ServerReputation` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerEscrow` (r:0 w:1)
+	/// Proof: `Mcp::ServerEscrow` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServersByOwner` (r:1 w:1)
+	/// Proof: `Mcp::ServersByOwner` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::CreditDeposits` (r:1 w:1)
+	/// Proof: `Mcp::CreditDeposits` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::SamplingHistory` (r:0 w:1)
+	/// Proof: `Mcp::SamplingHistory` (`max_values`: None, `max_size`: Some(3761), added: 6236, mode: `MaxEncodedLen`)
+	/// The range of component `t` is `[0, 64]`.
+	/// The range of component `p` is `[0, 32]`.
+	/// The range of component `r` is `[0, 64]`.
+	fn expire_server(t: u32, p: u32, r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `603 + t * (8593 ±0) + p * (6610 ±0) + r * (850 ±0)`
+		//  Estimated: `4352 + t * (11020 ±0) + p * (9037 ±0) + r * (3277 ±0)`
+		// Minimum execution time: 45_000_000 picoseconds.
+		Weight::from_parts(47_000_000, 4352)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(t.into()))
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(p.into()))
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(7_000_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 11020).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(0, 9037).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 3277).saturating_mul(r.into()))
+	}
+
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolIdByName` (r:1 w:1)
+	/// Proof: `Mcp::ToolIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolsByServer` (r:1 w:1)
+	/// Proof: `Mcp::ToolsByServer` (`max_values`: None, `max_size`: Some(530), added: 3005, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::NextToolId` (r:1 w:1)
+	/// Proof: `Mcp::NextToolId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Tools` (r:0 w:1)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn register_tool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `393`
+		//  Estimated: `4352`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(28_000_000, 4352)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: `Mcp::Tools` (r:1 w:1)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolIdByName` (r:1 w:2)
+	/// Proof: `Mcp::ToolIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn update_tool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8937`
+		//  Estimated: `12010`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(33_000_000, 12010)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: `Mcp::Tools` (r:64 w:64)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolIdByName` (r:64 w:128)
+	/// Proof: `Mcp::ToolIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 64]`.
+	fn batch_update_tools(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `393 + n * (8593 ±0)`
+		//  Estimated: `4352 + n * (11020 ±0)`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 4352)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(22_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 11020).saturating_mul(n.into()))
+	}
+
+	/// Storage: `Mcp::Tools` (r:1 w:1)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn set_tool_active() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8937`
+		//  Estimated: `12010`
+		// Minimum execution time: 22_000_000 picoseconds.
+		Weight::from_parts(23_000_000, 12010)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: `Mcp::Tools` (r:1 w:1)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::SealedTools` (r:1 w:1)
+	/// Proof: `Mcp::SealedTools` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolIdByName` (r:0 w:1)
+	/// Proof: `Mcp::ToolIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::AccessRules` (r:0 w:1)
+	/// Proof: `Mcp::AccessRules` (`max_values`: None, `max_size`: Some(2075), added: 4550, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolPricing` (r:0 w:1)
+	/// Proof: `Mcp::ToolPricing` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolReputation` (r:0 w:1)
+	/// Proof: `Mcp::ToolReputation` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolsByServer` (r:1 w:1)
+	/// Proof: `Mcp::ToolsByServer` (`max_values`: None, `max_size`: Some(530), added: 3005, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn remove_tool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `9001`
+		//  Estimated: `12010`
+		// Minimum execution time: 35_000_000 picoseconds.
+		Weight::from_parts(36_000_000, 12010)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+
+	/// Storage: `Mcp::Tools` (r:1 w:0)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolPricing` (r:0 w:1)
+	/// Proof: `Mcp::ToolPricing` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn set_tool_pricing() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8937`
+		//  Estimated: `12010`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 12010)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolIdByName` (r:1 w:1)
+	/// Proof: `Mcp::ToolIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolsByServer` (r:1 w:1)
+	/// Proof: `Mcp::ToolsByServer` (`max_values`: None, `max_size`: Some(530), added: 3005, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::NextToolId` (r:1 w:1)
+	/// Proof: `Mcp::NextToolId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::SealedTools` (r:0 w:1)
+	/// Proof: `Mcp::SealedTools` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn register_sealed_tool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `393`
+		//  Estimated: `4352`
+		// Minimum execution time: 26_000_000 picoseconds.
+		Weight::from_parts(27_000_000, 4352)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: `Mcp::SealedTools` (r:1 w:1)
+	/// Proof: `Mcp::SealedTools` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Tools` (r:0 w:1)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn reveal_tool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `553`
+		//  Estimated: `4352`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(40_000_000, 4352)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PromptIdByName` (r:1 w:1)
+	/// Proof: `Mcp::PromptIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PromptsByServer` (r:1 w:1)
+	/// Proof: `Mcp::PromptsByServer` (`max_values`: None, `max_size`: Some(273), added: 2748, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::NextPromptId` (r:1 w:1)
+	/// Proof: `Mcp::NextPromptId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Prompts` (r:0 w:1)
+	/// Proof: `Mcp::Prompts` (`max_values`: None, `max_size`: Some(6562), added: 9037, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn add_prompt() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `393`
+		//  Estimated: `4352`
+		// Minimum execution time: 30_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 4352)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: `Mcp::Prompts` (r:1 w:1)
+	/// Proof: `Mcp::Prompts` (`max_values`: None, `max_size`: Some(6562), added: 9037, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PromptIdByName` (r:1 w:2)
+	/// Proof: `Mcp::PromptIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn update_prompt() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6954`
+		//  Estimated: `10027`
+		// Minimum execution time: 33_000_000 picoseconds.
+		Weight::from_parts(35_000_000, 10027)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: `Mcp::Prompts` (r:1 w:1)
+	/// Proof: `Mcp::Prompts` (`max_values`: None, `max_size`: Some(6562), added: 9037, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PromptIdByName` (r:0 w:1)
+	/// Proof: `Mcp::PromptIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PromptsByServer` (r:1 w:1)
+	/// Proof: `Mcp::PromptsByServer` (`max_values`: None, `max_size`: Some(273), added: 2748, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn remove_prompt() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7018`
+		//  Estimated: `10027`
+		// Minimum execution time: 30_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 10027)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceIdByUri` (r:1 w:1)
+	/// Proof: `Mcp::ResourceIdByUri` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourcesByServer` (r:1 w:1)
+	/// Proof: `Mcp::ResourcesByServer` (`max_values`: None, `max_size`: Some(530), added: 3005, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::NextResourceId` (r:1 w:1)
+	/// Proof: `Mcp::NextResourceId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Resources` (r:0 w:1)
+	/// Proof: `Mcp::Resources` (`max_values`: None, `max_size`: Some(802), added: 3277, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn add_resource() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `393`
+		//  Estimated: `4352`
+		// Minimum execution time: 28_000_000 picoseconds.
+		Weight::from_parts(29_000_000, 4352)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: `Mcp::Resources` (r:1 w:1)
+	/// Proof: `Mcp::Resources` (`max_values`: None, `max_size`: Some(802), added: 3277, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceIdByUri` (r:1 w:2)
+	/// Proof: `Mcp::ResourceIdByUri` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceSubscribers` (r:1 w:0)
+	/// Proof: `Mcp::ResourceSubscribers` (`max_values`: None, `max_size`: Some(2069), added: 4544, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 64]`.
+	fn update_resource(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1262 + s * (32 ±0)`
+		//  Estimated: `5534 + s * (0 ±0)`
+		// Minimum execution time: 32_000_000 picoseconds.
+		Weight::from_parts(33_000_000, 5534)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: `Mcp::Resources` (r:1 w:1)
+	/// Proof: `Mcp::Resources` (`max_values`: None, `max_size`: Some(802), added: 3277, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceIdByUri` (r:0 w:1)
+	/// Proof: `Mcp::ResourceIdByUri` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::AccessRules` (r:0 w:1)
+	/// Proof: `Mcp::AccessRules` (`max_values`: None, `max_size`: Some(2075), added: 4550, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceSubscribers` (r:0 w:1)
+	/// Proof: `Mcp::ResourceSubscribers` (`max_values`: None, `max_size`: Some(2069), added: 4544, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourcesByServer` (r:1 w:1)
+	/// Proof: `Mcp::ResourcesByServer` (`max_values`: None, `max_size`: Some(530), added: 3005, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn remove_resource() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1258`
+		//  Estimated: `4352`
+		// Minimum execution time: 30_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 4352)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+
+	/// Storage: `Mcp::Resources` (r:1 w:0)
+	/// Proof: `Mcp::Resources` (`max_values`: None, `max_size`: Some(802), added: 3277, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::AccessRules` (r:1 w:0)
+	/// Proof: `Mcp::AccessRules` (`max_values`: None, `max_size`: Some(2075), added: 4550, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceSubscribers` (r:1 w:1)
+	/// Proof: `Mcp::ResourceSubscribers` (`max_values`: None, `max_size`: Some(2069), added: 4544, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceSubscriptionsByAccount` (r:0 w:1)
+	/// Proof: `Mcp::ResourceSubscriptionsByAccount` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn subscribe_resource() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5282`
+		//  Estimated: `5540`
+		// Minimum execution time: 34_000_000 picoseconds.
+		Weight::from_parts(36_000_000, 5540)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: `Mcp::ResourceSubscribers` (r:1 w:1)
+	/// Proof: `Mcp::ResourceSubscribers` (`max_values`: None, `max_size`: Some(2069), added: 4544, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceSubscriptionsByAccount` (r:0 w:1)
+	/// Proof: `Mcp::ResourceSubscriptionsByAccount` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn unsubscribe_resource() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2151`
+		//  Estimated: `5534`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 5534)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: `Mcp::Servers` (r:1 w:1)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolsByServer` (r:1 w:1)
+	/// Proof: `Mcp::ToolsByServer` (`max_values`: None, `max_size`: Some(530), added: 3005, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Tools` (r:64 w:64)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::SealedTools` (r:64 w:64)
+	/// Proof: `Mcp::SealedTools` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolIdByName` (r:0 w:64)
+	/// Proof: `Mcp::ToolIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::AccessRules` (r:0 w:128)
+	/// Proof: `Mcp::AccessRules` (`max_values`: None, `max_size`: Some(2075), added: 4550, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PromptsByServer` (r:1 w:1)
+	/// Proof: `Mcp::PromptsByServer` (`max_values`: None, `max_size`: Some(273), added: 2748, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Prompts` (r:32 w:32)
+	/// Proof: `Mcp::Prompts` (`max_values`: None, `max_size`: Some(6562), added: 9037, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PromptIdByName` (r:0 w:32)
+	/// Proof: `Mcp::PromptIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourcesByServer` (r:1 w:1)
+	/// Proof: `Mcp::ResourcesByServer` (`max_values`: None, `max_size`: Some(530), added: 3005, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Resources` (r:64 w:64)
+	/// Proof: `Mcp::Resources` (`max_values`: None, `max_size`: Some(802), added: 3277, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceIdByUri` (r:0 w:64)
+	/// Proof: `Mcp::ResourceIdByUri` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceSubscribers` (r:0 w:64)
+	/// Proof: `Mcp::ResourceSubscribers` (`max_values`: None, `max_size`: Some(2069), added: 4544, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerHealth` (r:0 w:1)
+	/// Proof: `Mcp::ServerHealth` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerVerification` (r:0 w:1)
+	/// Proof: `Mcp::ServerVerification` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolPricing` (r:0 w:64)
+	/// Proof: `Mcp::ToolPricing` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolReputation` (r:0 w:64)
+	/// Proof: `Mcp::ToolReputation` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerReputation` (r:0 w:1)
+	/// Proof: `Mcp::ServerReputation` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerEscrow` (r:0 w:1)
+	/// Proof: `Mcp::ServerEscrow` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServersByOwner` (r:1 w:1)
+	/// Proof: `Mcp::ServersByOwner` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::CreditDeposits` (r:1 w:1)
+	/// Proof: `Mcp::CreditDeposits` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::SamplingHistory` (r:0 w:1)
+	/// Proof: `Mcp::SamplingHistory` (`max_values`: None, `max_size`: Some(3761), added: 6236, mode: `MaxEncodedLen`)
+	/// The range of component `t` is `[0, 64]`.
+	/// The range of component `p` is `[0, 32]`.
+	/// The range of component `r` is `[0, 64]`.
+	fn slash_server(t: u32, p: u32, r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `603 + t * (8593 ±0) + p * (6610 ±0) + r * (850 ±0)`
+		//  Estimated: `4352 + t * (11020 ±0) + p * (9037 ±0) + r * (3277 ±0)`
+		// Minimum execution time: 51_000_000 picoseconds.
+		Weight::from_parts(53_000_000, 4352)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(t.into()))
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(p.into()))
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(7_000_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 11020).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(0, 9037).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 3277).saturating_mul(r.into()))
+	}
+
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerVerification` (r:0 w:1)
+	/// Proof: `Mcp::ServerVerification` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	fn verify_server() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `340`
+		//  Estimated: `4352`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 4352)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: `Mcp::ServerVerification` (r:1 w:1)
+	/// Proof: `Mcp::ServerVerification` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	fn revoke_verification() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `17`
+		//  Estimated: `3482`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 3482)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: `Mcp::Tools` (r:1 w:0)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:16 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::AccessRules` (r:1 w:0)
+	/// Proof: `Mcp::AccessRules` (`max_values`: None, `max_size`: Some(2075), added: 4550, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServersByOwner` (r:1 w:0)
+	/// Proof: `Mcp::ServersByOwner` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolPricing` (r:1 w:0)
+	/// Proof: `Mcp::ToolPricing` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Usage` (r:1 w:0)
+	/// Proof: `Mcp::Usage` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerEscrow` (r:16 w:1)
+	/// Proof: `Mcp::ServerEscrow` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::NextRequestId` (r:1 w:1)
+	/// Proof: `Mcp::NextRequestId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PendingExecutions` (r:1 w:1)
+	/// Proof: `Mcp::PendingExecutions` (`max_values`: None, `max_size`: Some(529), added: 3004, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::SubscriptionPurchases` (r:0 w:1)
+	/// Proof: `Mcp::SubscriptionPurchases` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ExecutionRequests` (r:0 w:1)
+	/// Proof: `Mcp::ExecutionRequests` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ExecutionBonds` (r:0 w:1)
+	/// Proof: `Mcp::ExecutionBonds` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::RequestsByAccount` (r:1 w:1)
+	/// Proof: `Mcp::RequestsByAccount` (`max_values`: None, `max_size`: Some(185), added: 2660, mode: `MaxEncodedLen`)
+	fn request_tool_execution() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `23518`
+		//  Estimated: `103010`
+		// Minimum execution time: 124_000_000 picoseconds.
+		Weight::from_parts(128_000_000, 103010)
+			.saturating_add(T::DbWeight::get().reads(42_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+
+	/// Storage: `Mcp::ExecutionRequests` (r:1 w:1)
+	/// Proof: `Mcp::ExecutionRequests` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PendingExecutions` (r:1 w:1)
+	/// Proof: `Mcp::PendingExecutions` (`max_values`: None, `max_size`: Some(529), added: 3004, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerEscrow` (r:1 w:1)
+	/// Proof: `Mcp::ServerEscrow` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::SubscriptionPurchases` (r:1 w:1)
+	/// Proof: `Mcp::SubscriptionPurchases` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::RatingCredits` (r:1 w:1)
+	/// Proof: `Mcp::RatingCredits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Usage` (r:1 w:1)
+	/// Proof: `Mcp::Usage` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::CreditEscrow` (r:1 w:1)
+	/// Proof: `Mcp::CreditEscrow` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ExecutionBonds` (r:1 w:1)
+	/// Proof: `Mcp::ExecutionBonds` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::RequestsByAccount` (r:1 w:1)
+	/// Proof: `Mcp::RequestsByAccount` (`max_values`: None, `max_size`: Some(185), added: 2660, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolSubscriptionsByAccount` (r:0 w:1)
+	/// Proof: `Mcp::ToolSubscriptionsByAccount` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn submit_execution_result() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1380`
+		//  Estimated: `6196`
+		// Minimum execution time: 57_000_000 picoseconds.
+		Weight::from_parts(60_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
+
+	/// Storage: `Mcp::ExecutionRequests` (r:1 w:1)
+	/// Proof: `Mcp::ExecutionRequests` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PendingExecutions` (r:1 w:1)
+	/// Proof: `Mcp::PendingExecutions` (`max_values`: None, `max_size`: Some(529), added: 3004, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerEscrow` (r:1 w:1)
+	/// Proof: `Mcp::ServerEscrow` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::SubscriptionPurchases` (r:1 w:1)
+	/// Proof: `Mcp::SubscriptionPurchases` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::CreditEscrow` (r:1 w:1)
+	/// Proof: `Mcp::CreditEscrow` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ExecutionBonds` (r:1 w:1)
+	/// Proof: `Mcp::ExecutionBonds` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::RequestsByAccount` (r:1 w:1)
+	/// Proof: `Mcp::RequestsByAccount` (`max_values`: None, `max_size`: Some(185), added: 2660, mode: `MaxEncodedLen`)
+	fn cancel_execution() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1103`
+		//  Estimated: `3994`
+		// Minimum execution time: 35_000_000 picoseconds.
+		Weight::from_parts(37_000_000, 3994)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::NextSamplingRequestId` (r:1 w:1)
+	/// Proof: `Mcp::NextSamplingRequestId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PendingSamplingRequests` (r:1 w:1)
+	/// Proof: `Mcp::PendingSamplingRequests` (`max_values`: None, `max_size`: Some(529), added: 3004, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::SamplingRequests` (r:0 w:1)
+	/// Proof: `Mcp::SamplingRequests` (`max_values`: None, `max_size`: Some(393), added: 2868, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn create_sampling_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `393`
+		//  Estimated: `4602`
+		// Minimum execution time: 28_000_000 picoseconds.
+		Weight::from_parts(29_000_000, 4602)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: `Mcp::SamplingRequests` (r:1 w:1)
+	/// Proof: `Mcp::SamplingRequests` (`max_values`: None, `max_size`: Some(393), added: 2868, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PendingSamplingRequests` (r:1 w:1)
+	/// Proof: `Mcp::PendingSamplingRequests` (`max_values`: None, `max_size`: Some(529), added: 3004, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::SamplingHistory` (r:1 w:1)
+	/// Proof: `Mcp::SamplingHistory` (`max_values`: None, `max_size`: Some(3761), added: 6236, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn submit_sampling_result() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4201`
+		//  Estimated: `7251`
+		// Minimum execution time: 33_000_000 picoseconds.
+		Weight::from_parts(35_000_000, 7251)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: `Mcp::SamplingRequests` (r:1 w:1)
+	/// Proof: `Mcp::SamplingRequests` (`max_values`: None, `max_size`: Some(393), added: 2868, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PendingSamplingRequests` (r:1 w:1)
+	/// Proof: `Mcp::PendingSamplingRequests` (`max_values`: None, `max_size`: Some(529), added: 3004, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn cancel_sampling_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1530`
+		//  Estimated: `4602`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 4602)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: `Mcp::Tools` (r:1 w:0)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::AccessRules` (r:0 w:1)
+	/// Proof: `Mcp::AccessRules` (`max_values`: None, `max_size`: Some(2075), added: 4550, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn set_access_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `9001`
+		//  Estimated: `12010`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 12010)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: `Mcp::Tools` (r:1 w:0)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::AccessRules` (r:1 w:1)
+	/// Proof: `Mcp::AccessRules` (`max_values`: None, `max_size`: Some(2075), added: 4550, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn grant_access() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `11076`
+		//  Estimated: `12010`
+		// Minimum execution time: 29_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 12010)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: `Mcp::Tools` (r:1 w:0)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::AccessRules` (r:1 w:1)
+	/// Proof: `Mcp::AccessRules` (`max_values`: None, `max_size`: Some(2075), added: 4550, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn revoke_access() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `11076`
+		//  Estimated: `12010`
+		// Minimum execution time: 29_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 12010)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: `GatewayRegistry::Reporters` (r:1 w:0)
+	/// Proof: `GatewayRegistry::Reporters` (`max_values`: Some(1), `max_size`: Some(513), added: 1008, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::LastHealthReport` (r:1 w:1)
+	/// Proof: `Mcp::LastHealthReport` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:64 w:64)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerHealth` (r:64 w:64)
+	/// Proof: `Mcp::ServerHealth` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 64]`.
+	fn report_server_health(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `584 + n * (917 ±0)`
+		//  Estimated: `3517 + n * (3362 ±0)`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 3517)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3362).saturating_mul(n.into()))
+	}
+
+	/// Storage: `Mcp::Tools` (r:1 w:0)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::RatingCredits` (r:1 w:1)
+	/// Proof: `Mcp::RatingCredits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolReputation` (r:1 w:1)
+	/// Proof: `Mcp::ToolReputation` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerReputation` (r:1 w:1)
+	/// Proof: `Mcp::ServerReputation` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	fn rate_tool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8737`
+		//  Estimated: `12010`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(33_000_000, 12010)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `Mcp::ServersByOwner` (r:1 w:1)
+	/// Proof: `Mcp::ServersByOwner` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::NextServerId` (r:1 w:1)
+	/// Proof: `Mcp::NextServerId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:0 w:1)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn register_server() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3642`
+		// Minimum execution time: 36_000_000 picoseconds.
+		Weight::from_parts(38_000_000, 3642)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: `Mcp::ServersByOwner` (r:1 w:1)
+	/// Proof: `Mcp::ServersByOwner` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::NextServerId` (r:1 w:1)
+	/// Proof: `Mcp::NextServerId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:0 w:1)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolIdByName` (r:64 w:64)
+	/// Proof: `Mcp::ToolIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolsByServer` (r:1 w:1)
+	/// Proof: `Mcp::ToolsByServer` (`max_values`: None, `max_size`: Some(530), added: 3005, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::NextToolId` (r:1 w:1)
+	/// Proof: `Mcp::NextToolId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Tools` (r:0 w:64)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PromptIdByName` (r:32 w:32)
+	/// Proof: `Mcp::PromptIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PromptsByServer` (r:1 w:1)
+	/// Proof: `Mcp::PromptsByServer` (`max_values`: None, `max_size`: Some(273), added: 2748, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::NextPromptId` (r:1 w:1)
+	/// Proof: `Mcp::NextPromptId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Prompts` (r:0 w:32)
+	/// Proof: `Mcp::Prompts` (`max_values`: None, `max_size`: Some(6562), added: 9037, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceIdByUri` (r:64 w:64)
+	/// Proof: `Mcp::ResourceIdByUri` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourcesByServer` (r:1 w:1)
+	/// Proof: `Mcp::ResourcesByServer` (`max_values`: None, `max_size`: Some(530), added: 3005, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::NextResourceId` (r:1 w:1)
+	/// Proof: `Mcp::NextResourceId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Resources` (r:0 w:64)
+	/// Proof: `Mcp::Resources` (`max_values`: None, `max_size`: Some(802), added: 3277, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	/// The range of component `t` is `[0, 64]`.
+	/// The range of component `p` is `[0, 32]`.
+	/// The range of component `r` is `[0, 64]`.
+	fn register_server_with_catalog(t: u32, p: u32, r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42 + t * (0 ±0) + p * (0 ±0) + r * (0 ±0)`
+		//  Estimated: `3642 + t * (2580 ±0) + p * (2580 ±0) + r * (2773 ±0)`
+		// Minimum execution time: 37_000_000 picoseconds.
+		Weight::from_parts(39_000_000, 3642)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(15_000_000, 0).saturating_mul(t.into()))
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(p.into()))
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(14_000_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(t.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(t.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2580).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(0, 2580).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 2773).saturating_mul(r.into()))
+	}
+
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 3675, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 3599, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn pay_with_credits() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `210`
+		//  Estimated: `6198`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(33_000_000, 6198)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: `Mcp::Servers` (r:1 w:1)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerVerification` (r:1 w:1)
+	/// Proof: `Mcp::ServerVerification` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn update_server() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `340`
+		//  Estimated: `4352`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 4352)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: `Mcp::Servers` (r:1 w:1)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn upgrade_server_protocol() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `340`
+		//  Estimated: `4352`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 4352)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: `Mcp::Servers` (r:1 w:1)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn deactivate_server() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `340`
+		//  Estimated: `4352`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 4352)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: `Mcp::Servers` (r:1 w:1)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerHealth` (r:1 w:1)
+	/// Proof: `Mcp::ServerHealth` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn activate_server() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `341`
+		//  Estimated: `4352`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 4352)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: `Mcp::Servers` (r:1 w:1)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn renew_server() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `340`
+		//  Estimated: `4352`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 4352)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: `Mcp::Servers` (r:1 w:1)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolsByServer` (r:1 w:1)
+	/// Proof: `Mcp::ToolsByServer` (`max_values`: None, `max_size`: Some(530), added: 3005, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Tools` (r:64 w:64)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::SealedTools` (r:64 w:64)
+	/// Proof: `Mcp::SealedTools` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolIdByName` (r:0 w:64)
+	/// Proof: `Mcp::ToolIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::AccessRules` (r:0 w:128)
+	/// Proof: `Mcp::AccessRules` (`max_values`: None, `max_size`: Some(2075), added: 4550, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PromptsByServer` (r:1 w:1)
+	/// Proof: `Mcp::PromptsByServer` (`max_values`: None, `max_size`: Some(273), added: 2748, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Prompts` (r:32 w:32)
+	/// Proof: `Mcp::Prompts` (`max_values`: None, `max_size`: Some(6562), added: 9037, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PromptIdByName` (r:0 w:32)
+	/// Proof: `Mcp::PromptIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourcesByServer` (r:1 w:1)
+	/// Proof: `Mcp::ResourcesByServer` (`max_values`: None, `max_size`: Some(530), added: 3005, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Resources` (r:64 w:64)
+	/// Proof: `Mcp::Resources` (`max_values`: None, `max_size`: Some(802), added: 3277, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceIdByUri` (r:0 w:64)
+	/// Proof: `Mcp::ResourceIdByUri` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceSubscribers` (r:0 w:64)
+	/// Proof: `Mcp::ResourceSubscribers` (`max_values`: None, `max_size`: Some(2069), added: 4544, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerHealth` (r:0 w:1)
+	/// Proof: `Mcp::ServerHealth` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerVerification` (r:0 w:1)
+	/// Proof: `Mcp::ServerVerification` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolPricing` (r:0 w:64)
+	/// Proof: `Mcp::ToolPricing` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolReputation` (r:0 w:64)
+	/// Proof: `Mcp::ToolReputation` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerReputation` (r:0 w:1)
+	/// Proof: `Mcp::ServerReputation` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerEscrow` (r:0 w:1)
+	/// Proof: `Mcp::ServerEscrow` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServersByOwner` (r:1 w:1)
+	/// Proof: `Mcp::ServersByOwner` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::CreditDeposits` (r:1 w:1)
+	/// Proof: `Mcp::CreditDeposits` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::SamplingHistory` (r:0 w:1)
+	/// Proof: `Mcp::SamplingHistory` (`max_values`: None, `max_size`: Some(3761), added: 6236, mode: `MaxEncodedLen`)
+	/// The range of component `t` is `[0, 64]`.
+	/// The range of component `p` is `[0, 32]`.
+	/// The range of component `r` is `[0, 64]`.
+	fn deregister_server(t: u32, p: u32, r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `603 + t * (8593 ±0) + p * (6610 ±0) + r * (850 ±0)`
+		//  Estimated: `4352 + t * (11020 ±0) + p * (9037 ±0) + r * (3277 ±0)`
+		// Minimum execution time: 47_000_000 picoseconds.
+		Weight::from_parts(49_000_000, 4352)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(t.into()))
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(p.into()))
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(7_000_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(t.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(t.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 11020).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(0, 9037).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 3277).saturating_mul(r.into()))
+	}
+
+	/// Storage: `Mcp::NextServerId` (r:1 w:0)
+	/// Proof: `Mcp::NextServerId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ExpiryCursor` (r:1 w:1)
+	/// Proof: `Mcp::ExpiryCursor` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	fn check_expiry() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `340`
+		//  Estimated: `4352`
+		// Minimum execution time: 6_000_000 picoseconds.
+		Weight::from_parts(7_000_000, 4352)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: `Mcp::NextServerId` (r:1 w:0)
+	/// Proof: `Mcp::NextServerId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ExpiryCursor` (r:1 w:1)
+	/// Proof: `Mcp::ExpiryCursor` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:1)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolsByServer` (r:1 w:1)
+	/// Proof: `Mcp::ToolsByServer` (`max_values`: None, `max_size`: Some(530), added: 3005, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Tools` (r:64 w:64)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::SealedTools` (r:64 w:64)
+	/// Proof: `Mcp::SealedTools` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolIdByName` (r:0 w:64)
+	/// Proof: `Mcp::ToolIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::AccessRules` (r:0 w:128)
+	/// Proof: `Mcp::AccessRules` (`max_values`: None, `max_size`: Some(2075), added: 4550, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PromptsByServer` (r:1 w:1)
+	/// Proof: `Mcp::PromptsByServer` (`max_values`: None, `max_size`: Some(273), added: 2748, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Prompts` (r:32 w:32)
+	/// Proof: `Mcp::Prompts` (`max_values`: None, `max_size`: Some(6562), added: 9037, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PromptIdByName` (r:0 w:32)
+	/// Proof: `Mcp::PromptIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourcesByServer` (r:1 w:1)
+	/// Proof: `Mcp::ResourcesByServer` (`max_values`: None, `max_size`: Some(530), added: 3005, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Resources` (r:64 w:64)
+	/// Proof: `Mcp::Resources` (`max_values`: None, `max_size`: Some(802), added: 3277, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceIdByUri` (r:0 w:64)
+	/// Proof: `Mcp::ResourceIdByUri` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceSubscribers` (r:0 w:64)
+	/// Proof: `Mcp::ResourceSubscribers` (`max_values`: None, `max_size`: Some(2069), added: 4544, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerHealth` (r:0 w:1)
+	/// Proof: `Mcp::ServerHealth` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerVerification` (r:0 w:1)
+	/// Proof: `Mcp::ServerVerification` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolPricing` (r:0 w:64)
+	/// Proof: `Mcp::ToolPricing` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolReputation` (r:0 w:64)
+	/// Proof: `Mcp::ToolReputation` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerReputation` (r:0 w:1)
+	/// Proof: `Mcp::ServerReputation` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerEscrow` (r:0 w:1)
+	/// Proof: `Mcp::ServerEscrow` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServersByOwner` (r:1 w:1)
+	/// Proof: `Mcp::ServersByOwner` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::CreditDeposits` (r:1 w:1)
+	/// Proof: `Mcp::CreditDeposits` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::SamplingHistory` (r:0 w:1)
+	/// Proof: `Mcp::SamplingHistory` (`max_values`: None, `max_size`: Some(3761), added: 6236, mode: `MaxEncodedLen`)
+	/// The range of component `t` is `[0, 64]`.
+	/// The range of component `p` is `[0, 32]`.
+	/// The range of component `r` is `[0, 64]`.
+	fn expire_server(t: u32, p: u32, r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `603 + t * (8593 ±0) + p * (6610 ±0) + r * (850 ±0)`
+		//  Estimated: `4352 + t * (11020 ±0) + p * (9037 ±0) + r * (3277 ±0)`
+		// Minimum execution time: 45_000_000 picoseconds.
+		Weight::from_parts(47_000_000, 4352)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(t.into()))
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(p.into()))
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(7_000_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(t.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(t.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 11020).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(0, 9037).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 3277).saturating_mul(r.into()))
+	}
+
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolIdByName` (r:1 w:1)
+	/// Proof: `Mcp::ToolIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolsByServer` (r:1 w:1)
+	/// Proof: `Mcp::ToolsByServer` (`max_values`: None, `max_size`: Some(530), added: 3005, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::NextToolId` (r:1 w:1)
+	/// Proof: `Mcp::NextToolId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Tools` (r:0 w:1)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn register_tool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `393`
+		//  Estimated: `4352`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(28_000_000, 4352)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: `Mcp::Tools` (r:1 w:1)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolIdByName` (r:1 w:2)
+	/// Proof: `Mcp::ToolIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn update_tool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8937`
+		//  Estimated: `12010`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(33_000_000, 12010)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: `Mcp::Tools` (r:64 w:64)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolIdByName` (r:64 w:128)
+	/// Proof: `Mcp::ToolIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 64]`.
+	fn batch_update_tools(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `393 + n * (8593 ±0)`
+		//  Estimated: `4352 + n * (11020 ±0)`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 4352)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(22_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 11020).saturating_mul(n.into()))
+	}
+
+	/// Storage: `Mcp::Tools` (r:1 w:1)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn set_tool_active() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8937`
+		//  Estimated: `12010`
+		// Minimum execution time: 22_000_000 picoseconds.
+		Weight::from_parts(23_000_000, 12010)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: `Mcp::Tools` (r:1 w:1)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::SealedTools` (r:1 w:1)
+	/// Proof: `Mcp::SealedTools` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolIdByName` (r:0 w:1)
+	/// Proof: `Mcp::ToolIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::AccessRules` (r:0 w:1)
+	/// Proof: `Mcp::AccessRules` (`max_values`: None, `max_size`: Some(2075), added: 4550, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolPricing` (r:0 w:1)
+	/// Proof: `Mcp::ToolPricing` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolReputation` (r:0 w:1)
+	/// Proof: `Mcp::ToolReputation` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolsByServer` (r:1 w:1)
+	/// Proof: `Mcp::ToolsByServer` (`max_values`: None, `max_size`: Some(530), added: 3005, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn remove_tool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `9001`
+		//  Estimated: `12010`
+		// Minimum execution time: 35_000_000 picoseconds.
+		Weight::from_parts(36_000_000, 12010)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+
+	/// Storage: `Mcp::Tools` (r:1 w:0)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolPricing` (r:0 w:1)
+	/// Proof: `Mcp::ToolPricing` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn set_tool_pricing() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8937`
+		//  Estimated: `12010`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 12010)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolIdByName` (r:1 w:1)
+	/// Proof: `Mcp::ToolIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolsByServer` (r:1 w:1)
+	/// Proof: `Mcp::ToolsByServer` (`max_values`: None, `max_size`: Some(530), added: 3005, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::NextToolId` (r:1 w:1)
+	/// Proof: `Mcp::NextToolId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::SealedTools` (r:0 w:1)
+	/// Proof: `Mcp::SealedTools` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn register_sealed_tool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `393`
+		//  Estimated: `4352`
+		// Minimum execution time: 26_000_000 picoseconds.
+		Weight::from_parts(27_000_000, 4352)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: `Mcp::SealedTools` (r:1 w:1)
+	/// Proof: `Mcp::SealedTools` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Tools` (r:0 w:1)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn reveal_tool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `553`
+		//  Estimated: `4352`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(40_000_000, 4352)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PromptIdByName` (r:1 w:1)
+	/// Proof: `Mcp::PromptIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PromptsByServer` (r:1 w:1)
+	/// Proof: `Mcp::PromptsByServer` (`max_values`: None, `max_size`: Some(273), added: 2748, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::NextPromptId` (r:1 w:1)
+	/// Proof: `Mcp::NextPromptId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Prompts` (r:0 w:1)
+	/// Proof: `Mcp::Prompts` (`max_values`: None, `max_size`: Some(6562), added: 9037, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn add_prompt() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `393`
+		//  Estimated: `4352`
+		// Minimum execution time: 30_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 4352)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: `Mcp::Prompts` (r:1 w:1)
+	/// Proof: `Mcp::Prompts` (`max_values`: None, `max_size`: Some(6562), added: 9037, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PromptIdByName` (r:1 w:2)
+	/// Proof: `Mcp::PromptIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn update_prompt() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6954`
+		//  Estimated: `10027`
+		// Minimum execution time: 33_000_000 picoseconds.
+		Weight::from_parts(35_000_000, 10027)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: `Mcp::Prompts` (r:1 w:1)
+	/// Proof: `Mcp::Prompts` (`max_values`: None, `max_size`: Some(6562), added: 9037, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PromptIdByName` (r:0 w:1)
+	/// Proof: `Mcp::PromptIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PromptsByServer` (r:1 w:1)
+	/// Proof: `Mcp::PromptsByServer` (`max_values`: None, `max_size`: Some(273), added: 2748, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn remove_prompt() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7018`
+		//  Estimated: `10027`
+		// Minimum execution time: 30_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 10027)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceIdByUri` (r:1 w:1)
+	/// Proof: `Mcp::ResourceIdByUri` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourcesByServer` (r:1 w:1)
+	/// Proof: `Mcp::ResourcesByServer` (`max_values`: None, `max_size`: Some(530), added: 3005, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::NextResourceId` (r:1 w:1)
+	/// Proof: `Mcp::NextResourceId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Resources` (r:0 w:1)
+	/// Proof: `Mcp::Resources` (`max_values`: None, `max_size`: Some(802), added: 3277, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn add_resource() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `393`
+		//  Estimated: `4352`
+		// Minimum execution time: 28_000_000 picoseconds.
+		Weight::from_parts(29_000_000, 4352)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: `Mcp::Resources` (r:1 w:1)
+	/// Proof: `Mcp::Resources` (`max_values`: None, `max_size`: Some(802), added: 3277, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceIdByUri` (r:1 w:2)
+	/// Proof: `Mcp::ResourceIdByUri` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceSubscribers` (r:1 w:0)
+	/// Proof: `Mcp::ResourceSubscribers` (`max_values`: None, `max_size`: Some(2069), added: 4544, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 64]`.
+	fn update_resource(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1262 + s * (32 ±0)`
+		//  Estimated: `5534 + s * (0 ±0)`
+		// Minimum execution time: 32_000_000 picoseconds.
+		Weight::from_parts(33_000_000, 5534)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: `Mcp::Resources` (r:1 w:1)
+	/// Proof: `Mcp::Resources` (`max_values`: None, `max_size`: Some(802), added: 3277, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceIdByUri` (r:0 w:1)
+	/// Proof: `Mcp::ResourceIdByUri` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::AccessRules` (r:0 w:1)
+	/// Proof: `Mcp::AccessRules` (`max_values`: None, `max_size`: Some(2075), added: 4550, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceSubscribers` (r:0 w:1)
+	/// Proof: `Mcp::ResourceSubscribers` (`max_values`: None, `max_size`: Some(2069), added: 4544, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourcesByServer` (r:1 w:1)
+	/// Proof: `Mcp::ResourcesByServer` (`max_values`: None, `max_size`: Some(530), added: 3005, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn remove_resource() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1258`
+		//  Estimated: `4352`
+		// Minimum execution time: 30_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 4352)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+
+	/// Storage: `Mcp::Resources` (r:1 w:0)
+	/// Proof: `Mcp::Resources` (`max_values`: None, `max_size`: Some(802), added: 3277, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::AccessRules` (r:1 w:0)
+	/// Proof: `Mcp::AccessRules` (`max_values`: None, `max_size`: Some(2075), added: 4550, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceSubscribers` (r:1 w:1)
+	/// Proof: `Mcp::ResourceSubscribers` (`max_values`: None, `max_size`: Some(2069), added: 4544, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceSubscriptionsByAccount` (r:0 w:1)
+	/// Proof: `Mcp::ResourceSubscriptionsByAccount` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn subscribe_resource() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5282`
+		//  Estimated: `5540`
+		// Minimum execution time: 34_000_000 picoseconds.
+		Weight::from_parts(36_000_000, 5540)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: `Mcp::ResourceSubscribers` (r:1 w:1)
+	/// Proof: `Mcp::ResourceSubscribers` (`max_values`: None, `max_size`: Some(2069), added: 4544, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceSubscriptionsByAccount` (r:0 w:1)
+	/// Proof: `Mcp::ResourceSubscriptionsByAccount` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn unsubscribe_resource() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2151`
+		//  Estimated: `5534`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 5534)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: `Mcp::Servers` (r:1 w:1)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolsByServer` (r:1 w:1)
+	/// Proof: `Mcp::ToolsByServer` (`max_values`: None, `max_size`: Some(530), added: 3005, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Tools` (r:64 w:64)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::SealedTools` (r:64 w:64)
+	/// Proof: `Mcp::SealedTools` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolIdByName` (r:0 w:64)
+	/// Proof: `Mcp::ToolIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::AccessRules` (r:0 w:128)
+	/// Proof: `Mcp::AccessRules` (`max_values`: None, `max_size`: Some(2075), added: 4550, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PromptsByServer` (r:1 w:1)
+	/// Proof: `Mcp::PromptsByServer` (`max_values`: None, `max_size`: Some(273), added: 2748, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Prompts` (r:32 w:32)
+	/// Proof: `Mcp::Prompts` (`max_values`: None, `max_size`: Some(6562), added: 9037, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PromptIdByName` (r:0 w:32)
+	/// Proof: `Mcp::PromptIdByName` (`max_values`: None, `max_size`: Some(105), added: 2580, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourcesByServer` (r:1 w:1)
+	/// Proof: `Mcp::ResourcesByServer` (`max_values`: None, `max_size`: Some(530), added: 3005, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Resources` (r:64 w:64)
+	/// Proof: `Mcp::Resources` (`max_values`: None, `max_size`: Some(802), added: 3277, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceIdByUri` (r:0 w:64)
+	/// Proof: `Mcp::ResourceIdByUri` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ResourceSubscribers` (r:0 w:64)
+	/// Proof: `Mcp::ResourceSubscribers` (`max_values`: None, `max_size`: Some(2069), added: 4544, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerHealth` (r:0 w:1)
+	/// Proof: `Mcp::ServerHealth` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerVerification` (r:0 w:1)
+	/// Proof: `Mcp::ServerVerification` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolPricing` (r:0 w:64)
+	/// Proof: `Mcp::ToolPricing` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolReputation` (r:0 w:64)
+	/// Proof: `Mcp::ToolReputation` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerReputation` (r:0 w:1)
+	/// Proof: `Mcp::ServerReputation` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerEscrow` (r:0 w:1)
+	/// Proof: `Mcp::ServerEscrow` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServersByOwner` (r:1 w:1)
+	/// Proof: `Mcp::ServersByOwner` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::CreditDeposits` (r:1 w:1)
+	/// Proof: `Mcp::CreditDeposits` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::SamplingHistory` (r:0 w:1)
+	/// Proof: `Mcp::SamplingHistory` (`max_values`: None, `max_size`: Some(3761), added: 6236, mode: `MaxEncodedLen`)
+	/// The range of component `t` is `[0, 64]`.
+	/// The range of component `p` is `[0, 32]`.
+	/// The range of component `r` is `[0, 64]`.
+	fn slash_server(t: u32, p: u32, r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `603 + t * (8593 ±0) + p * (6610 ±0) + r * (850 ±0)`
+		//  Estimated: `4352 + t * (11020 ±0) + p * (9037 ±0) + r * (3277 ±0)`
+		// Minimum execution time: 51_000_000 picoseconds.
+		Weight::from_parts(53_000_000, 4352)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(t.into()))
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(p.into()))
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(7_000_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(t.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(t.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 11020).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(0, 9037).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 3277).saturating_mul(r.into()))
+	}
+
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerVerification` (r:0 w:1)
+	/// Proof: `Mcp::ServerVerification` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	fn verify_server() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `340`
+		//  Estimated: `4352`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 4352)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: `Mcp::ServerVerification` (r:1 w:1)
+	/// Proof: `Mcp::ServerVerification` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	fn revoke_verification() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `17`
+		//  Estimated: `3482`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 3482)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: `Mcp::Tools` (r:1 w:0)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:16 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::AccessRules` (r:1 w:0)
+	/// Proof: `Mcp::AccessRules` (`max_values`: None, `max_size`: Some(2075), added: 4550, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServersByOwner` (r:1 w:0)
+	/// Proof: `Mcp::ServersByOwner` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolPricing` (r:1 w:0)
+	/// Proof: `Mcp::ToolPricing` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Usage` (r:1 w:0)
+	/// Proof: `Mcp::Usage` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerEscrow` (r:16 w:1)
+	/// Proof: `Mcp::ServerEscrow` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::NextRequestId` (r:1 w:1)
+	/// Proof: `Mcp::NextRequestId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PendingExecutions` (r:1 w:1)
+	/// Proof: `Mcp::PendingExecutions` (`max_values`: None, `max_size`: Some(529), added: 3004, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::SubscriptionPurchases` (r:0 w:1)
+	/// Proof: `Mcp::SubscriptionPurchases` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ExecutionRequests` (r:0 w:1)
+	/// Proof: `Mcp::ExecutionRequests` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ExecutionBonds` (r:0 w:1)
+	/// Proof: `Mcp::ExecutionBonds` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::RequestsByAccount` (r:1 w:1)
+	/// Proof: `Mcp::RequestsByAccount` (`max_values`: None, `max_size`: Some(185), added: 2660, mode: `MaxEncodedLen`)
+	fn request_tool_execution() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `23518`
+		//  Estimated: `103010`
+		// Minimum execution time: 124_000_000 picoseconds.
+		Weight::from_parts(128_000_000, 103010)
+			.saturating_add(RocksDbWeight::get().reads(42_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+
+	/// Storage: `Mcp::ExecutionRequests` (r:1 w:1)
+	/// Proof: `Mcp::ExecutionRequests` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PendingExecutions` (r:1 w:1)
+	/// Proof: `Mcp::PendingExecutions` (`max_values`: None, `max_size`: Some(529), added: 3004, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerEscrow` (r:1 w:1)
+	/// Proof: `Mcp::ServerEscrow` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::SubscriptionPurchases` (r:1 w:1)
+	/// Proof: `Mcp::SubscriptionPurchases` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::RatingCredits` (r:1 w:1)
+	/// Proof: `Mcp::RatingCredits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Usage` (r:1 w:1)
+	/// Proof: `Mcp::Usage` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::CreditEscrow` (r:1 w:1)
+	/// Proof: `Mcp::CreditEscrow` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ExecutionBonds` (r:1 w:1)
+	/// Proof: `Mcp::ExecutionBonds` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::RequestsByAccount` (r:1 w:1)
+	/// Proof: `Mcp::RequestsByAccount` (`max_values`: None, `max_size`: Some(185), added: 2660, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolSubscriptionsByAccount` (r:0 w:1)
+	/// Proof: `Mcp::ToolSubscriptionsByAccount` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn submit_execution_result() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1380`
+		//  Estimated: `6196`
+		// Minimum execution time: 57_000_000 picoseconds.
+		Weight::from_parts(60_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
+
+	/// Storage: `Mcp::ExecutionRequests` (r:1 w:1)
+	/// Proof: `Mcp::ExecutionRequests` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PendingExecutions` (r:1 w:1)
+	/// Proof: `Mcp::PendingExecutions` (`max_values`: None, `max_size`: Some(529), added: 3004, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerEscrow` (r:1 w:1)
+	/// Proof: `Mcp::ServerEscrow` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::SubscriptionPurchases` (r:1 w:1)
+	/// Proof: `Mcp::SubscriptionPurchases` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::CreditEscrow` (r:1 w:1)
+	/// Proof: `Mcp::CreditEscrow` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ExecutionBonds` (r:1 w:1)
+	/// Proof: `Mcp::ExecutionBonds` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::RequestsByAccount` (r:1 w:1)
+	/// Proof: `Mcp::RequestsByAccount` (`max_values`: None, `max_size`: Some(185), added: 2660, mode: `MaxEncodedLen`)
+	fn cancel_execution() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1103`
+		//  Estimated: `3994`
+		// Minimum execution time: 35_000_000 picoseconds.
+		Weight::from_parts(37_000_000, 3994)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::NextSamplingRequestId` (r:1 w:1)
+	/// Proof: `Mcp::NextSamplingRequestId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PendingSamplingRequests` (r:1 w:1)
+	/// Proof: `Mcp::PendingSamplingRequests` (`max_values`: None, `max_size`: Some(529), added: 3004, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::SamplingRequests` (r:0 w:1)
+	/// Proof: `Mcp::SamplingRequests` (`max_values`: None, `max_size`: Some(393), added: 2868, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn create_sampling_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `393`
+		//  Estimated: `4602`
+		// Minimum execution time: 28_000_000 picoseconds.
+		Weight::from_parts(29_000_000, 4602)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: `Mcp::SamplingRequests` (r:1 w:1)
+	/// Proof: `Mcp::SamplingRequests` (`max_values`: None, `max_size`: Some(393), added: 2868, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PendingSamplingRequests` (r:1 w:1)
+	/// Proof: `Mcp::PendingSamplingRequests` (`max_values`: None, `max_size`: Some(529), added: 3004, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::SamplingHistory` (r:1 w:1)
+	/// Proof: `Mcp::SamplingHistory` (`max_values`: None, `max_size`: Some(3761), added: 6236, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn submit_sampling_result() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4201`
+		//  Estimated: `7251`
+		// Minimum execution time: 33_000_000 picoseconds.
+		Weight::from_parts(35_000_000, 7251)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: `Mcp::SamplingRequests` (r:1 w:1)
+	/// Proof: `Mcp::SamplingRequests` (`max_values`: None, `max_size`: Some(393), added: 2868, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::PendingSamplingRequests` (r:1 w:1)
+	/// Proof: `Mcp::PendingSamplingRequests` (`max_values`: None, `max_size`: Some(529), added: 3004, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn cancel_sampling_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1530`
+		//  Estimated: `4602`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 4602)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: `Mcp::Tools` (r:1 w:0)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::AccessRules` (r:0 w:1)
+	/// Proof: `Mcp::AccessRules` (`max_values`: None, `max_size`: Some(2075), added: 4550, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn set_access_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `9001`
+		//  Estimated: `12010`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 12010)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: `Mcp::Tools` (r:1 w:0)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::AccessRules` (r:1 w:1)
+	/// Proof: `Mcp::AccessRules` (`max_values`: None, `max_size`: Some(2075), added: 4550, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn grant_access() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `11076`
+		//  Estimated: `12010`
+		// Minimum execution time: 29_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 12010)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: `Mcp::Tools` (r:1 w:0)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::AccessRules` (r:1 w:1)
+	/// Proof: `Mcp::AccessRules` (`max_values`: None, `max_size`: Some(2075), added: 4550, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	fn revoke_access() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `11076`
+		//  Estimated: `12010`
+		// Minimum execution time: 29_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 12010)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: `GatewayRegistry::Reporters` (r:1 w:0)
+	/// Proof: `GatewayRegistry::Reporters` (`max_values`: Some(1), `max_size`: Some(513), added: 1008, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::LastHealthReport` (r:1 w:1)
+	/// Proof: `Mcp::LastHealthReport` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:64 w:64)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerHealth` (r:64 w:64)
+	/// Proof: `Mcp::ServerHealth` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 64]`.
+	fn report_server_health(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `584 + n * (917 ±0)`
+		//  Estimated: `3517 + n * (3362 ±0)`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 3517)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3362).saturating_mul(n.into()))
+	}
+
+	/// Storage: `Mcp::Tools` (r:1 w:0)
+	/// Proof: `Mcp::Tools` (`max_values`: None, `max_size`: Some(8545), added: 11020, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::RatingCredits` (r:1 w:1)
+	/// Proof: `Mcp::RatingCredits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ToolReputation` (r:1 w:1)
+	/// Proof: `Mcp::ToolReputation` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ServerReputation` (r:1 w:1)
+	/// Proof: `Mcp::ServerReputation` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::ActivityByAccount` (r:1 w:1)
+	/// Proof: `Mcp::ActivityByAccount` (`max_values`: None, `max_size`: Some(1137), added: 3612, mode: `MaxEncodedLen`)
+	/// Storage: `Mcp::Servers` (r:1 w:0)
+	/// Proof: `Mcp::Servers` (`max_values`: None, `max_size`: Some(887), added: 3362, mode: `MaxEncodedLen`)
+	fn rate_tool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8737`
+		//  Estimated: `12010`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(33_000_000, 12010)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+}
@@ -0,0 +1,30 @@
+//! Runtime API for enumerating the module registry.
+//!
+//! Off-chain tooling resolves module metadata through these calls instead of decoding raw
+//! `ModuleRegistry` storage.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+pub use pallet_module_registry::ModuleEntry;
+
+sp_api::decl_runtime_apis! {
+    /// Queries of the module registry.
+    pub trait ModuleRegistryApi<AccountId>
+    where
+        AccountId: Codec,
+    {
+        /// Registered modules in storage order, at most `limit`, continuing after the module
+        /// with key `start_after`.
+        fn list_modules(start_after: Option<Vec<u8>>, limit: u32) -> Vec<ModuleEntry<AccountId>>;
+
+        /// Modules owned by `owner`, in registration order.
+        fn modules_by_owner(owner: AccountId) -> Vec<ModuleEntry<AccountId>>;
+
+        /// The module registered under `key`, if any.
+        fn get_module(key: Vec<u8>) -> Option<ModuleEntry<AccountId>>;
+    }
+}
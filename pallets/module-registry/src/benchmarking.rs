@@ -2,13 +2,35 @@
 
 use super::*;
 
-use frame_support::BoundedVec;
+use frame_support::{
+    traits::{EnsureOrigin, Get, Hooks},
+    BoundedVec,
+};
 
 #[allow(unused)]
 use crate::Pallet as ModuleRegistry;
 use frame_benchmarking::v2::*;
 use frame_system::RawOrigin;
 
+/// A distinct 32-byte (Ed25519-sized) key for each index.
+fn key(index: u32) -> sp_std::vec::Vec<u8> {
+    let mut key = sp_std::vec![1u8; 32];
+    key[..4].copy_from_slice(&index.to_le_bytes());
+    key
+}
+
+/// Register `count` modules owned by `owner`, starting at key index `first`.
+fn register_modules<T: Config>(owner: &T::AccountId, first: u32, count: u32) {
+    for index in first..first + count {
+        ModuleRegistry::<T>::register_module(
+            RawOrigin::Signed(owner.clone()).into(),
+            key(index),
+            b"QmTestCID123456789012345678901234".to_vec(),
+        )
+        .expect("module registers");
+    }
+}
+
 #[benchmarks]
 mod benchmarks {
     use super::*;
@@ -16,6 +38,8 @@ mod benchmarks {
     #[benchmark]
     fn register_module() {
         let caller: T::AccountId = whitelisted_caller();
+        // The caller's list of modules is one short of full
+        register_modules::<T>(&caller, 1, T::MaxModulesPerAccount::get() - 1);
         let key = sp_std::vec![1u8; 32]; // Ed25519 key
         let cid = b"QmTestCID123456789012345678901234".to_vec();
 
@@ -55,6 +79,8 @@ mod benchmarks {
         let caller: T::AccountId = whitelisted_caller();
         let key = sp_std::vec![1u8; 32]; // Ed25519 key
         let cid = b"QmTestCID123456789012345678901234".to_vec();
+        // The removed key is searched for in a full list of modules
+        register_modules::<T>(&caller, 1, T::MaxModulesPerAccount::get() - 1);
 
         // First register a module
         let _ = ModuleRegistry::<T>::register_module(
@@ -71,6 +97,57 @@ mod benchmarks {
         assert!(!Modules::<T>::contains_key(&bounded_key));
     }
 
+    #[benchmark]
+    fn transfer_module_ownership() {
+        let caller: T::AccountId = whitelisted_caller();
+        let receiver: T::AccountId = account("receiver", 0, 0);
+        let max = T::MaxModulesPerAccount::get();
+        // The sender's list is full and the receiver's is one short of full
+        register_modules::<T>(&caller, 0, max);
+        register_modules::<T>(&receiver, max, max - 1);
+
+        #[extrinsic_call]
+        transfer_module_ownership(RawOrigin::Signed(caller), key(0), receiver.clone());
+
+        let bounded_key: BoundedVec<u8, T::MaxKeyLength> = key(0).try_into().unwrap();
+        assert_eq!(ModuleOwners::<T>::get(&bounded_key), Some(receiver));
+    }
+
+    #[benchmark]
+    fn force_set_module_owner() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let owner: T::AccountId = account("owner", 0, 0);
+        let receiver: T::AccountId = account("receiver", 0, 0);
+        let max = T::MaxModulesPerAccount::get();
+        // The previous owner's list is full and the receiver's is one short of full
+        register_modules::<T>(&owner, 0, max);
+        register_modules::<T>(&receiver, max, max - 1);
+
+        #[extrinsic_call]
+        force_set_module_owner(origin as T::RuntimeOrigin, key(0), receiver.clone());
+
+        let bounded_key: BoundedVec<u8, T::MaxKeyLength> = key(0).try_into().unwrap();
+        assert_eq!(ModuleOwners::<T>::get(&bounded_key), Some(receiver));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn on_finalize() {
+        // The touched set is full, so its keys make the largest digest
+        let touched: sp_std::vec::Vec<[u8; 16]> = (0..T::MaxTouchedPerBlock::get())
+            .map(|index| ModuleRegistry::<T>::touched_key_hash(&key(index)))
+            .collect();
+        TouchedModules::<T>::put(BoundedVec::truncate_from(touched));
+
+        #[block]
+        {
+            ModuleRegistry::<T>::on_finalize(1u32.into());
+        }
+
+        assert!(!TouchedModules::<T>::exists());
+    }
+
     impl_benchmark_test_suite!(
         ModuleRegistry,
         crate::mock::new_test_ext(),
@@ -20,13 +20,32 @@
 //! - Key: `Vec<u8>` - Public key in various formats (flexible to support all chains)
 //! - Value: `Vec<u8>` - IPFS CID pointing to module metadata
 //!
+//! Ownership is kept beside it: `ModuleOwners` maps each key to the account that registered it
+//! (or received it in a transfer), and `ModulesByOwner` lists the keys of each account, up to
+//! `MaxModulesPerAccount`. Modules registered before ownership was recorded have no owner until
+//! `ForceOrigin` assigns one with `force_set_module_owner`.
+//!
 //! ## Functionality
 //!
-//! - `register_module`: Store module metadata CID on-chain
+//! - `register_module`: Store module metadata CID on-chain, owned by the caller
+//! - `update_module`: Point an owned module at new metadata
+//! - `transfer_module_ownership`: Hand an owned module to another account
+//! - `remove_module`: Delete an owned module from registry
+//! - `force_set_module_owner`: Assign a module to an account, e.g. one registered before
+//!   ownership was recorded (`ForceOrigin` only)
 //! - `get_module`: Retrieve module metadata CID by public key
-//! - `remove_module`: Delete module from registry
 //! - Key validation for different public key formats
 //! - CID validation for IPFS references
+//!
+//! The `pallet-module-registry-runtime-api` crate exposes the registry to off-chain tooling, which
+//! enumerates modules through it instead of iterating raw storage.
+//!
+//! ## Block Digest
+//!
+//! Every block that mutates the registry carries a `DigestItem::Other` log prefixed with
+//! [`TOUCHED_DIGEST_ID`] and followed by a SCALE-encoded [`TouchedModulesDigest`]. Light clients
+//! and gateways can hash the keys they follow with [`Pallet::touched_key_hash`] and skip any block
+//! whose digest does not contain them, without decoding the block's events.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -47,11 +66,36 @@ pub use weights::*;
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
-    use frame_support::pallet_prelude::*;
+    use frame_support::{pallet_prelude::*, StorageHasher};
     use frame_system::pallet_prelude::*;
+    use sp_runtime::DigestItem;
     extern crate alloc;
     use alloc::vec::Vec;
 
+    /// Prefix of the registry's `DigestItem::Other` log, used to tell it apart from other logs.
+    pub const TOUCHED_DIGEST_ID: [u8; 4] = *b"mreg";
+
+    /// A registry entry as returned to off-chain callers.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct ModuleEntry<AccountId> {
+        /// The public key identifying the module.
+        pub key: Vec<u8>,
+        /// The IPFS CID of the module metadata.
+        pub cid: Vec<u8>,
+        /// The account that may change the module; `None` for modules registered before
+        /// ownership was recorded and not yet assigned an owner by `ForceOrigin`.
+        pub owner: Option<AccountId>,
+    }
+
+    /// Summary of the registry entries mutated in a single block.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub enum TouchedModulesDigest {
+        /// Sorted, deduplicated `blake2_128` hashes of every module key mutated in the block.
+        Keys(Vec<[u8; 16]>),
+        /// More than `MaxTouchedPerBlock` modules were mutated; clients must inspect the events.
+        Saturated,
+    }
+
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
@@ -60,12 +104,20 @@ pub mod pallet {
     pub trait Config: frame_system::Config {
         /// A type representing the weights required by the dispatchables of this pallet.
         type WeightInfo: WeightInfo;
+        /// Origin allowed to assign any module to an account
+        type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
         /// Maximum length for public keys (in bytes)
         #[pallet::constant]
         type MaxKeyLength: Get<u32>;
         /// Maximum length for IPFS CIDs (in bytes)
         #[pallet::constant]
         type MaxCidLength: Get<u32>;
+        /// Maximum number of distinct modules listed in a block's digest before it saturates
+        #[pallet::constant]
+        type MaxTouchedPerBlock: Get<u32>;
+        /// Maximum number of modules a single account may own
+        #[pallet::constant]
+        type MaxModulesPerAccount: Get<u32>;
     }
 
     /// Storage map for module registry.
@@ -80,6 +132,61 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Owner of each module.
+    #[pallet::storage]
+    pub type ModuleOwners<T: Config> =
+        StorageMap<_, Blake2_128Concat, BoundedVec<u8, T::MaxKeyLength>, T::AccountId, OptionQuery>;
+
+    /// Keys of the modules owned by each account, in registration order.
+    #[pallet::storage]
+    pub type ModulesByOwner<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<BoundedVec<u8, T::MaxKeyLength>, T::MaxModulesPerAccount>,
+        ValueQuery,
+    >;
+
+    /// Sorted `blake2_128` hashes of the module keys mutated in the current block.
+    /// Drained into the block digest in `on_finalize`.
+    #[pallet::storage]
+    pub type TouchedModules<T: Config> =
+        StorageValue<_, BoundedVec<[u8; 16], T::MaxTouchedPerBlock>, ValueQuery>;
+
+    /// Whether the current block mutated more modules than `TouchedModules` can hold.
+    #[pallet::storage]
+    pub type TouchedOverflow<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            // Blocks that touch no module only check the touched set in `on_finalize`; calls that
+            // touch one pay for draining it.
+            T::DbWeight::get().reads(1)
+        }
+
+        fn on_finalize(_n: BlockNumberFor<T>) {
+            // The set overflows only once it is full, so an absent set means nothing was touched
+            if !TouchedModules::<T>::exists() {
+                return;
+            }
+            let touched = TouchedModules::<T>::take();
+            let overflow = TouchedOverflow::<T>::take();
+            if touched.is_empty() && !overflow {
+                return;
+            }
+
+            let digest = if overflow {
+                TouchedModulesDigest::Saturated
+            } else {
+                TouchedModulesDigest::Keys(touched.into_inner())
+            };
+            frame_system::Pallet::<T>::deposit_log(DigestItem::Other(
+                (TOUCHED_DIGEST_ID, digest).encode(),
+            ));
+        }
+    }
+
     /// Events emitted by this pallet.
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -109,6 +216,24 @@ pub mod pallet {
             /// The account who removed the module.
             who: T::AccountId,
         },
+        /// A module was handed to another account.
+        ModuleOwnershipTransferred {
+            /// The public key used as identifier.
+            key: BoundedVec<u8, T::MaxKeyLength>,
+            /// The previous owner.
+            from: T::AccountId,
+            /// The new owner.
+            to: T::AccountId,
+        },
+        /// `ForceOrigin` assigned a module to an account.
+        ModuleOwnerSet {
+            /// The public key used as identifier.
+            key: BoundedVec<u8, T::MaxKeyLength>,
+            /// The previous owner, if the module had one.
+            from: Option<T::AccountId>,
+            /// The new owner.
+            to: T::AccountId,
+        },
     }
 
     /// Errors that can be returned by this pallet.
@@ -130,6 +255,12 @@ pub mod pallet {
         EmptyCid,
         /// The module already exists in the registry.
         ModuleAlreadyExists,
+        /// The caller does not own the module.
+        NotModuleOwner,
+        /// The account already owns `MaxModulesPerAccount` modules.
+        TooManyModules,
+        /// The module is already owned by the target account.
+        AlreadyModuleOwner,
     }
 
     /// Dispatchable functions for the module registry pallet.
@@ -150,8 +281,11 @@ pub mod pallet {
         /// * `InvalidKeyFormat` - If the public key format is invalid
         /// * `InvalidCidFormat` - If the IPFS CID format is invalid
         /// * `ModuleAlreadyExists` - If a module with this key already exists
+        /// * `TooManyModules` - If the caller already owns `MaxModulesPerAccount` modules
         #[pallet::call_index(0)]
-        #[pallet::weight(T::WeightInfo::register_module())]
+        #[pallet::weight(
+            T::WeightInfo::register_module().saturating_add(T::WeightInfo::on_finalize())
+        )]
         pub fn register_module(origin: OriginFor<T>, key: Vec<u8>, cid: Vec<u8>) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
@@ -171,8 +305,14 @@ pub mod pallet {
                 Error::<T>::ModuleAlreadyExists
             );
 
+            // Record the caller as owner
+            ModulesByOwner::<T>::try_mutate(&who, |keys| keys.try_push(bounded_key.clone()))
+                .map_err(|_| Error::<T>::TooManyModules)?;
+            ModuleOwners::<T>::insert(&bounded_key, &who);
+
             // Store the module
             Modules::<T>::insert(&bounded_key, &bounded_cid);
+            Self::note_touched(&bounded_key);
 
             // Emit event
             Self::deposit_event(Event::ModuleRegistered {
@@ -195,10 +335,13 @@ pub mod pallet {
         ///
         /// # Errors
         /// * `ModuleNotFound` - If no module exists with this key
+        /// * `NotModuleOwner` - If the caller does not own the module
         /// * `InvalidKeyFormat` - If the public key format is invalid
         /// * `InvalidCidFormat` - If the IPFS CID format is invalid
         #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::update_module())]
+        #[pallet::weight(
+            T::WeightInfo::update_module().saturating_add(T::WeightInfo::on_finalize())
+        )]
         pub fn update_module(origin: OriginFor<T>, key: Vec<u8>, cid: Vec<u8>) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
@@ -212,14 +355,12 @@ pub mod pallet {
             let bounded_cid: BoundedVec<u8, T::MaxCidLength> =
                 cid.try_into().map_err(|_| Error::<T>::CidTooLong)?;
 
-            // Check if module exists
-            ensure!(
-                Modules::<T>::contains_key(&bounded_key),
-                Error::<T>::ModuleNotFound
-            );
+            // Check if module exists and belongs to the caller
+            Self::ensure_module_owner(&bounded_key, &who)?;
 
             // Update the module
             Modules::<T>::insert(&bounded_key, &bounded_cid);
+            Self::note_touched(&bounded_key);
 
             // Emit event
             Self::deposit_event(Event::ModuleUpdated {
@@ -241,9 +382,12 @@ pub mod pallet {
         ///
         /// # Errors
         /// * `ModuleNotFound` - If no module exists with this key
+        /// * `NotModuleOwner` - If the caller does not own the module
         /// * `InvalidKeyFormat` - If the public key format is invalid
         #[pallet::call_index(2)]
-        #[pallet::weight(T::WeightInfo::remove_module())]
+        #[pallet::weight(
+            T::WeightInfo::remove_module().saturating_add(T::WeightInfo::on_finalize())
+        )]
         pub fn remove_module(origin: OriginFor<T>, key: Vec<u8>) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
@@ -254,14 +398,14 @@ pub mod pallet {
             let bounded_key: BoundedVec<u8, T::MaxKeyLength> =
                 key.try_into().map_err(|_| Error::<T>::KeyTooLong)?;
 
-            // Check if module exists
-            ensure!(
-                Modules::<T>::contains_key(&bounded_key),
-                Error::<T>::ModuleNotFound
-            );
+            // Check if module exists and belongs to the caller
+            Self::ensure_module_owner(&bounded_key, &who)?;
 
-            // Remove the module
+            // Remove the module and its ownership records
             Modules::<T>::remove(&bounded_key);
+            ModuleOwners::<T>::remove(&bounded_key);
+            ModulesByOwner::<T>::mutate(&who, |keys| keys.retain(|k| k != &bounded_key));
+            Self::note_touched(&bounded_key);
 
             // Emit event
             Self::deposit_event(Event::ModuleRemoved {
@@ -271,6 +415,114 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Transfer ownership of a module to another account.
+        ///
+        /// The module's CID is unchanged; only the account allowed to update or remove it changes.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (must be signed by the current owner)
+        /// * `key` - The public key identifier (`Vec<u8>`)
+        /// * `new_owner` - The account receiving the module
+        ///
+        /// # Errors
+        /// * `ModuleNotFound` - If no module exists with this key
+        /// * `NotModuleOwner` - If the caller does not own the module
+        /// * `AlreadyModuleOwner` - If `new_owner` is the caller
+        /// * `TooManyModules` - If `new_owner` already owns `MaxModulesPerAccount` modules
+        #[pallet::call_index(3)]
+        #[pallet::weight(
+            T::WeightInfo::transfer_module_ownership().saturating_add(T::WeightInfo::on_finalize())
+        )]
+        pub fn transfer_module_ownership(
+            origin: OriginFor<T>,
+            key: Vec<u8>,
+            new_owner: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // Validate input
+            Self::validate_key(&key)?;
+
+            // Convert to bounded vector
+            let bounded_key: BoundedVec<u8, T::MaxKeyLength> =
+                key.try_into().map_err(|_| Error::<T>::KeyTooLong)?;
+
+            // Check if module exists and belongs to the caller
+            Self::ensure_module_owner(&bounded_key, &who)?;
+            ensure!(new_owner != who, Error::<T>::AlreadyModuleOwner);
+
+            // Move the key between the owners' lists
+            ModulesByOwner::<T>::try_mutate(&new_owner, |keys| keys.try_push(bounded_key.clone()))
+                .map_err(|_| Error::<T>::TooManyModules)?;
+            ModulesByOwner::<T>::mutate(&who, |keys| keys.retain(|k| k != &bounded_key));
+            ModuleOwners::<T>::insert(&bounded_key, &new_owner);
+            Self::note_touched(&bounded_key);
+
+            // Emit event
+            Self::deposit_event(Event::ModuleOwnershipTransferred {
+                key: bounded_key,
+                from: who,
+                to: new_owner,
+            });
+
+            Ok(())
+        }
+
+        /// Assign a module to an account, whoever owned it before.
+        ///
+        /// This is how modules registered before ownership was recorded get an owner, who can
+        /// then update, transfer or remove them.
+        ///
+        /// # Arguments
+        /// * `origin` - The origin of the call (must be `ForceOrigin`)
+        /// * `key` - The public key identifier (`Vec<u8>`)
+        /// * `owner` - The account receiving the module
+        ///
+        /// # Errors
+        /// * `ModuleNotFound` - If no module exists with this key
+        /// * `AlreadyModuleOwner` - If `owner` already owns the module
+        /// * `TooManyModules` - If `owner` already owns `MaxModulesPerAccount` modules
+        #[pallet::call_index(4)]
+        #[pallet::weight(
+            T::WeightInfo::force_set_module_owner().saturating_add(T::WeightInfo::on_finalize())
+        )]
+        pub fn force_set_module_owner(
+            origin: OriginFor<T>,
+            key: Vec<u8>,
+            owner: T::AccountId,
+        ) -> DispatchResult {
+            T::ForceOrigin::ensure_origin(origin)?;
+
+            let bounded_key: BoundedVec<u8, T::MaxKeyLength> =
+                key.try_into().map_err(|_| Error::<T>::KeyTooLong)?;
+            ensure!(
+                Modules::<T>::contains_key(&bounded_key),
+                Error::<T>::ModuleNotFound
+            );
+            let from = ModuleOwners::<T>::get(&bounded_key);
+            ensure!(
+                from.as_ref() != Some(&owner),
+                Error::<T>::AlreadyModuleOwner
+            );
+
+            // Move the key between the owners' lists
+            ModulesByOwner::<T>::try_mutate(&owner, |keys| keys.try_push(bounded_key.clone()))
+                .map_err(|_| Error::<T>::TooManyModules)?;
+            if let Some(from) = &from {
+                ModulesByOwner::<T>::mutate(from, |keys| keys.retain(|k| k != &bounded_key));
+            }
+            ModuleOwners::<T>::insert(&bounded_key, &owner);
+            Self::note_touched(&bounded_key);
+
+            Self::deposit_event(Event::ModuleOwnerSet {
+                key: bounded_key,
+                from,
+                to: owner,
+            });
+
+            Ok(())
+        }
     }
 
     /// Helper functions for validation and utility operations.
@@ -372,5 +624,95 @@ pub mod pallet {
             let bounded_key: BoundedVec<u8, T::MaxKeyLength> = key.to_vec().try_into().ok()?;
             Modules::<T>::get(&bounded_key)
         }
+
+        /// List registered modules, at most `limit` of them.
+        ///
+        /// Modules are returned in storage order, which is stable but not sorted. Pass the key of
+        /// the last returned module as `start_after` to fetch the next page.
+        pub fn list_modules(
+            start_after: Option<&[u8]>,
+            limit: u32,
+        ) -> Vec<ModuleEntry<T::AccountId>> {
+            let entries = match start_after {
+                Some(key) => {
+                    let Ok(bounded_key) = BoundedVec::<u8, T::MaxKeyLength>::try_from(key.to_vec())
+                    else {
+                        return Vec::new();
+                    };
+                    Modules::<T>::iter_from(Modules::<T>::hashed_key_for(&bounded_key))
+                }
+                None => Modules::<T>::iter(),
+            };
+            entries
+                .take(limit as usize)
+                .map(|(key, cid)| Self::entry(key, cid))
+                .collect()
+        }
+
+        /// List the modules owned by an account, in registration order.
+        pub fn modules_by_owner(owner: &T::AccountId) -> Vec<ModuleEntry<T::AccountId>> {
+            ModulesByOwner::<T>::get(owner)
+                .into_iter()
+                .filter_map(|key| Modules::<T>::get(&key).map(|cid| Self::entry(key, cid)))
+                .collect()
+        }
+
+        /// Get a module together with its owner.
+        pub fn module_entry(key: &[u8]) -> Option<ModuleEntry<T::AccountId>> {
+            let bounded_key: BoundedVec<u8, T::MaxKeyLength> = key.to_vec().try_into().ok()?;
+            Modules::<T>::get(&bounded_key).map(|cid| Self::entry(bounded_key, cid))
+        }
+
+        fn entry(
+            key: BoundedVec<u8, T::MaxKeyLength>,
+            cid: BoundedVec<u8, T::MaxCidLength>,
+        ) -> ModuleEntry<T::AccountId> {
+            ModuleEntry {
+                owner: ModuleOwners::<T>::get(&key),
+                key: key.into_inner(),
+                cid: cid.into_inner(),
+            }
+        }
+
+        /// Ensure a module exists and is owned by `who`.
+        ///
+        /// Modules registered before ownership was recorded have no owner, so nobody passes this
+        /// check until `ForceOrigin` assigns one.
+        fn ensure_module_owner(
+            key: &BoundedVec<u8, T::MaxKeyLength>,
+            who: &T::AccountId,
+        ) -> DispatchResult {
+            ensure!(Modules::<T>::contains_key(key), Error::<T>::ModuleNotFound);
+            ensure!(
+                ModuleOwners::<T>::get(key).as_ref() == Some(who),
+                Error::<T>::NotModuleOwner
+            );
+            Ok(())
+        }
+
+        /// Hash of a module key as it appears in the block digest.
+        ///
+        /// The hash is taken over the raw key bytes, so clients can compute it without
+        /// SCALE-encoding the key first.
+        pub fn touched_key_hash(key: &[u8]) -> [u8; 16] {
+            Blake2_128::hash(key)
+        }
+
+        /// Record that a module was mutated in the current block.
+        fn note_touched(key: &[u8]) {
+            if TouchedOverflow::<T>::get() {
+                return;
+            }
+
+            let hash = Self::touched_key_hash(key);
+            let overflowed =
+                TouchedModules::<T>::mutate(|touched| match touched.binary_search(&hash) {
+                    Ok(_) => false,
+                    Err(pos) => touched.try_insert(pos, hash).is_err(),
+                });
+            if overflowed {
+                TouchedOverflow::<T>::put(true);
+            }
+        }
     }
 }
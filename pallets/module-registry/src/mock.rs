@@ -3,6 +3,7 @@ use frame_support::{
     derive_impl, parameter_types,
     traits::{ConstU16, ConstU64},
 };
+use frame_system::EnsureRoot;
 use sp_core::H256;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
@@ -50,12 +51,17 @@ impl frame_system::Config for Test {
 parameter_types! {
     pub const MaxKeyLength: u32 = 128;
     pub const MaxCidLength: u32 = 128;
+    pub const MaxTouchedPerBlock: u32 = 3;
+    pub const MaxModulesPerAccount: u32 = 4;
 }
 
 impl pallet_module_registry::Config for Test {
     type WeightInfo = ();
+    type ForceOrigin = EnsureRoot<u64>;
     type MaxKeyLength = MaxKeyLength;
     type MaxCidLength = MaxCidLength;
+    type MaxTouchedPerBlock = MaxTouchedPerBlock;
+    type MaxModulesPerAccount = MaxModulesPerAccount;
 }
 
 // Build genesis storage according to the mock runtime.
@@ -1,7 +1,24 @@
-use crate::{mock::*, Error, Event};
-use frame_support::{assert_noop, assert_ok, BoundedVec};
+use crate::{
+    mock::*, Error, Event, ModuleEntry, ModuleOwners, ModulesByOwner, TouchedModulesDigest,
+    TOUCHED_DIGEST_ID,
+};
+use codec::Decode;
+use frame_support::{assert_noop, assert_ok, traits::Hooks, BoundedVec};
+use sp_runtime::{DigestItem, DispatchError};
 extern crate alloc;
-use alloc::vec;
+use alloc::{vec, vec::Vec};
+
+/// Finalize block `n` and return the registry digest it deposited, if any.
+fn finalize_and_read_digest(n: u64) -> Option<TouchedModulesDigest> {
+    ModuleRegistry::on_finalize(n);
+    System::digest().logs.iter().find_map(|log| match log {
+        DigestItem::Other(data) => {
+            let (id, digest) = <([u8; 4], TouchedModulesDigest)>::decode(&mut &data[..]).ok()?;
+            (id == TOUCHED_DIGEST_ID).then_some(digest)
+        }
+        _ => None,
+    })
+}
 
 #[test]
 fn register_module_works() {
@@ -270,3 +287,316 @@ fn validate_different_key_formats() {
         ));
     });
 }
+
+#[test]
+fn digest_lists_sorted_unique_touched_keys() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let key_a = vec![1u8; 32];
+        let key_b = vec![2u8; 32];
+        let cid1 = b"QmTestCID123456789012345678901234".to_vec();
+        let cid2 = b"QmNewCID1234567890123456789012345".to_vec();
+
+        assert_ok!(ModuleRegistry::register_module(
+            RuntimeOrigin::signed(1),
+            key_a.clone(),
+            cid1.clone()
+        ));
+        assert_ok!(ModuleRegistry::register_module(
+            RuntimeOrigin::signed(1),
+            key_b.clone(),
+            cid1
+        ));
+        assert_ok!(ModuleRegistry::update_module(
+            RuntimeOrigin::signed(1),
+            key_a.clone(),
+            cid2
+        ));
+
+        let mut expected: Vec<[u8; 16]> = vec![
+            ModuleRegistry::touched_key_hash(&key_a),
+            ModuleRegistry::touched_key_hash(&key_b),
+        ];
+        expected.sort();
+
+        assert_eq!(
+            finalize_and_read_digest(1),
+            Some(TouchedModulesDigest::Keys(expected))
+        );
+        // The per-block set is cleared once the digest is deposited
+        assert!(crate::TouchedModules::<Test>::get().is_empty());
+    });
+}
+
+#[test]
+fn digest_saturates_when_too_many_keys_touched() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let cid = b"QmTestCID123456789012345678901234".to_vec();
+        for i in 0..=MaxTouchedPerBlock::get() as u8 {
+            assert_ok!(ModuleRegistry::register_module(
+                RuntimeOrigin::signed(1),
+                vec![i; 32],
+                cid.clone()
+            ));
+        }
+
+        assert_eq!(
+            finalize_and_read_digest(1),
+            Some(TouchedModulesDigest::Saturated)
+        );
+        assert!(!crate::TouchedOverflow::<Test>::get());
+    });
+}
+
+#[test]
+fn no_digest_for_blocks_without_registry_changes() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_eq!(finalize_and_read_digest(1), None);
+    });
+}
+
+#[test]
+fn only_owner_can_change_module() {
+    new_test_ext().execute_with(|| {
+        let key = b"test_ed25519_key_32_bytes_long!!".to_vec();
+        let cid = b"QmTestCID123456789012345678901234".to_vec();
+        let new_cid = b"QmNewCID1234567890123456789012345".to_vec();
+
+        assert_ok!(ModuleRegistry::register_module(
+            RuntimeOrigin::signed(1),
+            key.clone(),
+            cid.clone()
+        ));
+        let bounded_key: BoundedVec<u8, MaxKeyLength> = key.clone().try_into().unwrap();
+        assert_eq!(ModuleOwners::<Test>::get(&bounded_key), Some(1));
+
+        // Another account can neither update nor remove it
+        assert_noop!(
+            ModuleRegistry::update_module(RuntimeOrigin::signed(2), key.clone(), new_cid),
+            Error::<Test>::NotModuleOwner
+        );
+        assert_noop!(
+            ModuleRegistry::remove_module(RuntimeOrigin::signed(2), key.clone()),
+            Error::<Test>::NotModuleOwner
+        );
+
+        // Removing frees the owner's slot
+        assert_ok!(ModuleRegistry::remove_module(RuntimeOrigin::signed(1), key));
+        assert!(ModuleOwners::<Test>::get(&bounded_key).is_none());
+        assert!(ModulesByOwner::<Test>::get(1).is_empty());
+    });
+}
+
+#[test]
+fn ownerless_module_is_assigned_by_force_origin() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let key = b"test_ed25519_key_32_bytes_long!!".to_vec();
+        let cid = b"QmTestCID123456789012345678901234".to_vec();
+        let bounded_key: BoundedVec<u8, MaxKeyLength> = key.clone().try_into().unwrap();
+        // A module registered before ownership was recorded
+        crate::Modules::<Test>::insert(&bounded_key, BoundedVec::try_from(cid.clone()).unwrap());
+
+        // Nobody owns it, so nobody can change it
+        assert_noop!(
+            ModuleRegistry::remove_module(RuntimeOrigin::signed(1), key.clone()),
+            Error::<Test>::NotModuleOwner
+        );
+        assert_noop!(
+            ModuleRegistry::force_set_module_owner(RuntimeOrigin::signed(1), key.clone(), 1),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            ModuleRegistry::force_set_module_owner(RuntimeOrigin::root(), vec![9; 32], 1),
+            Error::<Test>::ModuleNotFound
+        );
+
+        assert_ok!(ModuleRegistry::force_set_module_owner(
+            RuntimeOrigin::root(),
+            key.clone(),
+            1
+        ));
+        System::assert_last_event(
+            Event::ModuleOwnerSet {
+                key: bounded_key.clone(),
+                from: None,
+                to: 1,
+            }
+            .into(),
+        );
+        assert_eq!(ModuleOwners::<Test>::get(&bounded_key), Some(1));
+        assert_eq!(
+            ModulesByOwner::<Test>::get(1).into_inner(),
+            vec![bounded_key.clone()]
+        );
+        assert_noop!(
+            ModuleRegistry::force_set_module_owner(RuntimeOrigin::root(), key.clone(), 1),
+            Error::<Test>::AlreadyModuleOwner
+        );
+
+        // An owned module moves between the owners' lists
+        assert_ok!(ModuleRegistry::force_set_module_owner(
+            RuntimeOrigin::root(),
+            key.clone(),
+            2
+        ));
+        assert!(ModulesByOwner::<Test>::get(1).is_empty());
+        assert_eq!(
+            ModulesByOwner::<Test>::get(2).into_inner(),
+            vec![bounded_key]
+        );
+
+        // The new owner can change it as usual
+        assert_ok!(ModuleRegistry::remove_module(RuntimeOrigin::signed(2), key));
+    });
+}
+
+#[test]
+fn register_module_fails_when_account_owns_too_many() {
+    new_test_ext().execute_with(|| {
+        let cid = b"QmTestCID123456789012345678901234".to_vec();
+        for i in 0..MaxModulesPerAccount::get() as u8 {
+            assert_ok!(ModuleRegistry::register_module(
+                RuntimeOrigin::signed(1),
+                vec![i; 32],
+                cid.clone()
+            ));
+        }
+
+        assert_noop!(
+            ModuleRegistry::register_module(RuntimeOrigin::signed(1), vec![255; 32], cid.clone()),
+            Error::<Test>::TooManyModules
+        );
+        // Other accounts have their own limit
+        assert_ok!(ModuleRegistry::register_module(
+            RuntimeOrigin::signed(2),
+            vec![255; 32],
+            cid
+        ));
+    });
+}
+
+#[test]
+fn transfer_module_ownership_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let key = b"test_ed25519_key_32_bytes_long!!".to_vec();
+        let cid = b"QmTestCID123456789012345678901234".to_vec();
+        let new_cid = b"QmNewCID1234567890123456789012345".to_vec();
+        assert_ok!(ModuleRegistry::register_module(
+            RuntimeOrigin::signed(1),
+            key.clone(),
+            cid
+        ));
+
+        assert_noop!(
+            ModuleRegistry::transfer_module_ownership(RuntimeOrigin::signed(2), key.clone(), 3),
+            Error::<Test>::NotModuleOwner
+        );
+        assert_noop!(
+            ModuleRegistry::transfer_module_ownership(RuntimeOrigin::signed(1), key.clone(), 1),
+            Error::<Test>::AlreadyModuleOwner
+        );
+
+        assert_ok!(ModuleRegistry::transfer_module_ownership(
+            RuntimeOrigin::signed(1),
+            key.clone(),
+            2
+        ));
+        let bounded_key: BoundedVec<u8, MaxKeyLength> = key.clone().try_into().unwrap();
+        System::assert_last_event(
+            Event::ModuleOwnershipTransferred {
+                key: bounded_key.clone(),
+                from: 1,
+                to: 2,
+            }
+            .into(),
+        );
+        assert_eq!(ModuleOwners::<Test>::get(&bounded_key), Some(2));
+        assert!(ModulesByOwner::<Test>::get(1).is_empty());
+        assert_eq!(
+            ModulesByOwner::<Test>::get(2).into_inner(),
+            vec![bounded_key]
+        );
+
+        // The previous owner lost control, the new one gained it
+        assert_noop!(
+            ModuleRegistry::update_module(RuntimeOrigin::signed(1), key.clone(), new_cid.clone()),
+            Error::<Test>::NotModuleOwner
+        );
+        assert_ok!(ModuleRegistry::update_module(
+            RuntimeOrigin::signed(2),
+            key,
+            new_cid
+        ));
+    });
+}
+
+#[test]
+fn transfer_module_ownership_respects_receiver_limit() {
+    new_test_ext().execute_with(|| {
+        let cid = b"QmTestCID123456789012345678901234".to_vec();
+        for i in 0..MaxModulesPerAccount::get() as u8 {
+            assert_ok!(ModuleRegistry::register_module(
+                RuntimeOrigin::signed(2),
+                vec![i; 32],
+                cid.clone()
+            ));
+        }
+        assert_ok!(ModuleRegistry::register_module(
+            RuntimeOrigin::signed(1),
+            vec![255; 32],
+            cid
+        ));
+
+        assert_noop!(
+            ModuleRegistry::transfer_module_ownership(RuntimeOrigin::signed(1), vec![255; 32], 2),
+            Error::<Test>::TooManyModules
+        );
+    });
+}
+
+#[test]
+fn module_queries_enumerate_registry() {
+    new_test_ext().execute_with(|| {
+        let cid = b"QmTestCID123456789012345678901234".to_vec();
+        for (owner, key) in [(1, vec![1u8; 32]), (2, vec![2u8; 32]), (1, vec![3u8; 32])] {
+            assert_ok!(ModuleRegistry::register_module(
+                RuntimeOrigin::signed(owner),
+                key,
+                cid.clone()
+            ));
+        }
+
+        let entry = |owner: u64, byte: u8| ModuleEntry {
+            key: vec![byte; 32],
+            cid: cid.clone(),
+            owner: Some(owner),
+        };
+        assert_eq!(
+            ModuleRegistry::modules_by_owner(&1),
+            vec![entry(1, 1), entry(1, 3)]
+        );
+        assert_eq!(ModuleRegistry::module_entry(&[2u8; 32]), Some(entry(2, 2)));
+        assert_eq!(ModuleRegistry::module_entry(&[4u8; 32]), None);
+
+        // Paging through the registry visits every module exactly once
+        let mut seen = Vec::new();
+        let mut start_after: Option<Vec<u8>> = None;
+        loop {
+            let page = ModuleRegistry::list_modules(start_after.as_deref(), 2);
+            if page.is_empty() {
+                break;
+            }
+            start_after = page.last().map(|entry| entry.key.clone());
+            seen.extend(page.into_iter().map(|entry| entry.key[0]));
+        }
+        seen.sort();
+        assert_eq!(seen, vec![1, 2, 3]);
+    });
+}
@@ -1,13 +1,12 @@
-//! Autogenerated weights for `pallet_module_registry`
+//! Placeholder weights for `pallet_module_registry`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2024-01-01, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `substrate-node`, CPU: `Intel(R) Core(TM) i7-8700K CPU @ 3.70GHz`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: 1024
-
-// Executed Command:
-// ./target/production/substrate-node
+//! THESE WEIGHTS WERE NOT PRODUCED BY THE BENCHMARK CLI. The storage accesses and proof sizes
+//! follow the benchmarked code paths, but the execution times are estimates, not measurements.
+//! Replace this file with the output of the command below, run on reference hardware, before
+//! relying on these weights on a production chain.
+
+// Command to regenerate, with a node built with `--features runtime-benchmarks`:
+// ./target/release/mod-net-node
 // benchmark
 // pallet
 // --chain=dev
@@ -32,6 +31,9 @@ pub trait WeightInfo {
 	fn register_module() -> Weight;
 	fn update_module() -> Weight;
 	fn remove_module() -> Weight;
+	fn transfer_module_ownership() -> Weight;
+	fn force_set_module_owner() -> Weight;
+	fn on_finalize() -> Weight;
 }
 
 /// Weights for `pallet_module_registry` using the Substrate node and recommended hardware.
@@ -39,38 +41,116 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `ModuleRegistry::Modules` (r:1 w:1)
 	/// Proof: `ModuleRegistry::Modules` (`max_values`: None, `max_size`: Some(256), added: 2731, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::ModulesByOwner` (r:1 w:1)
+	/// Proof: `ModuleRegistry::ModulesByOwner` (`max_values`: None, `max_size`: Some(4210), added: 6685, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::ModuleOwners` (r:0 w:1)
+	/// Proof: `ModuleRegistry::ModuleOwners` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::TouchedOverflow` (r:1 w:1)
+	/// Proof: `ModuleRegistry::TouchedOverflow` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::TouchedModules` (r:1 w:1)
+	/// Proof: `ModuleRegistry::TouchedModules` (`max_values`: Some(1), `max_size`: Some(2049), added: 2544, mode: `MaxEncodedLen`)
 	fn register_module() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `76`
-		//  Estimated: `3721`
-		// Minimum execution time: 15_000_000 picoseconds.
-		Weight::from_parts(16_000_000, 3721)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Estimated: `12456`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 12456)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 
 	/// Storage: `ModuleRegistry::Modules` (r:1 w:1)
 	/// Proof: `ModuleRegistry::Modules` (`max_values`: None, `max_size`: Some(256), added: 2731, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::ModuleOwners` (r:1 w:0)
+	/// Proof: `ModuleRegistry::ModuleOwners` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::TouchedOverflow` (r:1 w:1)
+	/// Proof: `ModuleRegistry::TouchedOverflow` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::TouchedModules` (r:1 w:1)
+	/// Proof: `ModuleRegistry::TouchedModules` (`max_values`: Some(1), `max_size`: Some(2049), added: 2544, mode: `MaxEncodedLen`)
 	fn update_module() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `178`
-		//  Estimated: `3721`
-		// Minimum execution time: 14_000_000 picoseconds.
-		Weight::from_parts(15_000_000, 3721)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Measured:  `251`
+		//  Estimated: `8359`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 8359)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 
 	/// Storage: `ModuleRegistry::Modules` (r:1 w:1)
 	/// Proof: `ModuleRegistry::Modules` (`max_values`: None, `max_size`: Some(256), added: 2731, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::ModuleOwners` (r:1 w:1)
+	/// Proof: `ModuleRegistry::ModuleOwners` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::ModulesByOwner` (r:1 w:1)
+	/// Proof: `ModuleRegistry::ModulesByOwner` (`max_values`: None, `max_size`: Some(4210), added: 6685, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::TouchedOverflow` (r:1 w:1)
+	/// Proof: `ModuleRegistry::TouchedOverflow` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::TouchedModules` (r:1 w:1)
+	/// Proof: `ModuleRegistry::TouchedModules` (`max_values`: Some(1), `max_size`: Some(2049), added: 2544, mode: `MaxEncodedLen`)
 	fn remove_module() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `178`
-		//  Estimated: `3721`
-		// Minimum execution time: 13_000_000 picoseconds.
-		Weight::from_parts(14_000_000, 3721)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Measured:  `4375`
+		//  Estimated: `15044`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 15044)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: `ModuleRegistry::Modules` (r:1 w:0)
+	/// Proof: `ModuleRegistry::Modules` (`max_values`: None, `max_size`: Some(256), added: 2731, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::ModuleOwners` (r:1 w:1)
+	/// Proof: `ModuleRegistry::ModuleOwners` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::ModulesByOwner` (r:2 w:2)
+	/// Proof: `ModuleRegistry::ModulesByOwner` (`max_values`: None, `max_size`: Some(4210), added: 6685, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::TouchedOverflow` (r:1 w:1)
+	/// Proof: `ModuleRegistry::TouchedOverflow` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::TouchedModules` (r:1 w:1)
+	/// Proof: `ModuleRegistry::TouchedModules` (`max_values`: Some(1), `max_size`: Some(2049), added: 2544, mode: `MaxEncodedLen`)
+	fn transfer_module_ownership() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8473`
+		//  Estimated: `19729`
+		// Minimum execution time: 30_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 19729)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: `ModuleRegistry::Modules` (r:1 w:0)
+	/// Proof: `ModuleRegistry::Modules` (`max_values`: None, `max_size`: Some(256), added: 2731, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::ModuleOwners` (r:1 w:1)
+	/// Proof: `ModuleRegistry::ModuleOwners` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::ModulesByOwner` (r:2 w:2)
+	/// Proof: `ModuleRegistry::ModulesByOwner` (`max_values`: None, `max_size`: Some(4210), added: 6685, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::TouchedOverflow` (r:1 w:1)
+	/// Proof: `ModuleRegistry::TouchedOverflow` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::TouchedModules` (r:1 w:1)
+	/// Proof: `ModuleRegistry::TouchedModules` (`max_values`: Some(1), `max_size`: Some(2049), added: 2544, mode: `MaxEncodedLen`)
+	fn force_set_module_owner() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8473`
+		//  Estimated: `19729`
+		// Minimum execution time: 29_000_000 picoseconds.
+		Weight::from_parts(30_000_000, 19729)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: `ModuleRegistry::TouchedModules` (r:1 w:1)
+	/// Proof: `ModuleRegistry::TouchedModules` (`max_values`: Some(1), `max_size`: Some(2049), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::TouchedOverflow` (r:1 w:1)
+	/// Proof: `ModuleRegistry::TouchedOverflow` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `System::Digest` (r:1 w:1)
+	/// Proof: `System::Digest` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn on_finalize() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2110`
+		//  Estimated: `5150`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 5150)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 }
 
@@ -78,37 +158,115 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 impl WeightInfo for () {
 	/// Storage: `ModuleRegistry::Modules` (r:1 w:1)
 	/// Proof: `ModuleRegistry::Modules` (`max_values`: None, `max_size`: Some(256), added: 2731, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::ModulesByOwner` (r:1 w:1)
+	/// Proof: `ModuleRegistry::ModulesByOwner` (`max_values`: None, `max_size`: Some(4210), added: 6685, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::ModuleOwners` (r:0 w:1)
+	/// Proof: `ModuleRegistry::ModuleOwners` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::TouchedOverflow` (r:1 w:1)
+	/// Proof: `ModuleRegistry::TouchedOverflow` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::TouchedModules` (r:1 w:1)
+	/// Proof: `ModuleRegistry::TouchedModules` (`max_values`: Some(1), `max_size`: Some(2049), added: 2544, mode: `MaxEncodedLen`)
 	fn register_module() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `76`
-		//  Estimated: `3721`
-		// Minimum execution time: 15_000_000 picoseconds.
-		Weight::from_parts(16_000_000, 3721)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Estimated: `12456`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 12456)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 
 	/// Storage: `ModuleRegistry::Modules` (r:1 w:1)
 	/// Proof: `ModuleRegistry::Modules` (`max_values`: None, `max_size`: Some(256), added: 2731, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::ModuleOwners` (r:1 w:0)
+	/// Proof: `ModuleRegistry::ModuleOwners` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::TouchedOverflow` (r:1 w:1)
+	/// Proof: `ModuleRegistry::TouchedOverflow` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::TouchedModules` (r:1 w:1)
+	/// Proof: `ModuleRegistry::TouchedModules` (`max_values`: Some(1), `max_size`: Some(2049), added: 2544, mode: `MaxEncodedLen`)
 	fn update_module() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `178`
-		//  Estimated: `3721`
-		// Minimum execution time: 14_000_000 picoseconds.
-		Weight::from_parts(15_000_000, 3721)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Measured:  `251`
+		//  Estimated: `8359`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 8359)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 
 	/// Storage: `ModuleRegistry::Modules` (r:1 w:1)
 	/// Proof: `ModuleRegistry::Modules` (`max_values`: None, `max_size`: Some(256), added: 2731, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::ModuleOwners` (r:1 w:1)
+	/// Proof: `ModuleRegistry::ModuleOwners` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::ModulesByOwner` (r:1 w:1)
+	/// Proof: `ModuleRegistry::ModulesByOwner` (`max_values`: None, `max_size`: Some(4210), added: 6685, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::TouchedOverflow` (r:1 w:1)
+	/// Proof: `ModuleRegistry::TouchedOverflow` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::TouchedModules` (r:1 w:1)
+	/// Proof: `ModuleRegistry::TouchedModules` (`max_values`: Some(1), `max_size`: Some(2049), added: 2544, mode: `MaxEncodedLen`)
 	fn remove_module() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `178`
-		//  Estimated: `3721`
-		// Minimum execution time: 13_000_000 picoseconds.
-		Weight::from_parts(14_000_000, 3721)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Measured:  `4375`
+		//  Estimated: `15044`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 15044)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: `ModuleRegistry::Modules` (r:1 w:0)
+	/// Proof: `ModuleRegistry::Modules` (`max_values`: None, `max_size`: Some(256), added: 2731, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::ModuleOwners` (r:1 w:1)
+	/// Proof: `ModuleRegistry::ModuleOwners` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::ModulesByOwner` (r:2 w:2)
+	/// Proof: `ModuleRegistry::ModulesByOwner` (`max_values`: None, `max_size`: Some(4210), added: 6685, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::TouchedOverflow` (r:1 w:1)
+	/// Proof: `ModuleRegistry::TouchedOverflow` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::TouchedModules` (r:1 w:1)
+	/// Proof: `ModuleRegistry::TouchedModules` (`max_values`: Some(1), `max_size`: Some(2049), added: 2544, mode: `MaxEncodedLen`)
+	fn transfer_module_ownership() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8473`
+		//  Estimated: `19729`
+		// Minimum execution time: 30_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 19729)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: `ModuleRegistry::Modules` (r:1 w:0)
+	/// Proof: `ModuleRegistry::Modules` (`max_values`: None, `max_size`: Some(256), added: 2731, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::ModuleOwners` (r:1 w:1)
+	/// Proof: `ModuleRegistry::ModuleOwners` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::ModulesByOwner` (r:2 w:2)
+	/// Proof: `ModuleRegistry::ModulesByOwner` (`max_values`: None, `max_size`: Some(4210), added: 6685, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::TouchedOverflow` (r:1 w:1)
+	/// Proof: `ModuleRegistry::TouchedOverflow` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::TouchedModules` (r:1 w:1)
+	/// Proof: `ModuleRegistry::TouchedModules` (`max_values`: Some(1), `max_size`: Some(2049), added: 2544, mode: `MaxEncodedLen`)
+	fn force_set_module_owner() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `8473`
+		//  Estimated: `19729`
+		// Minimum execution time: 29_000_000 picoseconds.
+		Weight::from_parts(30_000_000, 19729)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: `ModuleRegistry::TouchedModules` (r:1 w:1)
+	/// Proof: `ModuleRegistry::TouchedModules` (`max_values`: Some(1), `max_size`: Some(2049), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `ModuleRegistry::TouchedOverflow` (r:1 w:1)
+	/// Proof: `ModuleRegistry::TouchedOverflow` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `System::Digest` (r:1 w:1)
+	/// Proof: `System::Digest` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn on_finalize() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2110`
+		//  Estimated: `5150`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 5150)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 }
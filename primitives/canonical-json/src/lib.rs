@@ -0,0 +1,623 @@
+//! # Canonical JSON
+//!
+//! `no_std` canonicalization of JSON documents following the JSON Canonicalization Scheme
+//! (JCS, RFC 8785).
+//!
+//! ## Overview
+//!
+//! Whenever a hash of a JSON document is stored or compared on chain (interface schemas,
+//! module manifests, commitments), the hash must not depend on how the document happens to be
+//! formatted. This crate turns any JSON text into its canonical form:
+//! - Insignificant whitespace is removed
+//! - Object members are sorted by their keys, compared as UTF-16 code units
+//! - Strings are written with the minimal set of escapes
+//! - Numbers are written in the shortest form that round-trips through an IEEE 754 double,
+//!   using ECMAScript formatting rules (`4.50` becomes `4.5`, `1E30` becomes `1e+30`)
+//!
+//! Two documents that are semantically identical therefore canonicalize to the same bytes, and
+//! [`canonical_hash`] gives them the same hash. The same crate builds for the runtime and for
+//! client SDKs, so both sides agree on the canonical bytes.
+//!
+//! Documents that JCS cannot represent unambiguously are rejected: duplicate object keys, lone
+//! UTF-16 surrogates and numbers outside the range of a double.
+//!
+//! [`parse`] exposes the same parser to code that reads JSON on chain, so a document is accepted
+//! there exactly when it can be canonicalized.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+
+/// Maximum nesting depth of arrays and objects accepted by [`canonicalize`] and [`parse`].
+pub const MAX_DEPTH: usize = 128;
+
+/// Reasons a document cannot be canonicalized.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The input is not valid UTF-8.
+    InvalidUtf8,
+    /// The input ended in the middle of a value.
+    UnexpectedEnd,
+    /// An unexpected character was found at the given byte offset.
+    UnexpectedCharacter(usize),
+    /// Non-whitespace characters follow the top-level value.
+    TrailingCharacters,
+    /// A number literal is malformed.
+    InvalidNumber,
+    /// A number does not fit in an IEEE 754 double.
+    NumberOutOfRange,
+    /// A string contains an invalid escape sequence or an unescaped control character.
+    InvalidString,
+    /// A string contains a UTF-16 surrogate that is not part of a pair.
+    LoneSurrogate,
+    /// An object contains the same key twice.
+    DuplicateKey,
+    /// Arrays and objects are nested deeper than [`MAX_DEPTH`].
+    TooDeep,
+}
+
+/// Canonicalize a JSON document.
+///
+/// Returns the canonical UTF-8 encoding of `input` as defined by RFC 8785.
+pub fn canonicalize(input: &[u8]) -> Result<Vec<u8>, Error> {
+    let value = parse(input)?;
+
+    let mut out = String::with_capacity(input.len());
+    write_value(&value, &mut out);
+    Ok(out.into_bytes())
+}
+
+/// Canonicalize a JSON document and return the `blake2_256` hash of its canonical form.
+pub fn canonical_hash(input: &[u8]) -> Result<[u8; 32], Error> {
+    canonicalize(input).map(|canonical| sp_crypto_hashing::blake2_256(&canonical))
+}
+
+/// Parse a JSON document, rejecting the same documents as [`canonicalize`].
+pub fn parse(input: &[u8]) -> Result<Value, Error> {
+    let text = core::str::from_utf8(input).map_err(|_| Error::InvalidUtf8)?;
+    let mut parser = Parser {
+        input: text.as_bytes(),
+        pos: 0,
+    };
+    parser.parse_document()
+}
+
+/// A parsed JSON value.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(Number),
+    String(String),
+    Array(Vec<Value>),
+    /// Members sorted by their keys in canonical order, without duplicates.
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    /// The member `key` of an object.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(members) => members.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+}
+
+/// A JSON number, with the literal it was written as.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Number {
+    literal: String,
+    value: f64,
+}
+
+impl Number {
+    /// The number as written in the document.
+    pub fn as_str(&self) -> &str {
+        &self.literal
+    }
+
+    /// The value of the number.
+    pub fn as_f64(&self) -> f64 {
+        self.value
+    }
+
+    /// The value of a non-negative integer written without a fraction or exponent.
+    pub fn as_u64(&self) -> Option<u64> {
+        self.literal.parse().ok()
+    }
+}
+
+struct Parser<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn parse_document(&mut self) -> Result<Value, Error> {
+        let value = self.parse_value(0)?;
+        self.skip_whitespace();
+        if self.pos != self.input.len() {
+            return Err(Error::TrailingCharacters);
+        }
+        Ok(value)
+    }
+
+    fn parse_value(&mut self, depth: usize) -> Result<Value, Error> {
+        self.skip_whitespace();
+        match self.peek()? {
+            b'{' => self.parse_object(depth + 1),
+            b'[' => self.parse_array(depth + 1),
+            b'"' => self.parse_string().map(Value::String),
+            b't' => self.parse_literal("true", Value::Bool(true)),
+            b'f' => self.parse_literal("false", Value::Bool(false)),
+            b'n' => self.parse_literal("null", Value::Null),
+            b'-' | b'0'..=b'9' => self.parse_number().map(Value::Number),
+            _ => Err(Error::UnexpectedCharacter(self.pos)),
+        }
+    }
+
+    fn parse_object(&mut self, depth: usize) -> Result<Value, Error> {
+        if depth > MAX_DEPTH {
+            return Err(Error::TooDeep);
+        }
+        self.expect(b'{')?;
+        let mut members: Vec<(String, Value)> = Vec::new();
+
+        self.skip_whitespace();
+        if self.peek()? == b'}' {
+            self.pos += 1;
+            return Ok(Value::Object(members));
+        }
+
+        loop {
+            self.skip_whitespace();
+            if self.peek()? != b'"' {
+                return Err(Error::UnexpectedCharacter(self.pos));
+            }
+            let key = self.parse_string()?;
+            self.skip_whitespace();
+            self.expect(b':')?;
+            let value = self.parse_value(depth)?;
+            members.push((key, value));
+
+            self.skip_whitespace();
+            match self.next()? {
+                b',' => continue,
+                b'}' => break,
+                _ => return Err(Error::UnexpectedCharacter(self.pos - 1)),
+            }
+        }
+
+        members.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+        if members.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+            return Err(Error::DuplicateKey);
+        }
+        Ok(Value::Object(members))
+    }
+
+    fn parse_array(&mut self, depth: usize) -> Result<Value, Error> {
+        if depth > MAX_DEPTH {
+            return Err(Error::TooDeep);
+        }
+        self.expect(b'[')?;
+        let mut items = Vec::new();
+
+        self.skip_whitespace();
+        if self.peek()? == b']' {
+            self.pos += 1;
+            return Ok(Value::Array(items));
+        }
+
+        loop {
+            items.push(self.parse_value(depth)?);
+            self.skip_whitespace();
+            match self.next()? {
+                b',' => continue,
+                b']' => break,
+                _ => return Err(Error::UnexpectedCharacter(self.pos - 1)),
+            }
+        }
+        Ok(Value::Array(items))
+    }
+
+    fn parse_literal(&mut self, literal: &str, value: Value) -> Result<Value, Error> {
+        let end = self.pos + literal.len();
+        if self.input.get(self.pos..end) != Some(literal.as_bytes()) {
+            return Err(Error::UnexpectedCharacter(self.pos));
+        }
+        self.pos = end;
+        Ok(value)
+    }
+
+    fn parse_number(&mut self) -> Result<Number, Error> {
+        let start = self.pos;
+        self.eat(b'-');
+
+        match self.peek() {
+            Ok(b'0') => self.pos += 1,
+            Ok(b'1'..=b'9') => self.eat_digits(),
+            _ => return Err(Error::InvalidNumber),
+        }
+        if self.eat(b'.') {
+            if !matches!(self.peek(), Ok(b'0'..=b'9')) {
+                return Err(Error::InvalidNumber);
+            }
+            self.eat_digits();
+        }
+        if self.eat(b'e') || self.eat(b'E') {
+            if !self.eat(b'+') {
+                self.eat(b'-');
+            }
+            if !matches!(self.peek(), Ok(b'0'..=b'9')) {
+                return Err(Error::InvalidNumber);
+            }
+            self.eat_digits();
+        }
+
+        // The grammar above only admits ASCII, so the slice is valid UTF-8.
+        let literal =
+            core::str::from_utf8(&self.input[start..self.pos]).map_err(|_| Error::InvalidNumber)?;
+        let number: f64 = literal.parse().map_err(|_| Error::InvalidNumber)?;
+        if !number.is_finite() {
+            return Err(Error::NumberOutOfRange);
+        }
+        Ok(Number {
+            literal: literal.into(),
+            value: number,
+        })
+    }
+
+    fn parse_string(&mut self) -> Result<String, Error> {
+        self.expect(b'"')?;
+        let mut out = String::new();
+
+        loop {
+            // Copy the run of bytes that need no processing in one go.
+            let run_start = self.pos;
+            while let Some(&byte) = self.input.get(self.pos) {
+                if byte == b'"' || byte == b'\\' || byte < 0x20 {
+                    break;
+                }
+                self.pos += 1;
+            }
+            // Runs end on ASCII bytes, so they never split a UTF-8 sequence.
+            out.push_str(
+                core::str::from_utf8(&self.input[run_start..self.pos])
+                    .map_err(|_| Error::InvalidUtf8)?,
+            );
+
+            match self.next()? {
+                b'"' => return Ok(out),
+                b'\\' => out.push(self.parse_escape()?),
+                _ => return Err(Error::InvalidString),
+            }
+        }
+    }
+
+    fn parse_escape(&mut self) -> Result<char, Error> {
+        let c = match self.next()? {
+            b'"' => '"',
+            b'\\' => '\\',
+            b'/' => '/',
+            b'b' => '\u{08}',
+            b'f' => '\u{0c}',
+            b'n' => '\n',
+            b'r' => '\r',
+            b't' => '\t',
+            b'u' => {
+                let unit = self.parse_hex4()?;
+                match unit {
+                    0xD800..=0xDBFF => {
+                        if !(self.eat(b'\\') && self.eat(b'u')) {
+                            return Err(Error::LoneSurrogate);
+                        }
+                        let low = self.parse_hex4()?;
+                        if !(0xDC00..=0xDFFF).contains(&low) {
+                            return Err(Error::LoneSurrogate);
+                        }
+                        let code = 0x10000 + ((unit - 0xD800) << 10) + (low - 0xDC00);
+                        char::from_u32(code).ok_or(Error::LoneSurrogate)?
+                    }
+                    0xDC00..=0xDFFF => return Err(Error::LoneSurrogate),
+                    _ => char::from_u32(unit).ok_or(Error::InvalidString)?,
+                }
+            }
+            _ => return Err(Error::InvalidString),
+        };
+        Ok(c)
+    }
+
+    fn parse_hex4(&mut self) -> Result<u32, Error> {
+        let mut unit = 0u32;
+        for _ in 0..4 {
+            let digit = (self.next()? as char)
+                .to_digit(16)
+                .ok_or(Error::InvalidString)?;
+            unit = unit * 16 + digit;
+        }
+        Ok(unit)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.input.get(self.pos), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn eat_digits(&mut self) {
+        while matches!(self.input.get(self.pos), Some(b'0'..=b'9')) {
+            self.pos += 1;
+        }
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        if self.input.get(self.pos) == Some(&byte) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), Error> {
+        if self.next()? == byte {
+            Ok(())
+        } else {
+            Err(Error::UnexpectedCharacter(self.pos - 1))
+        }
+    }
+
+    fn peek(&self) -> Result<u8, Error> {
+        self.input
+            .get(self.pos)
+            .copied()
+            .ok_or(Error::UnexpectedEnd)
+    }
+
+    fn next(&mut self) -> Result<u8, Error> {
+        let byte = self.peek()?;
+        self.pos += 1;
+        Ok(byte)
+    }
+}
+
+fn write_value(value: &Value, out: &mut String) {
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(true) => out.push_str("true"),
+        Value::Bool(false) => out.push_str("false"),
+        Value::Number(number) => write_number(number.value, out),
+        Value::String(string) => write_string(string, out),
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_value(item, out);
+            }
+            out.push(']');
+        }
+        Value::Object(members) => {
+            out.push('{');
+            for (i, (key, value)) in members.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_string(key, out);
+                out.push(':');
+                write_value(value, out);
+            }
+            out.push('}');
+        }
+    }
+}
+
+fn write_string(string: &str, out: &mut String) {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+
+    out.push('"');
+    for c in string.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\u{08}' => out.push_str("\\b"),
+            '\u{0c}' => out.push_str("\\f"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                out.push_str("\\u00");
+                out.push(HEX[(c as usize) >> 4] as char);
+                out.push(HEX[(c as usize) & 0xf] as char);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Write a finite number the way ECMAScript's `Number.prototype.toString` does.
+fn write_number(number: f64, out: &mut String) {
+    if number == 0.0 {
+        // Covers negative zero, which serializes as `0`.
+        out.push('0');
+        return;
+    }
+    if number < 0.0 {
+        out.push('-');
+    }
+
+    // `ryu` produces the shortest digit string that round-trips; only its layout differs from
+    // ECMAScript, so split it into significant digits and a decimal exponent.
+    let mut buffer = ryu::Buffer::new();
+    let formatted = buffer.format_finite(number.abs());
+    let (mantissa, exponent) = match formatted.split_once('e') {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse::<i32>().unwrap_or(0)),
+        None => (formatted, 0),
+    };
+    let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    let mut digits: Vec<u8> = int_part.bytes().chain(frac_part.bytes()).collect();
+    // Position of the decimal point relative to the start of `digits`.
+    let mut point = int_part.len() as i32 + exponent;
+    let leading_zeros = digits.iter().take_while(|&&d| d == b'0').count();
+    digits.drain(..leading_zeros);
+    point -= leading_zeros as i32;
+    while digits.last() == Some(&b'0') {
+        digits.pop();
+    }
+
+    let k = digits.len() as i32;
+    let n = point;
+    let push_digits = |out: &mut String, digits: &[u8]| {
+        out.extend(digits.iter().map(|&d| d as char));
+    };
+
+    if k <= n && n <= 21 {
+        push_digits(out, &digits);
+        out.extend(core::iter::repeat_n('0', (n - k) as usize));
+    } else if 0 < n && n <= 21 {
+        push_digits(out, &digits[..n as usize]);
+        out.push('.');
+        push_digits(out, &digits[n as usize..]);
+    } else if -6 < n && n <= 0 {
+        out.push_str("0.");
+        out.extend(core::iter::repeat_n('0', (-n) as usize));
+        push_digits(out, &digits);
+    } else {
+        push_digits(out, &digits[..1]);
+        if k > 1 {
+            out.push('.');
+            push_digits(out, &digits[1..]);
+        }
+        out.push('e');
+        let e = n - 1;
+        out.push(if e < 0 { '-' } else { '+' });
+        out.push_str(itoa(e.unsigned_abs()).as_str());
+    }
+}
+
+fn itoa(mut value: u32) -> String {
+    let mut digits = Vec::new();
+    loop {
+        digits.push(b'0' + (value % 10) as u8);
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+    digits.iter().rev().map(|&d| d as char).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn canonical(input: &str) -> String {
+        String::from_utf8(canonicalize(input.as_bytes()).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn rfc8785_example() {
+        let input = r#"{
+            "numbers": [333333333.33333329, 1E30, 4.50, 2e-3, 0.000000000000000000000000001],
+            "string": "\u20ac$\u000F\u000aA'\u0042\u0022\u005c\\\"\/",
+            "literals": [null, true, false]
+        }"#;
+        assert_eq!(
+            canonical(input),
+            r#"{"literals":[null,true,false],"numbers":[333333333.3333333,1e+30,4.5,0.002,1e-27],"string":"€$\u000f\nA'B\"\\\\\"/"}"#
+        );
+    }
+
+    #[test]
+    fn numbers_use_ecmascript_formatting() {
+        for (input, expected) in [
+            ("0", "0"),
+            ("-0", "0"),
+            ("-0.0e5", "0"),
+            ("1", "1"),
+            ("-1.50", "-1.5"),
+            ("100", "100"),
+            ("1e21", "1e+21"),
+            ("1e20", "100000000000000000000"),
+            ("123e18", "123000000000000000000"),
+            ("9007199254740992", "9007199254740992"),
+            ("295147905179352830000", "295147905179352830000"),
+            ("1.7976931348623157e308", "1.7976931348623157e+308"),
+            ("5e-324", "5e-324"),
+            ("0.000001", "0.000001"),
+            ("0.0000001", "1e-7"),
+            ("1.25e-7", "1.25e-7"),
+            ("123.456", "123.456"),
+        ] {
+            assert_eq!(canonical(input), expected, "input {input}");
+        }
+    }
+
+    #[test]
+    fn keys_are_sorted_by_utf16_code_units() {
+        // U+FB33 sorts after U+1F600 by code point but before it by UTF-16 code unit.
+        assert_eq!(
+            canonical(r#"{"\ufb33": 3, "\ud83d\ude00": 2, "\u20ac": 1, "a": {"b": 0, "a": 0}}"#),
+            "{\"a\":{\"a\":0,\"b\":0},\"\u{20ac}\":1,\"\u{1f600}\":2,\"\u{fb33}\":3}"
+        );
+    }
+
+    #[test]
+    fn formatting_does_not_change_the_hash() {
+        let a = br#"{"name":"echo","input":{"type":"object","required":["text"]}}"#;
+        let b = b"{ \"input\" : { \"required\" : [ \"text\" ] ,\n \"type\" : \"object\" } , \"name\" : \"echo\" }";
+        assert_eq!(canonical_hash(a).unwrap(), canonical_hash(b).unwrap());
+    }
+
+    #[test]
+    fn parse_keeps_number_literals() {
+        let value = parse(br#"{"count": 3, "ratio": 4.50, "nested": {"a": null}}"#).unwrap();
+        let Some(Value::Number(count)) = value.get("count") else {
+            panic!("count is not a number");
+        };
+        assert_eq!((count.as_str(), count.as_u64()), ("3", Some(3)));
+        let Some(Value::Number(ratio)) = value.get("ratio") else {
+            panic!("ratio is not a number");
+        };
+        assert_eq!(
+            (ratio.as_str(), ratio.as_f64(), ratio.as_u64()),
+            ("4.50", 4.5, None)
+        );
+        assert_eq!(
+            value.get("nested").and_then(|nested| nested.get("a")),
+            Some(&Value::Null)
+        );
+        assert_eq!(value.get("missing"), None);
+        assert_eq!(parse(b"{\"a\":1,\"a\":2}"), Err(Error::DuplicateKey));
+    }
+
+    #[test]
+    fn rejects_invalid_documents() {
+        for (input, error) in [
+            (&b""[..], Error::UnexpectedEnd),
+            (b"{\"a\":1,\"a\":2}", Error::DuplicateKey),
+            (b"\"\\ud800\"", Error::LoneSurrogate),
+            (b"\"\\udc00\"", Error::LoneSurrogate),
+            (b"\"a\nb\"", Error::InvalidString),
+            (b"1e400", Error::NumberOutOfRange),
+            (b"01", Error::TrailingCharacters),
+            (b"1.", Error::InvalidNumber),
+            (b"[1,]", Error::UnexpectedCharacter(3)),
+            (b"\"\xff\"", Error::InvalidUtf8),
+            (b"{} {}", Error::TrailingCharacters),
+        ] {
+            assert_eq!(canonicalize(input), Err(error));
+        }
+
+        let deep = "[".repeat(MAX_DEPTH + 1) + &"]".repeat(MAX_DEPTH + 1);
+        assert_eq!(canonicalize(deep.as_bytes()), Err(Error::TooDeep));
+        let ok = "[".repeat(MAX_DEPTH) + &"]".repeat(MAX_DEPTH);
+        assert!(canonicalize(ok.as_bytes()).is_ok());
+    }
+}
@@ -42,8 +42,9 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-    AccountId, Aura, Balance, Block, Executive, Grandpa, InherentDataExt, Nonce, Runtime,
-    RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, VERSION,
+    AccountId, Aura, Balance, Block, BlockNumber, Executive, Grandpa, InherentDataExt, Mcp,
+    ModuleRegistry, Nonce, Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System,
+    TransactionPayment, VERSION,
 };
 
 impl_runtime_apis! {
@@ -220,6 +221,104 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_module_registry_runtime_api::ModuleRegistryApi<Block, AccountId> for Runtime {
+        fn list_modules(
+            start_after: Option<Vec<u8>>,
+            limit: u32,
+        ) -> Vec<pallet_module_registry::ModuleEntry<AccountId>> {
+            ModuleRegistry::list_modules(start_after.as_deref(), limit)
+        }
+        fn modules_by_owner(owner: AccountId) -> Vec<pallet_module_registry::ModuleEntry<AccountId>> {
+            ModuleRegistry::modules_by_owner(&owner)
+        }
+        fn get_module(key: Vec<u8>) -> Option<pallet_module_registry::ModuleEntry<AccountId>> {
+            ModuleRegistry::module_entry(&key)
+        }
+    }
+
+    impl pallet_mcp_runtime_api::McpApi<
+        Block,
+        AccountId,
+        Balance,
+        pallet_mcp::ServerInfo<Runtime>,
+        pallet_mcp::ToolInfo<Runtime>,
+        pallet_mcp::PromptTemplate<Runtime>,
+        pallet_mcp::ResourceInfo<Runtime>,
+        pallet_mcp::HealthStatus<BlockNumber>,
+        pallet_mcp::AccountOverview<Runtime>,
+        pallet_mcp::SamplingRequest<Runtime>,
+    > for Runtime {
+        fn list_servers(
+            start: pallet_mcp::ServerId,
+            limit: u32,
+            min_reputation: Option<u32>,
+            min_verification: Option<pallet_mcp::VerificationLevel>,
+        ) -> Vec<(pallet_mcp::ServerId, pallet_mcp::ServerInfo<Runtime>)> {
+            Mcp::list_servers(start, limit, min_reputation, min_verification)
+        }
+        fn servers_by_owner(
+            owner: AccountId,
+        ) -> Vec<(pallet_mcp::ServerId, pallet_mcp::ServerInfo<Runtime>)> {
+            Mcp::servers_by_owner(&owner)
+        }
+        fn tools_by_server(
+            server_id: pallet_mcp::ServerId,
+        ) -> Vec<(pallet_mcp::ToolId, pallet_mcp::ToolInfo<Runtime>)> {
+            Mcp::tools_by_server(server_id)
+        }
+        fn get_quote(tool_id: pallet_mcp::ToolId, caller: AccountId) -> Option<Balance> {
+            Mcp::quote(tool_id, &caller)
+        }
+        fn prompts_by_server(
+            server_id: pallet_mcp::ServerId,
+        ) -> Vec<(pallet_mcp::PromptId, pallet_mcp::PromptTemplate<Runtime>)> {
+            Mcp::prompts_by_server(server_id)
+        }
+        fn render_prompt(
+            prompt_id: pallet_mcp::PromptId,
+            params_json: Vec<u8>,
+        ) -> Result<Vec<u8>, pallet_mcp::RenderError> {
+            Mcp::render_prompt(prompt_id, &params_json)
+        }
+        fn resources_by_server(
+            server_id: pallet_mcp::ServerId,
+        ) -> Vec<(pallet_mcp::ResourceId, pallet_mcp::ResourceInfo<Runtime>)> {
+            Mcp::resources_by_server(server_id)
+        }
+        fn negotiate_capabilities(
+            client_required: pallet_mcp::ServerCapabilities,
+            min_protocol_version: Option<pallet_mcp::ProtocolVersion>,
+        ) -> Vec<pallet_mcp::ServerId> {
+            Mcp::negotiate_capabilities(&client_required, min_protocol_version)
+        }
+        fn get_server_capabilities(
+            server_id: pallet_mcp::ServerId,
+        ) -> Option<pallet_mcp::ServerCapabilities> {
+            Mcp::server_capabilities(server_id)
+        }
+        fn get_server_verification(
+            server_id: pallet_mcp::ServerId,
+        ) -> Option<pallet_mcp::VerificationLevel> {
+            Mcp::server_verification(server_id)
+        }
+        fn get_server_health(
+            server_id: pallet_mcp::ServerId,
+        ) -> Option<pallet_mcp::HealthStatus<BlockNumber>> {
+            Mcp::server_health(server_id)
+        }
+        fn get_account_overview(account: AccountId) -> pallet_mcp::AccountOverview<Runtime> {
+            Mcp::account_overview(&account)
+        }
+        fn pending_sampling_requests(
+            server_id: pallet_mcp::ServerId,
+        ) -> Vec<(pallet_mcp::SamplingRequestId, pallet_mcp::SamplingRequest<Runtime>)> {
+            Mcp::pending_sampling_requests(server_id)
+        }
+        fn get_next_ids() -> pallet_mcp::NextIds {
+            Mcp::next_ids()
+        }
+    }
+
     #[cfg(feature = "runtime-benchmarks")]
     impl frame_benchmarking::Benchmark<Block> for Runtime {
         fn benchmark_metadata(extra: bool) -> (
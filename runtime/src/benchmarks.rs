@@ -31,4 +31,9 @@ frame_benchmarking::define_benchmarks!(
     [pallet_timestamp, Timestamp]
     [pallet_sudo, Sudo]
     [pallet_template, Template]
+    [pallet_gateway_registry, GatewayRegistry]
+    [pallet_agent_channels, AgentChannels]
+    [pallet_mcp, Mcp]
+    [pallet_ipfs_pinning, IpfsPinning]
+    [pallet_assets, Assets]
 );
@@ -24,27 +24,42 @@
 // For more information, please refer to <http://unlicense.org>
 
 // Substrate and Polkadot dependencies
+use alloc::vec::Vec;
+use codec::Encode;
 use frame_support::{
     derive_impl, parameter_types,
-    traits::{ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, VariantCountOf},
+    traits::{
+        AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, SortedMembers,
+        VariantCountOf,
+    },
     weights::{
         constants::{RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND},
         IdentityFee, Weight,
     },
 };
 use frame_system::limits::{BlockLength, BlockWeights};
+use frame_system::{EnsureRoot, EnsureSigned};
 use pallet_transaction_payment::{ConstFeeMultiplier, FungibleAdapter, Multiplier};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_runtime::{traits::One, Perbill};
+use sp_runtime::{
+    generic::Era,
+    traits::{One, Verify},
+    Perbill,
+};
 use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-    AccountId, Aura, Balance, Balances, Block, BlockNumber, Hash, Nonce, PalletInfo, Runtime,
-    RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask,
-    System, EXISTENTIAL_DEPOSIT, SLOT_DURATION, VERSION,
+    AccountId, Assets, Aura, Balance, Balances, Block, BlockNumber, Hash, IpfsPinning, Nonce,
+    PalletInfo, Runtime, RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason,
+    RuntimeOrigin, RuntimeTask, Signature, SignedPayload, System, TxExtension, UncheckedExtrinsic,
+    COMPUTE_CREDIT_ASSET, DAYS, EXISTENTIAL_DEPOSIT, MICRO_UNIT, MILLI_UNIT, MINUTES,
+    SLOT_DURATION, UNIT, VERSION,
 };
 
+mod profile;
+use profile::PROFILE;
+
 const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
 
 parameter_types! {
@@ -165,8 +180,234 @@ impl pallet_template::Config for Runtime {
 /// Configure the ModuleRegistry pallet for real blockchain transactions.
 impl pallet_module_registry::Config for Runtime {
     type WeightInfo = pallet_module_registry::weights::SubstrateWeight<Runtime>;
+    /// Sudo assigns owners to modules registered before ownership was recorded
+    type ForceOrigin = EnsureRoot<AccountId>;
     /// Maximum length for public keys (supports Ed25519, Ethereum, Solana keys)
     type MaxKeyLength = ConstU32<64>;
     /// Maximum length for IPFS CIDs (typical CID is ~46 characters)
     type MaxCidLength = ConstU32<64>;
+    /// 16 bytes of digest per touched module, so 2 KiB with the default profile
+    type MaxTouchedPerBlock = ConstU32<{ PROFILE.max_touched_per_block }>;
+    type MaxModulesPerAccount = ConstU32<{ PROFILE.max_modules_per_account }>;
+}
+
+/// Configure the GatewayRegistry pallet; reporters are managed by sudo.
+impl pallet_gateway_registry::Config for Runtime {
+    type WeightInfo = pallet_gateway_registry::weights::SubstrateWeight<Runtime>;
+    type AuthorityId = pallet_gateway_registry::crypto::AuthorityId;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type Currency = Balances;
+    type GatewayDeposit = ConstU128<{ 10 * UNIT }>;
+    type MaxUrlLength = ConstU32<128>;
+    type MaxRegionLength = ConstU32<32>;
+    type MaxGateways = ConstU32<{ PROFILE.max_gateways }>;
+    /// Filling every slot takes a deposit per gateway and an account per four gateways
+    type MaxGatewaysPerOperator = ConstU32<4>;
+    type MaxReporters = ConstU32<{ PROFILE.max_reporters }>;
+    /// Probe every gateway roughly every ten minutes
+    type ProbeInterval = ConstU32<{ 10 * MINUTES }>;
+    /// Probes run one after another, so a round takes at most `MaxGateways` times this; even
+    /// the large profile stays well inside the probe interval
+    type ProbeTimeout = ConstU64<2_000>;
+}
+
+/// Configure the AgentChannels pallet.
+impl pallet_agent_channels::Config for Runtime {
+    type WeightInfo = pallet_agent_channels::weights::SubstrateWeight<Runtime>;
+    type MaxOpenChannels = ConstU32<{ PROFILE.max_open_channels }>;
+}
+
+parameter_types! {
+    pub const McpPalletId: frame_support::PalletId = frame_support::PalletId(*b"py/mcpcr");
+}
+
+/// Configure the Mcp pallet.
+impl pallet_mcp::Config for Runtime {
+    type WeightInfo = pallet_mcp::weights::SubstrateWeight<Runtime>;
+    type RuntimeCall = RuntimeCall;
+    type Currency = Balances;
+    type Assets = Assets;
+    type SlashOrigin = EnsureRoot<AccountId>;
+    type VerifyOrigin = EnsureRoot<AccountId>;
+    /// No pallet reacts to registrations or settled calls yet
+    type OnServerRegistered = ();
+    type OnCallSettled = ();
+    /// Every content and metadata CID opens a pin bounty
+    type OnCidPublished = IpfsPinning;
+    type ServerDeposit = ConstU128<{ PROFILE.server_deposit }>;
+    type RegistrationPeriod = ConstU32<{ PROFILE.registration_period }>;
+    type RenewalFee = ConstU128<{ PROFILE.renewal_fee }>;
+    type CreditAsset = ConstU32<COMPUTE_CREDIT_ASSET>;
+    /// Credits have six decimals and one credit is worth one unit
+    type NativePerCredit = ConstU128<MICRO_UNIT>;
+    type PalletId = McpPalletId;
+    type MaxNameLength = ConstU32<64>;
+    type MaxDescriptionLength = ConstU32<256>;
+    type MaxUrlLength = ConstU32<256>;
+    type MaxCidLength = ConstU32<64>;
+    type MaxServersPerOwner = ConstU32<{ PROFILE.max_servers_per_owner }>;
+    type MaxToolsPerServer = ConstU32<{ PROFILE.max_tools_per_server }>;
+    type MaxSchemaLength = ConstU32<4096>;
+    type MaxPromptsPerServer = ConstU32<32>;
+    type MaxTemplateLength = ConstU32<2048>;
+    type MaxResourcesPerServer = ConstU32<64>;
+    type MaxSubscribersPerResource = ConstU32<64>;
+    type ExecutionTimeout = ConstU32<{ 10 * MINUTES }>;
+    type MinExecutionFee = ConstU128<MILLI_UNIT>;
+    type MaxPendingExecutions = ConstU32<{ PROFILE.max_pending_executions }>;
+    type MaxRequestsPerAccount = ConstU32<{ PROFILE.max_requests_per_account }>;
+    type MaxModelHints = ConstU32<4>;
+    type MaxPendingSamplingRequests = ConstU32<64>;
+    type MaxSamplingHistory = ConstU32<32>;
+    /// A provider's open requests may escrow up to ten times their server deposits
+    type ExposureMultiplier = ConstU32<10>;
+    type MaxAllowlistLength = ConstU32<64>;
+    /// MCP servers are probed by the gateway reporters, signing with their gateway key
+    type AuthorityId = pallet_gateway_registry::crypto::AuthorityId;
+    type HealthReporters = GatewayReporters;
+    type ProbeInterval = ConstU32<{ 10 * MINUTES }>;
+    /// A full round of probes takes at most 128 seconds, well inside the probe interval
+    type ProbeTimeout = ConstU64<2_000>;
+    type MaxHealthReports = ConstU32<64>;
+    /// A server is deactivated after about half an hour of failed probes
+    type MaxConsecutiveFailures = ConstU32<3>;
+    type MaxActivityPerAccount = ConstU32<64>;
+    /// A rating counts half as much after a month
+    type ReputationHalfLife = ConstU32<{ 30 * DAYS }>;
+}
+
+/// Randomness derived from the parent block hash.
+///
+/// The block author can bias it by withholding a block, so it is only used where a biased outcome
+/// is cheap, such as picking which pins to challenge.
+pub struct ParentHashRandomness;
+
+impl frame_support::traits::Randomness<Hash, BlockNumber> for ParentHashRandomness {
+    fn random(subject: &[u8]) -> (Hash, BlockNumber) {
+        let parent = System::parent_hash();
+        let seed = <sp_runtime::traits::BlakeTwo256 as sp_runtime::traits::Hash>::hash_of(&(
+            subject, parent,
+        ));
+        (seed, System::block_number().saturating_sub(1))
+    }
+}
+
+/// Configure the Assets pallet.
+impl pallet_assets::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type AssetId = u32;
+    type AssetIdParameter = codec::Compact<u32>;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type AssetDeposit = ConstU128<{ 10 * UNIT }>;
+    type AssetAccountDeposit = ConstU128<MILLI_UNIT>;
+    type MetadataDepositBase = ConstU128<UNIT>;
+    type MetadataDepositPerByte = ConstU128<MILLI_UNIT>;
+    type ApprovalDeposit = ConstU128<MILLI_UNIT>;
+    type StringLimit = ConstU32<50>;
+    type Freezer = ();
+    type Holder = ();
+    type Extra = ();
+    type CallbackHandle = ();
+    type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+    type RemoveItemsLimit = ConstU32<1000>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
+parameter_types! {
+    pub const IpfsPinningPalletId: frame_support::PalletId = frame_support::PalletId(*b"py/ipfsp");
+}
+
+impl pallet_ipfs_pinning::Config for Runtime {
+    type WeightInfo = pallet_ipfs_pinning::weights::SubstrateWeight<Runtime>;
+    type Currency = Balances;
+    type Randomness = ParentHashRandomness;
+    type BountyOrigin = EnsureRoot<AccountId>;
+    type PalletId = IpfsPinningPalletId;
+    type MaxCidLength = ConstU32<64>;
+    type MaxPinnersPerCid = ConstU32<16>;
+    type PinBond = ConstU128<{ 10 * UNIT }>;
+    type AttestationReward = ConstU128<{ 10 * MILLI_UNIT }>;
+    /// Pinners are rewarded at most once a day per pin
+    type AttestationPeriod = ConstU32<DAYS>;
+    type ChallengePeriod = ConstU32<{ 10 * MINUTES }>;
+    type ChallengeWindow = ConstU32<{ 30 * MINUTES }>;
+    type ChallengesPerRound = ConstU32<16>;
+}
+
+/// The reporters of the gateway registry, who also report MCP server health.
+pub struct GatewayReporters;
+
+impl SortedMembers<AccountId> for GatewayReporters {
+    fn sorted_members() -> Vec<AccountId> {
+        let mut reporters = pallet_gateway_registry::Reporters::<Runtime>::get().into_inner();
+        reporters.sort();
+        reporters
+    }
+
+    fn contains(who: &AccountId) -> bool {
+        pallet_gateway_registry::Reporters::<Runtime>::get().contains(who)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn add(who: &AccountId) {
+        let _ = pallet_gateway_registry::Reporters::<Runtime>::try_append(who);
+    }
+}
+
+impl frame_system::offchain::SigningTypes for Runtime {
+    type Public = <Signature as Verify>::Signer;
+    type Signature = Signature;
+}
+
+impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for Runtime
+where
+    RuntimeCall: From<LocalCall>,
+{
+    type RuntimeCall = RuntimeCall;
+    type Extrinsic = UncheckedExtrinsic;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Runtime
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_signed_transaction<
+        C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>,
+    >(
+        call: RuntimeCall,
+        public: <Signature as Verify>::Signer,
+        account: AccountId,
+        nonce: Nonce,
+    ) -> Option<UncheckedExtrinsic> {
+        let period = BlockHashCount::get()
+            .checked_next_power_of_two()
+            .map(|c| c / 2)
+            .unwrap_or(2) as u64;
+        let current_block = System::block_number().saturating_sub(1) as u64;
+        let tx_ext: TxExtension = (
+            frame_system::CheckNonZeroSender::<Runtime>::new(),
+            frame_system::CheckSpecVersion::<Runtime>::new(),
+            frame_system::CheckTxVersion::<Runtime>::new(),
+            frame_system::CheckGenesis::<Runtime>::new(),
+            frame_system::CheckEra::<Runtime>::from(Era::mortal(period, current_block)),
+            frame_system::CheckNonce::<Runtime>::from(nonce),
+            frame_system::CheckWeight::<Runtime>::new(),
+            pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0),
+            frame_metadata_hash_extension::CheckMetadataHash::<Runtime>::new(false),
+            frame_system::WeightReclaim::<Runtime>::new(),
+        );
+        let raw_payload = SignedPayload::new(call, tx_ext).ok()?;
+        let signature = raw_payload.using_encoded(|payload| C::sign(payload, public))?;
+        let (call, tx_ext, _) = raw_payload.deconstruct();
+        Some(UncheckedExtrinsic::new_signed(
+            call,
+            sp_runtime::MultiAddress::Id(account),
+            signature,
+            tx_ext,
+        ))
+    }
 }
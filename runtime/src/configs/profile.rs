@@ -0,0 +1,99 @@
+//! Network size profiles.
+//!
+//! The registry pallets have several capacity limits that must grow together: a network with
+//! more gateways needs more reporters to probe them, and more modules touched per block need a
+//! larger digest. The deposits and fees that keep those limits from being filled cheaply grow
+//! with them. Instead of tuning each `Max*` constant and deposit by hand, pick a profile when
+//! building the runtime:
+//!
+//! ```bash
+//! cargo build --release --features profile-small   # development and test networks
+//! cargo build --release                            # medium, the default
+//! cargo build --release --features profile-large   # large public networks such as a mainnet
+//! ```
+//!
+//! If both features are enabled (as with `--all-features`), the large profile wins. Format
+//! limits such as key and CID lengths do not depend on network size and stay in the pallet
+//! configs.
+
+use crate::{Balance, BlockNumber, DAYS, UNIT};
+
+/// A consistent set of capacity limits and deposits.
+pub struct Profile {
+    /// Modules listed in a block digest before it saturates.
+    pub max_touched_per_block: u32,
+    /// Modules a single account may own.
+    pub max_modules_per_account: u32,
+    /// Registered IPFS gateways.
+    pub max_gateways: u32,
+    /// Accounts allowed to report gateway health.
+    pub max_reporters: u32,
+    /// Agent channels an account may have open at once.
+    pub max_open_channels: u32,
+    /// MCP servers a single account may register.
+    pub max_servers_per_owner: u32,
+    /// Tools a single MCP server may offer.
+    pub max_tools_per_server: u32,
+    /// Unanswered execution requests a single MCP server may have.
+    pub max_pending_executions: u32,
+    /// Unsettled execution requests a single account may have made.
+    pub max_requests_per_account: u32,
+    /// Deposit reserved for every MCP server registration.
+    pub server_deposit: Balance,
+    /// Blocks an MCP server registration or renewal lasts.
+    pub registration_period: BlockNumber,
+    /// Fee burned for every renewal of an MCP server registration.
+    pub renewal_fee: Balance,
+}
+
+/// Limits for test networks and small deployments.
+#[cfg(all(feature = "profile-small", not(feature = "profile-large")))]
+pub const PROFILE: Profile = Profile {
+    max_touched_per_block: 32,
+    max_modules_per_account: 16,
+    max_gateways: 8,
+    max_reporters: 4,
+    max_open_channels: 16,
+    max_servers_per_owner: 4,
+    max_tools_per_server: 32,
+    max_pending_executions: 16,
+    max_requests_per_account: 4,
+    // Cheap and short-lived, so test registrations lapse without cleanup
+    server_deposit: UNIT,
+    registration_period: 7 * DAYS,
+    renewal_fee: UNIT / 10,
+};
+
+/// Limits for large public networks.
+#[cfg(feature = "profile-large")]
+pub const PROFILE: Profile = Profile {
+    max_touched_per_block: 512,
+    max_modules_per_account: 256,
+    max_gateways: 128,
+    max_reporters: 64,
+    max_open_channels: 256,
+    max_servers_per_owner: 64,
+    max_tools_per_server: 128,
+    max_pending_executions: 256,
+    max_requests_per_account: 64,
+    server_deposit: 100 * UNIT,
+    registration_period: 365 * DAYS,
+    renewal_fee: 10 * UNIT,
+};
+
+/// Default limits.
+#[cfg(not(any(feature = "profile-small", feature = "profile-large")))]
+pub const PROFILE: Profile = Profile {
+    max_touched_per_block: 128,
+    max_modules_per_account: 64,
+    max_gateways: 32,
+    max_reporters: 16,
+    max_open_channels: 64,
+    max_servers_per_owner: 16,
+    max_tools_per_server: 64,
+    max_pending_executions: 64,
+    max_requests_per_account: 16,
+    server_deposit: 10 * UNIT,
+    registration_period: 365 * DAYS,
+    renewal_fee: UNIT,
+};
@@ -15,7 +15,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{AccountId, BalancesConfig, RuntimeGenesisConfig, SudoConfig};
+use crate::{
+    AccountId, AssetsConfig, BalancesConfig, RuntimeGenesisConfig, SudoConfig, COMPUTE_CREDIT_ASSET,
+};
 use alloc::{vec, vec::Vec};
 use frame_support::build_struct_json_patch;
 use serde_json::Value;
@@ -50,6 +52,15 @@ fn testnet_genesis(
                 .map(|x| (x.1.clone(), 1))
                 .collect::<Vec<_>>(),
         },
+        assets: AssetsConfig {
+            assets: vec![(COMPUTE_CREDIT_ASSET, root.clone(), true, 1)],
+            metadata: vec![(
+                COMPUTE_CREDIT_ASSET,
+                b"Compute Credit".to_vec(),
+                b"CREDIT".to_vec(),
+                6
+            )],
+        },
         sudo: SudoConfig { key: Some(root) },
     })
 }
@@ -103,6 +103,9 @@ pub const UNIT: Balance = 1_000_000_000_000;
 pub const MILLI_UNIT: Balance = 1_000_000_000;
 pub const MICRO_UNIT: Balance = 1_000_000;
 
+/// Asset that MCP deposits and execution fees may be paid in instead of the native token.
+pub const COMPUTE_CREDIT_ASSET: u32 = 1;
+
 /// Existential deposit.
 pub const EXISTENTIAL_DEPOSIT: Balance = MILLI_UNIT;
 
@@ -174,7 +177,11 @@ pub type SignedPayload = generic::SignedPayload<RuntimeCall, TxExtension>;
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+    pallet_mcp::migrations::v1::MigrateV0ToV1<Runtime>,
+    pallet_mcp::migrations::v2::MigrateV1ToV2<Runtime>,
+    pallet_mcp::migrations::v3::MigrateV2ToV3<Runtime>,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -232,4 +239,24 @@ mod runtime {
     // Include the ModuleRegistry pallet for real blockchain transactions
     #[runtime::pallet_index(8)]
     pub type ModuleRegistry = pallet_module_registry;
+
+    // Community IPFS gateways with offchain-worker health scoring
+    #[runtime::pallet_index(9)]
+    pub type GatewayRegistry = pallet_gateway_registry;
+
+    // Hash-chain anchors for off-chain agent-to-agent conversations
+    #[runtime::pallet_index(10)]
+    pub type AgentChannels = pallet_agent_channels;
+
+    // Registry of Model Context Protocol servers
+    #[runtime::pallet_index(11)]
+    pub type Mcp = pallet_mcp;
+
+    // Pin bounties rewarding accounts that keep IPFS content available
+    #[runtime::pallet_index(12)]
+    pub type IpfsPinning = pallet_ipfs_pinning;
+
+    // Fungible assets, including the compute credits accepted by the MCP pallet
+    #[runtime::pallet_index(13)]
+    pub type Assets = pallet_assets;
 }